@@ -0,0 +1,55 @@
+mod common;
+
+use std::hint::black_box;
+
+use zjson::{any::Any, document::Document, testing::corpus::CorpusKind};
+
+const SEED: u64 = 0;
+const NUMBERS: usize = 100_000;
+
+/// Measures collecting every number of a [`CorpusKind::NumericArray`] document into a `Vec<f64>`.
+///
+/// The numbers are collected with [`Array::collect_numbers`](zjson::array::Array::collect_numbers),
+/// and with a hand-written loop over `next` and `read_number`, for comparison.
+///
+/// Usage: `cargo bench --bench collect_numbers`
+fn main() {
+    let json = CorpusKind::NumericArray.generate(SEED, NUMBERS);
+    assert_eq!(collect_numbers(&json), manual_loop(&json));
+
+    common::bench("collect_numbers", json.len(), || {
+        collect_numbers(black_box(&json))
+    });
+    common::bench("collect_numbers (manual loop)", json.len(), || {
+        manual_loop(black_box(&json))
+    });
+}
+
+/// Collects the numbers with [`Array::collect_numbers`](zjson::array::Array::collect_numbers).
+fn collect_numbers(json: &str) -> Vec<f64> {
+    let mut document = Document::new(json);
+    let Some(Any::Array(mut array)) = document.next().expect("failed to parse document") else {
+        panic!("expected an array");
+    };
+
+    let numbers = array.collect_numbers().expect("failed to collect numbers");
+    document.finish().expect("failed to parse document");
+    numbers
+}
+
+/// Collects the numbers by reading each value as a number.
+fn manual_loop(json: &str) -> Vec<f64> {
+    let mut document = Document::new(json);
+    let Some(Any::Array(mut array)) = document.next().expect("failed to parse document") else {
+        panic!("expected an array");
+    };
+
+    let mut numbers = Vec::new();
+    while let Some(mut value) = array.next().expect("failed to parse array") {
+        let number = value.read_number().expect("failed to read number");
+        numbers.push(number.as_f64());
+    }
+
+    document.finish().expect("failed to parse document");
+    numbers
+}
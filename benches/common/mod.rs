@@ -0,0 +1,24 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// The number of timed runs of each benchmark.
+pub const RUNS: u32 = 50;
+
+/// Runs `f` once to warm up, then [`RUNS`] times, and prints the best time and the throughput over `len` bytes of input.
+pub fn bench<T>(name: &str, len: usize, mut f: impl FnMut() -> T) {
+    // Warm up
+    black_box(f());
+
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        black_box(f());
+        best = best.min(start.elapsed());
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let throughput = len as f64 / best.as_secs_f64() / 1_000_000.0;
+    println!("{name}: {len} bytes in {best:?} ({throughput:.0} MB/s, best of {RUNS})");
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use std::hint::black_box;
+
+use zjson::{document::Document, multi_document::MultiDocument, testing::corpus::CorpusKind};
+
+const SEED: u64 = 0;
+const SIZE: usize = 20_000;
+/// Deep nesting grows with the size, so keep it to a reasonable depth.
+const DEPTH: usize = 256;
+
+/// Measures parsing each kind of generated corpus document.
+///
+/// Usage: `cargo bench --bench corpus`
+fn main() {
+    for kind in CorpusKind::ALL {
+        let size = if kind == CorpusKind::DeepNesting {
+            DEPTH
+        } else {
+            SIZE
+        };
+        let json = kind.generate(SEED, size);
+        let name = kind.file_name();
+
+        if kind == CorpusKind::Ndjson {
+            common::bench(name, json.len(), || {
+                MultiDocument::new(black_box(&json))
+                    .finish()
+                    .expect("failed to parse documents");
+            });
+        } else {
+            common::bench(name, json.len(), || {
+                Document::new(black_box(&json))
+                    .finish()
+                    .expect("failed to parse document");
+            });
+        }
+    }
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use std::{fmt::Write, hint::black_box};
+
+use zjson::{any::Any, document::Document};
+
+const RECORDS: usize = 2_000;
+const INDENT: usize = 4;
+
+/// Measures parsing a heavily indented document, where most of the input is whitespace.
+///
+/// Usage: `cargo bench --bench indented`
+fn main() {
+    let json = indented(RECORDS);
+    common::bench("indented", json.len(), || parse(black_box(&json)));
+}
+
+/// Generates an array of `records` objects, pretty-printed with deep indentation.
+fn indented(records: usize) -> String {
+    let mut json = String::from("[\n");
+
+    for i in 0..records {
+        let pad = " ".repeat(INDENT);
+        let inner = " ".repeat(INDENT * 8);
+        write!(
+            json,
+            "{pad}{{\n\
+             {inner}\"id\" :   {i} ,\n\
+             {inner}\"tags\" :   [\n{inner}{inner}\"a\" ,\n{inner}{inner}\"b\"\n{inner}] ,\n\
+             {inner}\"nested\" :   {{\n{inner}{inner}\"ok\" :   true\n{inner}}}\n\
+             {pad}}}{}\n",
+            if i + 1 == records { "" } else { "," }
+        )
+        .expect("failed to write document");
+    }
+
+    json.push(']');
+    json
+}
+
+fn parse(json: &str) {
+    let mut document = Document::new(json);
+    let mut root = document
+        .next()
+        .expect("failed to parse document")
+        .expect("expected a value");
+    visit(&mut root);
+    document.finish().expect("failed to parse document");
+}
+
+fn visit(value: &mut Any) {
+    match value {
+        Any::Object(object) => {
+            while let Some((_, mut value)) = object.next().expect("failed to parse object") {
+                visit(&mut value);
+            }
+        }
+        Any::Array(array) => {
+            while let Some(mut value) = array.next().expect("failed to parse array") {
+                visit(&mut value);
+            }
+        }
+        _ => value.finish().expect("failed to parse value"),
+    }
+}
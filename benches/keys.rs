@@ -0,0 +1,66 @@
+mod common;
+
+use std::hint::black_box;
+
+use zjson::{any::Any, document::Document, string::ParsedString, testing::corpus::CorpusKind};
+
+const SEED: u64 = 0;
+const KEYS: usize = 40_000;
+
+/// Measures matching object keys against field names, where most keys have no escapes.
+///
+/// The document is a [`CorpusKind::KeyHeavyObject`], and each key is compared with its decoded value.
+/// Keys are compared with [`ParsedString`]'s `==`, and character by character for comparison.
+///
+/// Usage: `cargo bench --bench keys`
+fn main() {
+    let json = CorpusKind::KeyHeavyObject.generate(SEED, KEYS);
+    let fields = fields(&json);
+
+    bench(&json, &fields, "keys (==)", |key, field| key == field);
+    bench(&json, &fields, "keys (chars)", |key, field| {
+        key.chars().eq(field.chars())
+    });
+}
+
+fn bench(json: &str, fields: &[String], name: &str, eq: fn(ParsedString, &str) -> bool) {
+    assert_eq!(parse(json, fields, eq), KEYS);
+    common::bench(name, json.len(), || parse(black_box(json), fields, eq));
+}
+
+/// Collects the decoded keys of the object in `json`, in order.
+fn fields(json: &str) -> Vec<String> {
+    let mut document = Document::new(json);
+    let Some(Any::Object(mut object)) = document.next().expect("failed to parse document") else {
+        panic!("expected an object");
+    };
+
+    let mut fields = Vec::with_capacity(KEYS);
+    while let Some((key, mut value)) = object.next().expect("failed to parse object") {
+        fields.push(key.escaped());
+        value.finish().expect("failed to parse value");
+    }
+
+    fields
+}
+
+/// Parses `json`, returning the number of keys that matched their field.
+fn parse(json: &str, fields: &[String], eq: fn(ParsedString, &str) -> bool) -> usize {
+    let mut document = Document::new(json);
+    let Some(Any::Object(mut object)) = document.next().expect("failed to parse document") else {
+        panic!("expected an object");
+    };
+
+    let mut fields = fields.iter();
+    let mut matched = 0;
+    while let Some((key, mut value)) = object.next().expect("failed to parse object") {
+        let field = fields.next().expect("more keys than fields");
+        if eq(key, field) {
+            matched += 1;
+        }
+        value.finish().expect("failed to parse value");
+    }
+
+    document.finish().expect("failed to parse document");
+    matched
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use std::hint::black_box;
+
+use zjson::{any::Any, document::Document};
+
+const RECORDS: usize = 200;
+const BLOB_LEN: usize = 16 * 1024;
+
+/// Measures parsing a document of long string values (base64 blobs), where strings are most of the input.
+///
+/// Usage: `cargo bench --bench long_strings`
+fn main() {
+    let json = blobs(RECORDS);
+    common::bench("long_strings", json.len(), || parse(black_box(&json)));
+}
+
+/// Generates an array of `records` objects, each with a base64-like blob of [`BLOB_LEN`] characters.
+fn blobs(records: usize) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut json = String::from("[");
+    // A simple LCG, so the blobs are the same every run
+    let mut state: u32 = 1;
+
+    for i in 0..records {
+        if i != 0 {
+            json.push(',');
+        }
+
+        json.push_str("{\"name\":\"blob\",\"data\":\"");
+        for _ in 0..BLOB_LEN {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            json.push(char::from(
+                ALPHABET[(state >> 16) as usize % ALPHABET.len()],
+            ));
+        }
+        json.push_str("\"}");
+    }
+
+    json.push(']');
+    json
+}
+
+fn parse(json: &str) {
+    let mut document = Document::new(json);
+    let Some(Any::Array(mut array)) = document.next().expect("failed to parse document") else {
+        panic!("expected an array");
+    };
+
+    while let Some(value) = array.next().expect("failed to parse array") {
+        let Any::Object(mut object) = value else {
+            panic!("expected an object");
+        };
+
+        while let Some((_, value)) = object.next().expect("failed to parse object") {
+            let Any::String(mut string) = value else {
+                panic!("expected a string");
+            };
+            black_box(string.get().expect("failed to parse string"));
+        }
+    }
+
+    document.finish().expect("failed to parse document");
+}
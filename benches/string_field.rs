@@ -0,0 +1,85 @@
+mod common;
+
+use std::hint::black_box;
+
+use zjson::{any::Any, document::Document, testing::corpus::CorpusKind};
+
+const SEED: u64 = 0;
+const RECORDS: usize = 20_000;
+const FIELD: &str = "msg";
+
+/// Measures reading a string field from every record of a [`CorpusKind::StringLogs`] document.
+///
+/// The field is read with [`Object::read_string_field`](zjson::object::Object::read_string_field), reusing one buffer,
+/// and by finding it with `next` and decoding it with [`ParsedString::escaped`](zjson::string::ParsedString::escaped), for comparison.
+///
+/// Usage: `cargo bench --bench string_field`
+fn main() {
+    let json = CorpusKind::StringLogs.generate(SEED, RECORDS);
+    assert_eq!(read_string_field(&json), get_escaped(&json));
+
+    common::bench("string_field (read_string_field)", json.len(), || {
+        read_string_field(black_box(&json))
+    });
+    common::bench("string_field (get + escaped)", json.len(), || {
+        get_escaped(black_box(&json))
+    });
+}
+
+/// Reads the field with [`Object::read_string_field`](zjson::object::Object::read_string_field), returning the total length of the values.
+fn read_string_field(json: &str) -> usize {
+    let mut document = Document::new(json);
+    let Some(Any::Array(mut array)) = document.next().expect("failed to parse document") else {
+        panic!("expected an array");
+    };
+
+    let mut buf = String::new();
+    let mut total = 0;
+    while let Some(value) = array.next().expect("failed to parse array") {
+        let Any::Object(mut object) = value else {
+            panic!("expected an object");
+        };
+
+        let outcome = object
+            .read_string_field(FIELD, &mut buf, true)
+            .expect("failed to read field");
+        assert!(outcome.found);
+        total += buf.len();
+    }
+
+    document.finish().expect("failed to parse document");
+    total
+}
+
+/// Finds the field with `next` and decodes it with [`ParsedString::escaped`](zjson::string::ParsedString::escaped), returning the total length of the values.
+fn get_escaped(json: &str) -> usize {
+    let mut document = Document::new(json);
+    let Some(Any::Array(mut array)) = document.next().expect("failed to parse document") else {
+        panic!("expected an array");
+    };
+
+    let mut total = 0;
+    while let Some(value) = array.next().expect("failed to parse array") {
+        let Any::Object(mut object) = value else {
+            panic!("expected an object");
+        };
+
+        while let Some((key, mut value)) = object.next().expect("failed to parse object") {
+            if key == FIELD {
+                let Any::String(string) = &mut value else {
+                    panic!("expected a string");
+                };
+                total += string
+                    .get()
+                    .expect("failed to parse string")
+                    .escaped()
+                    .len();
+            } else {
+                value.finish().expect("failed to parse value");
+            }
+        }
+    }
+
+    document.finish().expect("failed to parse document");
+    total
+}
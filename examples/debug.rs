@@ -1,4 +1,4 @@
-use zjson::{any::Any, document::Document};
+use zjson::document::Document;
 
 fn main() {
     let json = r#"{
@@ -11,26 +11,27 @@ fn main() {
 
     // root
     let mut root = document
-        .next()
-        .expect("failed to parse document")
-        .and_then(Any::object)
+        .expect_object()
         .expect("failed to get an object from the document");
 
     // "one"
-    let element = root.next().expect("failed to parse object");
-    let Some((key, Any::Number(mut one))) = element else {
-        panic!("failed to get a number from the object");
-    };
-    let one = one.get().expect("failed to parse number");
+    let (key, mut one) = root
+        .next()
+        .expect("failed to parse object")
+        .expect("failed to get a value from the object");
+    let one = one.read_number().expect("failed to read a number");
 
     assert_eq!(key, "one");
     assert_eq!(one.as_u8(), Some(1));
 
     // "array"
-    let element = root.next().expect("failed to parse object");
-    let Some((key, Any::Array(mut array))) = element else {
-        panic!("failed to get an array from the object");
-    };
+    let (key, array) = root
+        .next()
+        .expect("failed to parse object")
+        .expect("failed to get a value from the object");
+    let mut array = array
+        .read_array()
+        .expect("failed to get an array from the object");
 
     assert_eq!(key, "array");
 
@@ -38,7 +39,8 @@ fn main() {
     let mut r#true = array
         .next()
         .expect("failed to parse array")
-        .and_then(Any::literal)
+        .expect("failed to get a value from the array")
+        .literal()
         .expect("failed to get a true value from the array");
 
     // debug print the true literal
@@ -0,0 +1,60 @@
+use std::{env, fs, io, path::Path};
+
+use zjson::testing::corpus::CorpusKind;
+
+const DEFAULT_SEED: u64 = 0;
+const DEFAULT_SIZE: usize = 10_000;
+
+/// Generates the benchmark corpus into a directory.
+///
+/// Usage: `cargo run --example gen_corpus -- <directory> [seed] [size]`
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let Some(directory) = args.next() else {
+        eprintln!("usage: gen_corpus <directory> [seed] [size]");
+        return Ok(());
+    };
+    let seed = args
+        .next()
+        .map_or(DEFAULT_SEED, |seed| seed.parse().expect("invalid seed"));
+    let size = args
+        .next()
+        .map_or(DEFAULT_SIZE, |size| size.parse().expect("invalid size"));
+
+    generate(Path::new(&directory), seed, size)
+}
+
+fn generate(directory: &Path, seed: u64, size: usize) -> io::Result<()> {
+    fs::create_dir_all(directory)?;
+
+    for kind in CorpusKind::ALL {
+        let path = directory.join(kind.file_name());
+        // Deep nesting grows with the size, so keep it to a reasonable depth
+        let size = if kind == CorpusKind::DeepNesting {
+            size.min(256)
+        } else {
+            size
+        };
+
+        fs::write(&path, kind.generate(seed, size))?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+// Test the example
+#[test]
+fn test() {
+    let directory = env::temp_dir().join("zjson_gen_corpus_test");
+    generate(&directory, DEFAULT_SEED, 100).expect("failed to generate corpus");
+
+    for kind in CorpusKind::ALL {
+        let json = fs::read_to_string(directory.join(kind.file_name()))
+            .expect("failed to read generated document");
+        assert_eq!(json, kind.generate(DEFAULT_SEED, 100));
+    }
+
+    fs::remove_dir_all(directory).expect("failed to remove generated corpus");
+}
@@ -1,6 +1,4 @@
-#![allow(clippy::approx_constant)]
-
-use zjson::{any::Any, document::Document};
+use zjson::document::Document;
 
 fn main() {
     let json = r#"{
@@ -13,93 +11,86 @@ fn main() {
 
     // root
     let mut root = document
-        .next()
-        .expect("failed to parse document")
-        .and_then(Any::object)
+        .expect_object()
         .expect("failed to get an object from the document");
 
     // "one"
-    let element = root.next().expect("failed to parse object");
-    let Some((key, Any::Number(mut one))) = element else {
-        panic!("failed to get a number from the object");
-    };
-    let one = one.get().expect("failed to parse number");
+    let (key, mut one) = root
+        .next()
+        .expect("failed to parse object")
+        .expect("failed to get a value from the object");
+    let one = one.read_number().expect("failed to read a number");
 
     assert_eq!(key, "one");
     assert_eq!(one.as_u8(), Some(1));
 
     // "array"
-    let element = root.next().expect("failed to parse object");
-    let Some((key, Any::Array(mut array))) = element else {
-        panic!("failed to get an array from the object");
-    };
+    let (key, array) = root
+        .next()
+        .expect("failed to parse object")
+        .expect("failed to get a value from the object");
+    let mut array = array
+        .read_array()
+        .expect("failed to get an array from the object");
 
     assert_eq!(key, "array");
 
     // "array" -> 0
     let r#true = array
-        .next()
-        .expect("failed to parse array")
-        .and_then(Any::literal)
-        .expect("failed to get a true value from the array")
-        .get()
-        .expect("failed to parse a true value");
+        .next_typed::<bool>()
+        .expect("failed to read a true value");
 
-    assert_eq!(r#true, true);
+    assert_eq!(r#true, Some(true));
 
     // "array" -> 1
     let r#false = array
-        .next()
-        .expect("failed to parse array")
-        .and_then(Any::literal)
-        .expect("failed to get a false value from the array")
-        .get()
-        .expect("failed to parse a false value");
+        .next_typed::<bool>()
+        .expect("failed to read a false value");
 
-    assert_eq!(r#false, false);
+    assert_eq!(r#false, Some(false));
 
     // "array" -> 2
     let null = array
-        .next()
-        .expect("failed to parse array")
-        .and_then(Any::literal)
-        .expect("failed to get a null value from the array")
-        .get()
-        .expect("failed to parse a null value");
+        .next_typed::<Option<bool>>()
+        .expect("failed to read a null value");
 
-    assert_eq!(null, None);
+    assert_eq!(null, Some(None));
 
     // finish "array"
     let array_element = array.next().expect("failed to parse array");
     assert!(array_element.is_none());
 
     // "object"
-    let element = root.next().expect("failed to parse object");
-    let Some((key, Any::Object(mut object))) = element else {
-        panic!("failed to get an object from the object");
-    };
+    let (key, object) = root
+        .next()
+        .expect("failed to parse object")
+        .expect("failed to get a value from the object");
+    let mut object = object
+        .read_object()
+        .expect("failed to get an object from the object");
 
     assert_eq!(key, "object");
 
     // "object" -> "pi"
-    let object_element = object.next().expect("failed to parse inner object");
-    let Some((key, Any::Number(mut pi))) = object_element else {
-        panic!("failed to get a number from the inner object");
-    };
-    let pi = pi.get().expect("failed to parse a number");
+    let (key, mut pi) = object
+        .next()
+        .expect("failed to parse inner object")
+        .expect("failed to get a value from the inner object");
+    let pi = pi.read_number().expect("failed to read a number");
 
     assert_eq!(key, "pi");
-    assert_eq!(pi, 3.14);
+    // Compare the text of the number, so that this works without the `float` feature
+    assert_eq!(pi.as_str(), "3.14");
 
     // "object" -> "exp"
-    let object_element = object.next().expect("failed to parse inner object");
-    let Some((key, Any::Number(mut exp))) = object_element else {
-        panic!("failed to get a number from the inner object");
-    };
-    let exp = exp.get().expect("failed to parse a number");
+    let (key, mut exp) = object
+        .next()
+        .expect("failed to parse inner object")
+        .expect("failed to get a value from the inner object");
+    let exp = exp.read_number().expect("failed to read a number");
 
     assert_eq!(key, "exp");
-    assert_eq!(exp.as_f32(), 1e5);
+    assert_eq!(exp.as_str(), "1e5");
 
     // skip the rest of "object"
     object.finish().expect("failed to parse inner object");
@@ -1,5 +1,6 @@
 use core::fmt;
 
+use super::ValueKind;
 use crate::{
     array::ParseArrayError, literal::ParseLiteralError, number::ParseNumberError,
     object::ParseObjectError, string::ParseStringError,
@@ -18,6 +19,26 @@ pub enum ParseAnyError {
     Array(ParseArrayError),
     /// A [`ParseLiteralError`] from parsing a [`Literal`](crate::literal::Literal).
     Literal(ParseLiteralError),
+    /// A value was not of the expected kind.
+    TypeMismatch {
+        /// The kind of value that was expected.
+        expected: ValueKind,
+        /// The kind of value that was found.
+        found: ValueKind,
+    },
+    /// A number could not be represented as the requested type.
+    NumberNotRepresentable {
+        /// The name of the requested type.
+        target: &'static str,
+    },
+    /// A `null` was found where a boolean was expected.
+    UnexpectedNull,
+    /// Writing output to a [`fmt::Write`] failed.
+    Write,
+    /// A string could not be borrowed from the JSON, as it contains escape sequences.
+    EscapedString,
+    /// The JSON of a value was requested, but the value had not been fully parsed, for example because parsing it failed earlier.
+    Unfinished,
 }
 
 impl fmt::Display for ParseAnyError {
@@ -29,21 +50,50 @@ impl fmt::Display for ParseAnyError {
             Self::Object(err) => err.fmt(f),
             Self::Array(err) => err.fmt(f),
             Self::Literal(err) => err.fmt(f),
+            Self::TypeMismatch { expected, found } => {
+                write!(f, "Expected a JSON {expected} but found a JSON {found}!")
+            }
+            Self::NumberNotRepresentable { target } => {
+                write!(f, "JSON number cannot be represented as a {target}!")
+            }
+            Self::UnexpectedNull => write!(f, "Expected a JSON boolean but found null!"),
+            Self::Write => write!(f, "Failed to write output!"),
+            Self::EscapedString => write!(
+                f,
+                "JSON string contains escape sequences, so it cannot be borrowed!"
+            ),
+            Self::Unfinished => write!(
+                f,
+                "JSON value has not been fully parsed, so its JSON is not available!"
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseAnyError {
+impl core::error::Error for ParseAnyError {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(match self {
-            Self::String(err) => err,
-            Self::Number(err) => err,
-            Self::Object(err) => err,
-            Self::Array(err) => err,
-            Self::Literal(err) => err,
-        })
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::String(err) => Some(err),
+            Self::Number(err) => Some(err),
+            Self::Object(err) => Some(err),
+            Self::Array(err) => Some(err),
+            Self::Literal(err) => Some(err),
+            Self::TypeMismatch { .. }
+            | Self::NumberNotRepresentable { .. }
+            | Self::UnexpectedNull
+            | Self::Write
+            | Self::EscapedString
+            | Self::Unfinished => None,
+        }
+    }
+}
+
+impl From<fmt::Error> for ParseAnyError {
+    #[inline]
+    fn from(_: fmt::Error) -> Self {
+        Self::Write
     }
 }
 
@@ -0,0 +1,121 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use super::ValueKind;
+use super::{Any, ParseAnyError};
+use crate::{
+    number::{FromNumber, ParsedNumber},
+    string::ParsedString,
+};
+
+/// A type that can be parsed from any JSON value.
+///
+/// This is implemented for numbers, strings, [`prim@bool`] and [`Option`], where `null` is [`None`].
+/// With the `alloc` feature, it is also implemented for `Vec` from arrays and `BTreeMap` with `String` keys from objects.
+/// Use [`Document::parse`](crate::document::Document::parse) to parse a whole document.
+///
+/// Implementations should return [`ParseAnyError::TypeMismatch`] if the value is of the wrong kind.
+/// Callers are expected to finish the value afterwards, so implementations do not need to.
+pub trait FromAny<'json>: Sized {
+    /// Parse `value` into this type.
+    ///
+    /// # Errors
+    /// If `value` cannot be parsed into this type, or if parsing it fails, a [`ParseAnyError`] is returned.
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError>;
+}
+
+impl<'json, T: FromNumber> FromAny<'json> for T {
+    #[inline]
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        Self::from_number(value.read_number()?)
+            .ok_or(ParseAnyError::NumberNotRepresentable { target: Self::NAME })
+    }
+}
+
+impl<'json> FromAny<'json> for ParsedNumber<'json> {
+    #[inline]
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        value.read_number()
+    }
+}
+
+impl<'json> FromAny<'json> for ParsedString<'json> {
+    #[inline]
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        value.read_string()
+    }
+}
+
+impl<'json> FromAny<'json> for bool {
+    #[inline]
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        value
+            .read_literal()?
+            .as_bool()
+            .ok_or(ParseAnyError::UnexpectedNull)
+    }
+}
+
+impl<'json, T: FromAny<'json>> FromAny<'json> for Option<T> {
+    #[inline]
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        if let Any::Literal(literal) = value {
+            if literal.get()?.is_null() {
+                return Ok(None);
+            }
+        }
+
+        T::from_any(value).map(Some)
+    }
+}
+
+impl<'json> FromAny<'json> for &'json str {
+    #[inline]
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        let string = value.read_string()?;
+
+        if string.has_escapes() {
+            Err(ParseAnyError::EscapedString)
+        } else {
+            Ok(string.unescaped())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'json, T: FromAny<'json>> FromAny<'json> for Vec<T> {
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        let Any::Array(array) = value else {
+            return Err(value.mismatch(ValueKind::Array));
+        };
+
+        let mut vec = Self::new();
+        while let Some(element) = array.next_typed()? {
+            vec.push(element);
+        }
+
+        Ok(vec)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'json, T: FromAny<'json>> FromAny<'json> for BTreeMap<String, T> {
+    fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+        let Any::Object(object) = value else {
+            return Err(value.mismatch(ValueKind::Object));
+        };
+
+        let mut map = Self::new();
+        while let Some((key, mut value)) = object.next()? {
+            let parsed = T::from_any(&mut value)?;
+            value.finish()?;
+            map.insert(key.escaped(), parsed);
+        }
+
+        Ok(map)
+    }
+}
@@ -0,0 +1,38 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The kind of a JSON value.
+pub enum ValueKind {
+    /// A [`String`](crate::string::String) value.
+    String,
+    /// A [`Number`](crate::number::Number) value.
+    Number,
+    /// An [`Object`](crate::object::Object) value.
+    Object,
+    /// An [`Array`](crate::array::Array) value.
+    Array,
+    /// A [`Literal`](crate::literal::Literal) value.
+    Literal,
+}
+
+impl ValueKind {
+    #[must_use]
+    #[inline]
+    /// Returns the name of the kind.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Literal => "literal",
+        }
+    }
+}
+
+impl fmt::Display for ValueKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,7 +1,18 @@
-use crate::{array::Array, literal::Literal, number::Number, object::Object, string::String};
+use crate::{
+    array::Array,
+    document::Document,
+    literal::{Literal, ParsedLiteral},
+    number::{Number, ParsedNumber},
+    object::Object,
+    string::{ParsedString, String},
+};
 
 mod error;
+mod from;
+mod kind;
 pub use error::ParseAnyError;
+pub use from::FromAny;
+pub use kind::ValueKind;
 
 #[derive(Debug)]
 /// Any JSON value.
@@ -50,7 +61,7 @@ macro_rules! as_impl {
         #[inline]
         #[must_use]
         #[doc = concat!("Get the value as ", $a, " [`&mut ", $type_name, "`].")]
-        pub fn $mut_as(&mut self) -> Option<&mut $type> {
+        pub const fn $mut_as(&mut self) -> Option<&mut $type> {
             if let $variant = self {
                 Some($value)
             } else {
@@ -148,6 +159,141 @@ impl<'json, 'p> Any<'json, 'p> {
         Ok(())
     }
 
+    #[must_use]
+    #[inline]
+    /// Returns the kind of the value.
+    pub const fn kind(&self) -> ValueKind {
+        match self {
+            Self::String(_) => ValueKind::String,
+            Self::Number(_) => ValueKind::Number,
+            Self::Object(_) => ValueKind::Object,
+            Self::Array(_) => ValueKind::Array,
+            Self::Literal(_) => ValueKind::Literal,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the value (and all of its children) has been fully parsed, so the parent can continue.
+    pub const fn is_finished(&self) -> bool {
+        match self {
+            Self::String(string) => string.is_finished(),
+            Self::Number(number) => number.is_finished(),
+            Self::Object(object) => object.is_exhausted(),
+            Self::Array(array) => array.is_exhausted(),
+            Self::Literal(literal) => literal.is_finished(),
+        }
+    }
+
+    /// Parse the value as a string.
+    ///
+    /// # Errors
+    /// - If the value is not a string, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing the string fails, this will return [`ParseAnyError::String`].
+    pub fn read_string(&mut self) -> Result<ParsedString<'json>, ParseAnyError> {
+        match self {
+            Self::String(string) => Ok(string.get()?),
+            _ => Err(self.mismatch(ValueKind::String)),
+        }
+    }
+
+    /// Parse the value as a number.
+    ///
+    /// # Errors
+    /// - If the value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing the number fails, this will return [`ParseAnyError::Number`].
+    pub fn read_number(&mut self) -> Result<ParsedNumber<'json>, ParseAnyError> {
+        match self {
+            Self::Number(number) => Ok(number.get()?),
+            _ => Err(self.mismatch(ValueKind::Number)),
+        }
+    }
+
+    /// Parse the value as a literal.
+    ///
+    /// # Errors
+    /// - If the value is not a literal, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing the literal fails, this will return [`ParseAnyError::Literal`].
+    pub fn read_literal(&mut self) -> Result<ParsedLiteral, ParseAnyError> {
+        match self {
+            Self::Literal(literal) => Ok(literal.get()?),
+            _ => Err(self.mismatch(ValueKind::Literal)),
+        }
+    }
+
+    /// Get the value as an [`Object`], to be parsed.
+    ///
+    /// Unlike [`Self::object`], this returns an error that can be propagated with the other parsing errors.
+    ///
+    /// # Errors
+    /// If the value is not an object, this will return [`ParseAnyError::TypeMismatch`].
+    pub const fn read_object(self) -> Result<Object<'json, 'p>, ParseAnyError> {
+        match self {
+            Self::Object(object) => Ok(object),
+            _ => Err(self.mismatch(ValueKind::Object)),
+        }
+    }
+
+    /// Get the value as an [`Array`], to be parsed.
+    ///
+    /// Unlike [`Self::array`], this returns an error that can be propagated with the other parsing errors.
+    ///
+    /// # Errors
+    /// If the value is not an array, this will return [`ParseAnyError::TypeMismatch`].
+    pub const fn read_array(self) -> Result<Array<'json, 'p>, ParseAnyError> {
+        match self {
+            Self::Array(array) => Ok(array),
+            _ => Err(self.mismatch(ValueKind::Array)),
+        }
+    }
+
+    #[inline]
+    const fn mismatch(&self, expected: ValueKind) -> ParseAnyError {
+        ParseAnyError::TypeMismatch {
+            expected,
+            found: self.kind(),
+        }
+    }
+
+    /// Finish parsing the value and create a new [`Document`] from its JSON.
+    ///
+    /// The new document is independent of the parent, so it can outlive it, and the parent can continue as normal.
+    ///
+    /// # Errors
+    /// - If parsing fails in this value or a child, the error is returned as a [`ParseAnyError`].
+    /// - If the value could not be finished, because parsing it failed earlier, this will return [`ParseAnyError::Unfinished`].
+    pub fn into_subdocument(&mut self) -> Result<Document<'json>, ParseAnyError> {
+        self.finish()?;
+        let span = self.span().ok_or(ParseAnyError::Unfinished)?;
+
+        Ok(Document::new(span))
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the JSON of the value, if it has been fully parsed.
+    pub(crate) fn span(&self) -> Option<&'json str> {
+        match self {
+            Self::String(string) => string.span(),
+            Self::Number(number) => number.span(),
+            Self::Object(object) => object.span(),
+            Self::Array(array) => array.span(),
+            Self::Literal(literal) => literal.span(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn remaining(&self) -> &'json str {
+        match self {
+            Self::String(string) => string.remaining(),
+            Self::Number(number) => number.remaining(),
+            Self::Object(object) => object.remaining(),
+            Self::Array(array) => array.remaining(),
+            Self::Literal(literal) => literal.remaining(),
+        }
+    }
+
     as_impl! {
         Self::String(value) => String<'json, 'p> ["String"] value:
         string, string_or, string_or_else,
@@ -178,3 +324,220 @@ impl<'json, 'p> Any<'json, 'p> {
         mut_literal, mut_literal_or, mut_literal_or_else
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{document::Document, literal::ParsedLiteral, string::ParseStringError};
+
+    use super::{Any, ParseAnyError, ValueKind};
+
+    #[test]
+    fn read() {
+        let values = [
+            (ValueKind::String, r#""a""#),
+            (ValueKind::Number, "1"),
+            (ValueKind::Object, "{}"),
+            (ValueKind::Array, "[]"),
+            (ValueKind::Literal, "null"),
+        ];
+
+        for (found, json) in values {
+            for (expected, _) in values {
+                let mut document = Document::new(json);
+                let mut value = document
+                    .next()
+                    .expect("failed to parse document")
+                    .expect("got no values in document");
+
+                let result = match expected {
+                    ValueKind::String => value.read_string().map(drop),
+                    ValueKind::Number => value.read_number().map(drop),
+                    ValueKind::Literal => value.read_literal().map(drop),
+                    ValueKind::Object => value.read_object().and_then(|mut o| o.finish()),
+                    ValueKind::Array => value.read_array().and_then(|mut a| a.finish()),
+                };
+
+                if expected == found {
+                    result.expect("failed to read value");
+                    document.finish().expect("failed to parse document");
+                } else {
+                    assert_eq!(
+                        result,
+                        Err(ParseAnyError::TypeMismatch { expected, found }),
+                        "{json}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn read_values() {
+        let mut document = Document::new(r#"["a\tb", 2, false, "\x"]"#);
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .read_array()
+            .expect("failed to read array");
+
+        let mut value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+        let string = value.read_string().expect("failed to read string");
+        assert_eq!(string, "a\tb");
+
+        let mut value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+        let number = value.read_number().expect("failed to read number");
+        assert_eq!(number, 2);
+
+        let mut value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+        let literal = value.read_literal().expect("failed to read literal");
+        assert_eq!(literal, ParsedLiteral::False);
+
+        let mut value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+        let error = value
+            .read_string()
+            .expect_err("failed to return error from invalid string");
+        assert_eq!(
+            error,
+            ParseAnyError::String(ParseStringError::InvalidEscape { c: 'x', offset: 1 })
+        );
+    }
+
+    #[test]
+    fn into_subdocument() {
+        let json = r#"{"a": {"b": [1, "x\"]"], "c": null}, "d": 2}"#;
+
+        let mut document = Document::new(json);
+        let mut root = document
+            .next()
+            .expect("failed to parse document")
+            .and_then(Any::object)
+            .expect("failed to get object from document");
+
+        let (key, mut value) = root
+            .next()
+            .expect("failed to parse object")
+            .expect("failed to get value from object");
+        assert_eq!(key, "a");
+
+        let mut subdocument = value
+            .into_subdocument()
+            .expect("failed to create subdocument");
+
+        let (key, mut value) = root
+            .next()
+            .expect("failed to parse object")
+            .expect("failed to get value from object");
+        assert_eq!(key, "d");
+        let number = value
+            .mut_number()
+            .expect("failed to get number from object")
+            .get()
+            .expect("failed to parse number");
+        assert_eq!(number, 2_u8);
+
+        root.finish().expect("failed to parse object");
+        document.finish().expect("failed to parse document");
+
+        let mut object = subdocument
+            .next()
+            .expect("failed to parse subdocument")
+            .and_then(Any::object)
+            .expect("failed to get object from subdocument");
+
+        let (key, value) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("failed to get value from object");
+        assert_eq!(key, "b");
+        let mut array = value.array().expect("failed to get array from object");
+        let count = array.count().expect("failed to parse array");
+        assert_eq!(count, 2);
+
+        let (key, value) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("failed to get value from object");
+        assert_eq!(key, "c");
+        let literal = value
+            .literal()
+            .expect("failed to get literal from object")
+            .get()
+            .expect("failed to parse literal");
+        assert!(literal.is_null());
+
+        object.finish().expect("failed to parse object");
+        subdocument.finish().expect("failed to parse subdocument");
+    }
+
+    #[test]
+    fn into_subdocument_scalars() {
+        let json = r#"["a\"b", -1.5e3, true]"#;
+        let expected = [r#""a\"b""#, "-1.5e3", "true"];
+
+        let mut document = Document::new(json);
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .and_then(Any::array)
+            .expect("failed to get array from document");
+
+        for expected in expected {
+            let mut value = array
+                .next()
+                .expect("failed to parse array")
+                .expect("failed to get value from array");
+            let mut subdocument = value
+                .into_subdocument()
+                .expect("failed to create subdocument");
+
+            let mut value = subdocument
+                .next()
+                .expect("failed to parse subdocument")
+                .expect("failed to get value from subdocument");
+            value.finish().expect("failed to parse value");
+            assert_eq!(value.span(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn subdocument_outlives_parent() {
+        fn extract(json: &str) -> Document<'_> {
+            let mut document = Document::new(json);
+            let mut array = document
+                .next()
+                .expect("failed to parse document")
+                .and_then(Any::array)
+                .expect("failed to get array from document");
+
+            array
+                .next()
+                .expect("failed to parse array")
+                .expect("failed to get value from array")
+                .into_subdocument()
+                .expect("failed to create subdocument")
+        }
+
+        let mut subdocument = extract("[[1, 2], 3]");
+        let count = subdocument
+            .next()
+            .expect("failed to parse subdocument")
+            .and_then(Any::array)
+            .expect("failed to get array from subdocument")
+            .count()
+            .expect("failed to parse array");
+        assert_eq!(count, 2);
+    }
+}
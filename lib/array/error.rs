@@ -1,43 +1,165 @@
 use core::fmt;
 
+use crate::debug::DisplayChar;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 /// The error returned when parsing an [`Array`](super::Array) fails.
+///
+/// Syntax errors carry the zero-based `index` of the element that was being parsed, or that had just been parsed, when the error occurred.
 pub enum ParseArrayError {
     /// The JSON string ended before the array was terminated.
-    UnexpectedEnd,
+    UnexpectedEnd {
+        /// The index of the element.
+        index: usize,
+    },
     /// A character that was not the start of an element was found where an element was expected.
     InvalidElement {
         /// The character found.
         c: char,
         /// If the array could have been terminated here.
         or_end: bool,
+        /// The index of the element expected.
+        index: usize,
     },
     /// A character that was not a comma or a terminator (']') was found directly after a value.
-    ExpectedCommaOrEnd(char),
-    /// The array was terminated directly after a comma.
-    TrailingComma,
+    ExpectedCommaOrEnd {
+        /// The character found.
+        c: char,
+        /// The index of the value.
+        index: usize,
+    },
+    /// The array was terminated directly after a comma, and trailing commas are not allowed by the [`ParseOptions`](crate::ParseOptions).
+    TrailingComma {
+        /// The index of the element expected after the comma.
+        index: usize,
+    },
+    /// A value was requested by index, but it had already been consumed.
+    AlreadyConsumed {
+        /// The index requested.
+        index: usize,
+        /// The number of values already consumed.
+        consumed: usize,
+    },
+    /// The array had fewer values than expected.
+    TooFewElements {
+        /// The number of values expected.
+        expected: usize,
+        /// The number of values found.
+        found: usize,
+    },
+    /// The array had more values than could be stored.
+    TooManyElements {
+        /// The number of values that could be stored.
+        capacity: usize,
+    },
+    /// The array did not have the expected number of values.
+    LengthMismatch {
+        /// The number of values expected.
+        expected: usize,
+        /// The number of values found.
+        actual: usize,
+    },
 }
 
 impl fmt::Display for ParseArrayError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::UnexpectedEnd => write!(f, "Unexpected end of JSON array!"),
-            Self::InvalidElement { c, or_end: true } => write!(
+            Self::UnexpectedEnd { index } => {
+                write!(f, "Unexpected end of JSON array at element {index}!")
+            }
+            Self::InvalidElement {
+                c,
+                or_end: true,
+                index,
+            } => write!(
+                f,
+                "Invalid character ({c}) in JSON array at element {index} (expected an element or an end, ']')!",
+                c = DisplayChar(*c)
+            ),
+            Self::InvalidElement {
+                c,
+                or_end: false,
+                index,
+            } => write!(
+                f,
+                "Invalid character ({c}) in JSON array at element {index} (expected an element)!",
+                c = DisplayChar(*c)
+            ),
+            Self::ExpectedCommaOrEnd { c, index } => write!(
                 f,
-                "Invalid character ({c}) in JSON array (expected an element or an end, ']')!"
+                "Invalid character ({c}) in JSON array at element {index} (expected a comma or an end, ']')",
+                c = DisplayChar(*c)
             ),
-            Self::InvalidElement { c, or_end: false } => write!(
+            Self::TrailingComma { index } => {
+                write!(f, "Trailing comma in JSON array at element {index}!")
+            }
+            Self::AlreadyConsumed { index, consumed } => write!(
                 f,
-                "Invalid character ({c}) in JSON array (expected an element)!"
+                "Element {index} of JSON array has already been consumed ({consumed} elements consumed)!"
             ),
-            Self::ExpectedCommaOrEnd(c) => write!(
+            Self::TooFewElements { expected, found } => write!(
                 f,
-                "Invalid character ({c}) in JSON array (expected a comma or an end, ']')"
+                "Too few elements in JSON array (expected {expected}, found {found})!"
+            ),
+            Self::TooManyElements { capacity } => write!(
+                f,
+                "Too many elements in JSON array (expected at most {capacity})!"
+            ),
+            Self::LengthMismatch { expected, actual } => write!(
+                f,
+                "Wrong number of elements in JSON array (expected {expected}, found {actual})!"
             ),
-            Self::TrailingComma => write!(f, "Trailing comma in JSON array!"),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseArrayError {}
+impl core::error::Error for ParseArrayError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseArrayError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseArrayError::InvalidElement {
+                c: '\0',
+                or_end: true,
+                index: 0,
+            }
+            .to_string(),
+            "Invalid character (U+0000) in JSON array at element 0 (expected an element or an end, ']')!"
+        );
+        assert_eq!(
+            ParseArrayError::InvalidElement {
+                c: 'j',
+                or_end: false,
+                index: 3,
+            }
+            .to_string(),
+            "Invalid character (j) in JSON array at element 3 (expected an element)!"
+        );
+        assert_eq!(
+            ParseArrayError::ExpectedCommaOrEnd {
+                c: '\u{1b}',
+                index: 12_345,
+            }
+            .to_string(),
+            "Invalid character (U+001B) in JSON array at element 12345 (expected a comma or an end, ']')"
+        );
+        assert_eq!(
+            ParseArrayError::UnexpectedEnd { index: 2 }.to_string(),
+            "Unexpected end of JSON array at element 2!"
+        );
+        assert_eq!(
+            ParseArrayError::LengthMismatch {
+                expected: 2,
+                actual: 3
+            }
+            .to_string(),
+            "Wrong number of elements in JSON array (expected 2, found 3)!"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use core::{fmt, iter::FusedIterator};
+
+use super::Array;
+use crate::any::{Any, ParseAnyError};
+
+/// An iterator over the values of an array, converted with a closure, created by [`Array::iter_with`].
+pub struct IterWith<'a, 'json, 'p, F> {
+    array: &'a mut Array<'json, 'p>,
+    f: F,
+    done: bool,
+}
+
+impl<'a, 'json, 'p, F> IterWith<'a, 'json, 'p, F> {
+    #[inline]
+    pub(super) const fn new(array: &'a mut Array<'json, 'p>, f: F) -> Self {
+        Self {
+            array,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<'a, 'json, 'p, T, F> IterWith<'a, 'json, 'p, F>
+where
+    F: FnMut(&mut Any<'json, '_>) -> Result<T, ParseAnyError>,
+{
+    fn try_next(&mut self) -> Result<Option<T>, ParseAnyError> {
+        let Some(mut value) = self.array.next()? else {
+            return Ok(None);
+        };
+
+        let converted = (self.f)(&mut value)?;
+        value.finish()?;
+
+        Ok(Some(converted))
+    }
+}
+
+impl<'a, 'json, 'p, T, F> Iterator for IterWith<'a, 'json, 'p, F>
+where
+    F: FnMut(&mut Any<'json, '_>) -> Result<T, ParseAnyError>,
+{
+    type Item = Result<T, ParseAnyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.try_next();
+        // Stop after the end or the first error
+        self.done = !matches!(result, Ok(Some(_)));
+
+        result.transpose()
+    }
+}
+
+impl<'a, 'json, 'p, T, F> FusedIterator for IterWith<'a, 'json, 'p, F> where
+    F: FnMut(&mut Any<'json, '_>) -> Result<T, ParseAnyError>
+{
+}
+
+impl<'a, 'json, 'p, F> fmt::Debug for IterWith<'a, 'json, 'p, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterWith")
+            .field("array", &self.array)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,4 +1,7 @@
-use crate::containers::{ParsePrompt, ParseStatus};
+use crate::{
+    containers::{ParsePrompt, ParseStatus},
+    ParseOptions,
+};
 
 use super::ParseArrayError;
 
@@ -10,14 +13,29 @@ pub enum Machine {
 }
 
 impl Machine {
-    pub fn apply(self, c: char) -> Result<Self, ParseArrayError> {
+    /// Returns the index of the element being parsed, or that has just been parsed, given the number of elements `consumed`.
+    pub const fn index(self, consumed: usize) -> usize {
+        match self {
+            Self::Element(_) => consumed - 1,
+            Self::In { .. } | Self::End => consumed,
+        }
+    }
+
+    pub fn apply(
+        self,
+        c: char,
+        consumed: usize,
+        options: ParseOptions,
+    ) -> Result<Self, ParseArrayError> {
+        let index = self.index(consumed);
+
         match self {
             Self::In { postcomma } => match c {
                 w if w.is_whitespace() => Ok(self),
 
                 ']' => {
-                    if postcomma {
-                        Err(ParseArrayError::TrailingComma)
+                    if postcomma && !options.trailing_commas_allowed() {
+                        Err(ParseArrayError::TrailingComma { index })
                     } else {
                         Ok(Self::End)
                     }
@@ -28,6 +46,7 @@ impl Machine {
                     .ok_or(ParseArrayError::InvalidElement {
                         c,
                         or_end: !postcomma,
+                        index,
                     }),
             },
 
@@ -37,10 +56,46 @@ impl Machine {
                 ',' => Ok(Self::In { postcomma: true }),
                 ']' => Ok(Self::End),
 
-                _ => Err(ParseArrayError::ExpectedCommaOrEnd(c)),
+                _ => Err(ParseArrayError::ExpectedCommaOrEnd { c, index }),
             },
 
-            _ => panic!(),
+            // `Array::next` yields the prompted value or returns before applying another character
+            Self::Element(ParseStatus::Prompted(_)) | Self::End => {
+                unreachable!("applied a character to an array in the {self:?} state")
+            }
         }
     }
 }
+
+/// Find the first comma or `terminator` (`]` for arrays, `}` for objects) in `json` that is not in a string or nested container.
+///
+/// Returns its byte index and whether it is the terminator, or [`None`] if there is none.
+pub fn find_separator(json: &str, terminator: char) -> Option<(usize, bool)> {
+    let mut depth = 0_usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in json.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '[' | '{' => depth += 1,
+            c if c == terminator && depth == 0 => return Some((i, true)),
+            ',' if depth == 0 => return Some((i, false)),
+            // Unbalanced terminators of the other kind are skipped
+            ']' | '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    None
+}
@@ -1,20 +1,46 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::number::Integer;
 use crate::{
-    any::{Any, ParseAnyError},
-    containers::{fff_impl, ParseStatus},
+    any::{Any, FromAny, ParseAnyError, ValueKind},
+    containers::{fff_impl, next_char, span, ParsePrompt, ParseStatus},
     debug::debug_impl,
-    Parent,
+    number::{FromNumber, ParsedNumber},
+    string::ParsedString,
+    Parent, ParseOptions,
 };
 
 mod error;
+mod iter_with;
 mod machine;
+mod partial;
 pub use error::ParseArrayError;
-use machine::Machine;
+pub use iter_with::IterWith;
+pub(crate) use machine::{find_separator, Machine};
+use partial::PartialArray;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A saved parsing position in an [`Array`], created by [`Array::checkpoint`].
+pub struct ArrayCheckpoint<'json> {
+    remaining: &'json str,
+    machine: Machine,
+    consumed: usize,
+}
 
 /// A JSON array.
 pub struct Array<'json, 'p> {
     parent: &'p mut dyn Parent<'json>,
+    options: ParseOptions,
+    start: &'json str,
     remaining: &'json str,
     machine: Machine,
+    consumed: usize,
 }
 
 impl<'json, 'p> Parent<'json> for Array<'json, 'p> {
@@ -34,11 +60,19 @@ impl<'json, 'p> Parent<'json> for Array<'json, 'p> {
 }
 
 impl<'json, 'p> Array<'json, 'p> {
-    pub(crate) fn new(parent: &'p mut dyn Parent<'json>, remaining: &'json str) -> Self {
+    pub(crate) fn new(
+        parent: &'p mut dyn Parent<'json>,
+        start: &'json str,
+        options: ParseOptions,
+    ) -> Self {
         Self {
             parent,
-            remaining,
+            options,
+            start,
+            // Skip the opening bracket
+            remaining: &start[1..],
             machine: Machine::In { postcomma: false },
+            consumed: 0,
         }
     }
 
@@ -49,41 +83,179 @@ impl<'json, 'p> Array<'json, 'p> {
     ///
     /// # Errors
     /// If parsing the array fails, this will return a [`ParseArrayError`].
+    /// The array does not move past the error, so calling this again returns the same error, until [`Self::recover_to_next_element`] is called.
     pub fn next(&mut self) -> Result<Option<Any<'json, '_>>, ParseArrayError> {
+        let started = matches!(self.machine, Machine::Element(ParseStatus::Prompted(_)));
+
+        let Some(prompt) = self.advance()? else {
+            self.parent.set_remaining(self.remaining);
+            return Ok(None);
+        };
+
+        if !started {
+            self.machine = Machine::Element(prompt.into());
+            self.consumed += 1;
+        }
+
+        let (remaining, options) = (self.remaining, self.options);
+        Ok(Some(prompt.create(self, remaining, options)))
+    }
+
+    /// Returns the kind of the next value in the array, without yielding it.
+    ///
+    /// Whitespace and commas before the value are skipped, so [`Self::next`] continues from the value.
+    /// Once the array is exhausted, this will return [`None`].
+    ///
+    /// # Errors
+    /// If parsing the array fails, this will return a [`ParseArrayError`], the same as [`Self::next`] would.
+    #[inline]
+    pub fn peek(&mut self) -> Result<Option<ValueKind>, ParseArrayError> {
+        Ok(self.advance()?.map(ParsePrompt::kind))
+    }
+
+    /// Parse up to the start of the next value, returning how it should be parsed, without starting it.
+    ///
+    /// Returns [`None`] if the array has been terminated.
+    fn advance(&mut self) -> Result<Option<ParsePrompt>, ParseArrayError> {
         loop {
             match self.machine {
                 Machine::In { .. } | Machine::Element(ParseStatus::Done) => {}
+                Machine::Element(ParseStatus::Prompted(prompt)) => return Ok(Some(prompt)),
+                Machine::End => return Ok(None),
+            }
 
-                Machine::Element(ParseStatus::Prompted(prompt)) => {
-                    let remaining = self.remaining;
-                    return Ok(Some(prompt.create(self, remaining)));
-                }
+            // Whitespace does not change the state, so it is skipped
+            let (remaining, c) = next_char(self.remaining);
+            self.remaining = remaining;
+            let c = c.ok_or_else(|| ParseArrayError::UnexpectedEnd {
+                index: self.machine.index(self.consumed),
+            })?;
 
-                Machine::End => {
-                    self.parent.set_remaining(self.remaining);
-                    return Ok(None);
-                }
+            // Values are created from their first character, so don't remove `c` from `self.remaining`
+            match self.machine.apply(c, self.consumed, self.options)? {
+                Machine::Element(ParseStatus::Prompted(prompt)) => return Ok(Some(prompt)),
+                machine => self.machine = machine,
             }
 
-            let (i, c) = self
-                .remaining
-                .char_indices()
-                .next()
-                .ok_or(ParseArrayError::UnexpectedEnd)?;
-            self.machine = self.machine.apply(c)?;
+            self.remaining = &self.remaining[c.len_utf8()..];
+        }
+    }
+
+    /// Try to get the next value from the array and convert it to `T`.
+    ///
+    /// The value is finished, even if it cannot be converted, so the next call continues after it.
+    /// Once the array is exhausted, this will return [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// let mut document = Document::new(r#"[42, "a", null]"#);
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// assert_eq!(array.next_typed::<u64>()?, Some(42));
+    /// assert_eq!(array.next_typed::<zjson::string::ParsedString>()?.map(|s| s.unescaped()), Some("a"));
+    /// assert_eq!(array.next_typed::<Option<bool>>()?, Some(None));
+    /// assert_eq!(array.next_typed::<bool>()?, None);
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - If the value is of the wrong kind for `T`, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If the value is a number that cannot be represented as `T`, this will return [`ParseAnyError::NumberNotRepresentable`].
+    /// - If the value is `null` and `T` is [`prim@bool`], this will return [`ParseAnyError::UnexpectedNull`].
+    /// - If parsing fails in this array or the value, the error is returned as a [`ParseAnyError`].
+    ///   This takes priority over conversion errors.
+    ///
+    /// If the error was in the value, that value is at index [`Self::index`] - 1.
+    pub fn next_typed<T: FromAny<'json>>(&mut self) -> Result<Option<T>, ParseAnyError> {
+        let Some(mut value) = self.next()? else {
+            return Ok(None);
+        };
+
+        let converted = T::from_any(&mut value);
+        value.finish()?;
+
+        converted.map(Some)
+    }
+
+    /// Returns [`true`] if there are no more values in the array, without yielding the next value.
+    ///
+    /// If no values have been yielded yet, this is whether the array is empty.
+    /// Only whitespace and commas are skipped, so [`Self::next`] still yields the next value if there is one.
+    ///
+    /// # Errors
+    /// If the JSON string ends before the next value or terminator, this will return [`ParseArrayError::UnexpectedEnd`].
+    /// Other errors are left to be returned by [`Self::next`].
+    pub fn is_empty(&mut self) -> Result<bool, ParseArrayError> {
+        match self.machine {
+            Machine::End => return Ok(true),
+            Machine::Element(ParseStatus::Prompted(_)) => return Ok(false),
+            Machine::In { .. } | Machine::Element(ParseStatus::Done) => {}
+        }
+
+        loop {
+            self.remaining = self.remaining.trim_start();
 
-            // If currently parsing a number or literal, don't remove `c` from `self.remaining`
-            if let Machine::Element(ParseStatus::Prompted(prompt)) = self.machine {
-                if prompt.keep_first() {
-                    continue;
+            match self.remaining.chars().next() {
+                None => {
+                    return Err(ParseArrayError::UnexpectedEnd {
+                        index: self.machine.index(self.consumed),
+                    })
+                }
+
+                // Look past the comma after a value
+                Some(',') if self.machine == Machine::Element(ParseStatus::Done) => {
+                    self.machine = Machine::In { postcomma: true };
+                    self.remaining = &self.remaining[1..];
                 }
-            }
 
-            let next_i = i + c.len_utf8();
-            self.remaining = &self.remaining[next_i..];
+                // A terminator after a comma is an error, which is left to `Self::next`
+                Some(']') => return Ok(!matches!(self.machine, Machine::In { postcomma: true })),
+                Some(_) => return Ok(false),
+            }
         }
     }
 
+    /// Returns an [`Iterator`] over the remaining values, converted with `f`.
+    ///
+    /// Values borrow the array, so they cannot be yielded by an iterator themselves; `f` converts each one into an owned (or `'json`) type instead.
+    /// [`Any::finish`] is automatically called on all values, so it is not needed in `f`.
+    ///
+    /// If parsing fails or `f` returns an error, the error is yielded, then the iterator ends.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::ParseAnyError, document::Document};
+    ///
+    /// let mut document = Document::new(r#"[{"id": 1}, {"id": 2}, {"id": 3}]"#);
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// let ids = array
+    ///     .iter_with(|value| {
+    ///         let (_, mut id) = value.mut_object().expect("expected an object").next()?.expect("expected an id");
+    ///         id.read_number()
+    ///     })
+    ///     .filter_map(|id| id.map(|id| id.as_u32()).transpose())
+    ///     .take_while(|id| id.as_ref().map_or(true, |id| *id < 3))
+    ///     .collect::<Result<Vec<_>, ParseAnyError>>()?;
+    ///
+    /// assert_eq!(ids, [1, 2]);
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub const fn iter_with<T, F>(&mut self, f: F) -> IterWith<'_, 'json, 'p, F>
+    where
+        F: FnMut(&mut Any<'json, '_>) -> Result<T, ParseAnyError>,
+    {
+        IterWith::new(self, f)
+    }
+
     /// Finish parsing the array so that the parent can continue.
     ///
     /// # Errors
@@ -96,117 +268,2148 @@ impl<'json, 'p> Array<'json, 'p> {
         Ok(())
     }
 
-    fff_impl! {
-        type: "array"
-        f(&mut Any<'json, '_>) -> Result<_, ParseAnyError>;
-        accumulator, mut value =>
-            f(&mut value),
-            f(accumulator, &mut value),
-            value;
-        ParseAnyError::Array
+    #[must_use]
+    #[inline]
+    /// Save the current parsing position, so that it can be returned to with [`Self::rollback`].
+    pub const fn checkpoint(&self) -> ArrayCheckpoint<'json> {
+        ArrayCheckpoint {
+            remaining: self.remaining,
+            machine: self.machine,
+            consumed: self.consumed,
+        }
     }
-}
 
-debug_impl!("Array", Array<'json, 'p>);
+    #[inline]
+    /// Return to a parsing position saved with [`Self::checkpoint`], so that values can be parsed again.
+    ///
+    /// `checkpoint` must have been created by this array.
+    ///
+    /// Only this array is rolled back.
+    /// Once the array is exhausted, its parent continues from after it, so rolling back an exhausted array does not rewind the parent; it is the caller's responsibility to only continue parsing the parent once this array has been exhausted again.
+    pub const fn rollback(&mut self, checkpoint: ArrayCheckpoint<'json>) {
+        self.remaining = checkpoint.remaining;
+        self.machine = checkpoint.machine;
+        self.consumed = checkpoint.consumed;
+    }
 
-#[cfg(test)]
-mod test {
-    use crate::test_parent::TestParent;
+    /// Finish parsing the array, checking that it had `expected` values in total.
+    ///
+    /// Values that have already been yielded are counted, so this can be called after reading the values you need.
+    ///
+    /// # Errors
+    /// - If the array did not have `expected` values, this will return [`ParseArrayError::LengthMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::ParseAnyError, array::ParseArrayError, document::Document};
+    ///
+    /// let mut document = Document::new("[1, 2, 3]");
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// let x = array.next()?.expect("expected x").read_number()?;
+    /// let y = array.next()?.expect("expected y").read_number()?;
+    ///
+    /// assert_eq!(
+    ///     array.expect_len(2),
+    ///     Err(ParseAnyError::Array(ParseArrayError::LengthMismatch {
+    ///         expected: 2,
+    ///         actual: 3
+    ///     }))
+    /// );
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn expect_len(&mut self, expected: usize) -> Result<(), ParseAnyError> {
+        self.finish()?;
 
-    use super::ParseArrayError;
+        if self.consumed == expected {
+            Ok(())
+        } else {
+            Err(ParseArrayError::LengthMismatch {
+                expected,
+                actual: self.consumed,
+            }
+            .into())
+        }
+    }
 
-    #[test]
-    fn empty() {
-        let mut parent = TestParent::new("]");
-        let mut array = parent.array();
+    /// Finish parsing the array, checking that no values remain after the ones already yielded.
+    ///
+    /// This is [`Self::expect_len`] with the number of values yielded so far.
+    ///
+    /// # Errors
+    /// - If any values remain, this will return [`ParseArrayError::LengthMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    #[inline]
+    pub fn finish_expect_empty(&mut self) -> Result<(), ParseAnyError> {
+        self.expect_len(self.consumed)
+    }
 
-        let value = array.next().expect("failed to parse array");
-        assert!(value.is_none());
+    /// Finish parsing the array, returning the JSON of the whole array, from the opening bracket to the closing bracket.
+    ///
+    /// Values that have already been yielded are included, so this can be called at any point.
+    /// The remaining values are validated, so the raw JSON can be passed on without being checked again.
+    ///
+    /// # Errors
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///   If the error was in a value, that value is at index [`Self::index`] - 1.
+    /// - If the array could not be finished, because parsing it failed earlier, this will return [`ParseAnyError::Unfinished`].
+    pub fn raw(&mut self) -> Result<&'json str, ParseAnyError> {
+        self.finish()?;
+        self.span().ok_or(ParseAnyError::Unfinished)
+    }
 
-        assert!(parent.remaining.is_empty());
+    /// Skip forward past the current value to the next one, so that parsing can continue after an error.
+    ///
+    /// This scans to the next comma or terminator (`]`) that is not in a string or a nested container, without checking what it skips.
+    /// The skipped value still counts towards [`Self::index`].
+    /// If the last value yielded was finished without an error, this skips the next value instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::ParseAnyError, document::Document};
+    ///
+    /// let mut document = Document::new("[1, -x, 3]");
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// let mut sum = 0;
+    /// loop {
+    ///     let number = match array.next() {
+    ///         Ok(Some(mut value)) => value.read_number(),
+    ///         Ok(None) => break,
+    ///         Err(error) => Err(error.into()),
+    ///     };
+    ///
+    ///     match number {
+    ///         Ok(number) => sum += number.as_u32().expect("expected an integer"),
+    ///         // Skip the invalid value
+    ///         Err(_) => array.recover_to_next_element()?,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum, 4);
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// If the JSON string ends before a comma or terminator, this will return [`ParseArrayError::UnexpectedEnd`].
+    pub fn recover_to_next_element(&mut self) -> Result<(), ParseArrayError> {
+        if matches!(self.machine, Machine::End) {
+            return Ok(());
+        }
+
+        let (i, end) =
+            find_separator(self.remaining, ']').ok_or_else(|| ParseArrayError::UnexpectedEnd {
+                index: self.machine.index(self.consumed),
+            })?;
+
+        // Count a value that failed before it was started
+        if matches!(self.machine, Machine::In { .. }) && !self.remaining[..i].trim().is_empty() {
+            self.consumed += 1;
+        }
+
+        self.remaining = &self.remaining[i + 1..];
+        self.machine = if end {
+            Machine::End
+        } else {
+            Machine::In { postcomma: true }
+        };
+
+        Ok(())
     }
 
-    #[test]
-    fn string() {
-        let expected_value = "value1";
-        let json = format!("\"{expected_value}\"]");
+    /// Returns the index of the value that the next call to [`Self::next`] will yield.
+    const fn next_index(&self) -> usize {
+        // The last value yielded has not been finished, so it will be yielded again
+        if matches!(self.machine, Machine::Element(ParseStatus::Prompted(_))) {
+            self.consumed - 1
+        } else {
+            self.consumed
+        }
+    }
 
-        let mut parent = TestParent::new(&json);
-        let mut array = parent.array();
+    /// Runs `f` for each value in the array until it returns [`true`], returning the number of values before the match, like [`Iterator::position`].
+    ///
+    /// [`Any::finish`] is automatically called on each value iterated over, so it is not needed in `f`.
+    /// The values after the match are left to be parsed, so [`Self::next`] continues from right after it.
+    /// The index of the match from the start of the array is [`Self::index`] - 1.
+    ///
+    /// # Errors
+    /// If parsing fails in this array or a value, or if `f` returns an error, the error is returned as a [`ParseAnyError`].
+    pub fn position<F>(&mut self, mut f: F) -> Result<Option<usize>, ParseAnyError>
+    where
+        F: FnMut(&mut Any<'json, '_>) -> Result<bool, ParseAnyError>,
+    {
+        let mut position = 0;
 
-        let value = array
-            .next()
-            .expect("failed to parse array")
-            .expect("failed to get value from array")
-            .string()
-            .expect("failed to get string from array")
-            .get()
-            .expect("failed to parse string");
+        while let Some(mut value) = self.next()? {
+            let found = f(&mut value)?;
+            value.finish()?;
 
-        assert_eq!(value, expected_value);
+            if found {
+                return Ok(Some(position));
+            }
 
-        let next = array.next().expect("failed to parse array");
-        assert!(next.is_none());
+            position += 1;
+        }
 
-        assert!(parent.remaining.is_empty());
+        Ok(None)
     }
 
-    #[test]
-    fn invalid() {
-        let invalid = 'j';
-        let json = invalid.to_string();
+    /// Skip `n` values and get the next one, like [`Iterator::nth`].
+    ///
+    /// The skipped values are fully parsed, so errors in them are returned.
+    /// If the array ends before the value is reached, this will return [`None`].
+    ///
+    /// # Errors
+    /// If parsing fails in this array or a skipped value, the error is returned as a [`ParseAnyError`].
+    pub fn nth(&mut self, n: usize) -> Result<Option<Any<'json, '_>>, ParseAnyError> {
+        if self.skip(n)? < n {
+            return Ok(None);
+        }
 
-        let mut parent = TestParent::new(&json);
-        let mut array = parent.array();
+        Ok(self.next()?)
+    }
 
-        let error = array
-            .next()
-            .expect_err("failed to return error from invalid array");
+    /// Skip up to `n` values, returning the number of values skipped.
+    ///
+    /// The skipped values are fully parsed, so errors in them are returned.
+    /// If the array ends first, fewer than `n` values are skipped; this is not an error.
+    ///
+    /// # Errors
+    /// If parsing fails in this array or a skipped value, the error is returned as a [`ParseAnyError`].
+    pub fn skip(&mut self, n: usize) -> Result<usize, ParseAnyError> {
+        for skipped in 0..n {
+            let Some(mut value) = self.next()? else {
+                return Ok(skipped);
+            };
 
-        assert_eq!(
-            error,
-            ParseArrayError::InvalidElement {
-                c: invalid,
-                or_end: true
+            value.finish()?;
+        }
+
+        Ok(n)
+    }
+
+    /// Get the value at `index`, counted from the start of the array.
+    ///
+    /// The values before it are fully parsed, so errors in them are returned.
+    /// The array keeps track of how many values have been consumed, so this can be called repeatedly with increasing indices.
+    /// If the array ends before the value is reached, this will return [`None`].
+    ///
+    /// # Errors
+    /// - If the value at `index` has already been consumed, this will return [`ParseArrayError::AlreadyConsumed`].
+    /// - If parsing fails in this array or a skipped value, the error is returned as a [`ParseAnyError`].
+    pub fn get(&mut self, index: usize) -> Result<Option<Any<'json, '_>>, ParseAnyError> {
+        if index < self.consumed {
+            return Err(ParseArrayError::AlreadyConsumed {
+                index,
+                consumed: self.consumed,
             }
-        );
+            .into());
+        }
 
-        assert_eq!(parent.remaining, json);
+        self.nth(index - self.next_index())
     }
 
-    #[test]
-    fn invalid_after_valid() {
-        let expected = "value1";
-        let invalid = 'j';
+    /// Finish parsing the array, returning the number of values remaining.
+    ///
+    /// Nested objects and arrays count as one value.
+    ///
+    /// # Errors
+    /// If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    pub fn count(&mut self) -> Result<usize, ParseAnyError> {
+        self.fold(0, |count, _| Ok(count + 1))
+    }
 
-        let json = format!("\"{expected}\", {invalid}");
+    #[cfg(feature = "alloc")]
+    /// Finish parsing the array, collecting the JSON of each remaining value into a [`Vec`].
+    ///
+    /// Each value is copied exactly as it appears in the JSON, without surrounding whitespace, so it can be parsed again later with a [`Document`](crate::document::Document).
+    /// Values of all types are supported; nested objects and arrays are collected as one value.
+    ///
+    /// # Errors
+    /// If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn collect_raw(&mut self) -> Result<Vec<alloc::string::String>, ParseAnyError> {
+        self.fold(Vec::new(), |mut values, value| {
+            value.finish()?;
+            let raw = value.span().ok_or(ParseAnyError::Unfinished)?;
+            values.push(raw.into());
 
-        let mut parent = TestParent::new(&json);
-        let mut array = parent.array();
+            Ok(values)
+        })
+    }
 
-        let value = array
-            .next()
-            .expect("failed to parse array")
-            .expect("failed to get value from array")
-            .string()
-            .expect("failed to get string from array")
-            .get()
-            .expect("failed to parse string");
+    #[cfg(feature = "alloc")]
+    /// Finish parsing the array, converting each remaining value with `f` and collecting the results into a [`Vec`].
+    ///
+    /// [`Any::finish`] is automatically called on all values, so it is not needed in `f`.
+    /// After the first value, space is reserved for the values estimated to remain, based on the length of the first value.
+    ///
+    /// # Errors
+    /// If parsing fails in this array or a child, or if `f` returns an error, the error is returned as a [`ParseAnyError`].
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn try_map<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ParseAnyError>
+    where
+        F: FnMut(&mut Any<'json, '_>) -> Result<T, ParseAnyError>,
+    {
+        // The most values to reserve space for at once, as the remaining JSON may be
+        // much longer than the array
+        const MAX_RESERVE: usize = 256;
 
-        assert_eq!(value, expected);
+        let mut values = Vec::new();
 
-        let error = array
-            .next()
-            .expect_err("failed to return error from invalid array");
+        loop {
+            let before = self.remaining.len();
 
-        assert_eq!(
-            error,
-            ParseArrayError::InvalidElement {
-                c: invalid,
-                or_end: false
+            let converted = {
+                let Some(mut value) = self.next()? else {
+                    return Ok(values);
+                };
+
+                let converted = f(&mut value)?;
+                value.finish()?;
+                converted
+            };
+
+            if values.is_empty() {
+                let value_len = before - self.remaining.len();
+                values.reserve((self.remaining.len() / value_len).min(MAX_RESERVE) + 1);
             }
-        );
 
-        assert_eq!(parent.remaining, json);
+            values.push(converted);
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "float"))]
+    /// Finish parsing the array, collecting the remaining values, which must all be numbers, into a [`Vec`] of [`prim@f64`]s.
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn collect_numbers(&mut self) -> Result<Vec<f64>, ParseAnyError> {
+        self.collect_primitives()
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Finish parsing the array, collecting the remaining values, which must all be integers, into a [`Vec`] of `T`.
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If a number is not an integer or is out of range for `T`, this will return [`ParseAnyError::NumberNotRepresentable`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn collect_integers<T: Integer>(&mut self) -> Result<Vec<T>, ParseAnyError> {
+        self.collect_primitives()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn collect_primitives<T: FromNumber>(&mut self) -> Result<Vec<T>, ParseAnyError> {
+        self.fold(Vec::new(), |mut values, value| {
+            values.push(T::from_any(value)?);
+
+            Ok(values)
+        })
+    }
+
+    /// Parse the remaining values, which must all be numbers, into `buf`, returning the number of values.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    /// The array is finished, so if there are more values than `buf` can hold, an error is returned instead of silently dropping them.
+    /// To stop when `buf` is full, use [`Self::fill_slice_truncating`].
+    ///
+    /// # Errors
+    /// - If there are more values than `buf` can hold, this will return [`ParseArrayError::TooManyElements`].
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If a number cannot be represented as `T`, this will return [`ParseAnyError::NumberNotRepresentable`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn fill_slice<T: FromNumber>(&mut self, buf: &mut [T]) -> Result<usize, ParseAnyError> {
+        let filled = self.fill_slice_truncating(buf)?;
+
+        if self.next()?.is_some() {
+            return Err(ParseArrayError::TooManyElements {
+                capacity: buf.len(),
+            }
+            .into());
+        }
+
+        Ok(filled)
+    }
+
+    /// Parse values, which must all be numbers, into `buf` until either it is full or the array ends, returning the number of values.
+    ///
+    /// If `buf` is filled, the rest of the array is left to be parsed.
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If a number cannot be represented as `T`, this will return [`ParseAnyError::NumberNotRepresentable`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn fill_slice_truncating<T: FromNumber>(
+        &mut self,
+        buf: &mut [T],
+    ) -> Result<usize, ParseAnyError> {
+        for (filled, slot) in buf.iter_mut().enumerate() {
+            let Some(mut value) = self.next()? else {
+                return Ok(filled);
+            };
+
+            *slot = T::from_any(&mut value)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Parse exactly `N` remaining values into an array of `T`, then finish the array.
+    ///
+    /// This does not allocate or panic, so it can be used without the `alloc` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::ParseAnyError, document::Document};
+    ///
+    /// let mut document = Document::new("[640, 480]");
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// let [width, height] = array.fixed::<2, u32>()?;
+    /// assert_eq!((width, height), (640, 480));
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - If there are fewer than `N` values, this will return [`ParseArrayError::TooFewElements`].
+    /// - If there are more than `N` values, this will return [`ParseArrayError::TooManyElements`].
+    /// - If a value cannot be converted to `T`, or if parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn fixed<const N: usize, T: FromAny<'json>>(&mut self) -> Result<[T; N], ParseAnyError> {
+        let mut values = PartialArray::<T, N>::new();
+
+        loop {
+            let Some(mut value) = self.next()? else {
+                return values.into_array().map_err(|values| {
+                    ParseArrayError::TooFewElements {
+                        expected: N,
+                        found: values.len(),
+                    }
+                    .into()
+                });
+            };
+
+            if values.is_full() {
+                return Err(ParseArrayError::TooManyElements { capacity: N }.into());
+            }
+
+            let converted = T::from_any(&mut value)?;
+            value.finish()?;
+            values.push(converted);
+        }
+    }
+
+    /// Finish parsing the array, where each remaining value is a two-element array, such as `[["a", 1], ["b", 2]]`.
+    ///
+    /// For each pair, `f_key` is run on the first element, then `f_value` is run on what it returned and the second element.
+    /// The elements cannot be borrowed at the same time, as they belong to the same array.
+    ///
+    /// [`Any::finish`] is automatically called on all values, so it is not needed in `f_key` or `f_value`.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::ParseAnyError, document::Document};
+    ///
+    /// let mut document = Document::new(r#"[["a", 1], ["b", 2]]"#);
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// let mut total = 0;
+    /// array.for_each_pair(
+    ///     |key| key.read_string(),
+    ///     |key, value| {
+    ///         println!("{key}");
+    ///         total += value.read_number()?.as_u32().expect("expected an integer");
+    ///         Ok(())
+    ///     },
+    /// )?;
+    ///
+    /// assert_eq!(total, 3);
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - If a value is not an array, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If a pair has fewer than two elements, this will return [`ParseArrayError::TooFewElements`].
+    /// - If a pair has more than two elements, this will return [`ParseArrayError::TooManyElements`].
+    /// - If parsing fails in this array or a child, or if `f_key` or `f_value` returns an error, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a pair, that pair is at index [`Self::index`] - 1.
+    pub fn for_each_pair<T, K, V>(
+        &mut self,
+        mut f_key: K,
+        mut f_value: V,
+    ) -> Result<(), ParseAnyError>
+    where
+        K: FnMut(&mut Any<'json, '_>) -> Result<T, ParseAnyError>,
+        V: FnMut(T, &mut Any<'json, '_>) -> Result<(), ParseAnyError>,
+    {
+        while let Some(mut value) = self.next()? {
+            let Any::Array(pair) = &mut value else {
+                return Err(ParseAnyError::TypeMismatch {
+                    expected: ValueKind::Array,
+                    found: value.kind(),
+                });
+            };
+
+            let Some(mut key) = pair.next()? else {
+                return Err(ParseArrayError::TooFewElements {
+                    expected: 2,
+                    found: 0,
+                }
+                .into());
+            };
+            let key_output = f_key(&mut key)?;
+            key.finish()?;
+
+            let Some(mut value) = pair.next()? else {
+                return Err(ParseArrayError::TooFewElements {
+                    expected: 2,
+                    found: 1,
+                }
+                .into());
+            };
+            f_value(key_output, &mut value)?;
+            value.finish()?;
+
+            if pair.next()?.is_some() {
+                return Err(ParseArrayError::TooManyElements { capacity: 2 }.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs `f` for each value in the array, which must all be strings.
+    ///
+    /// # Errors
+    /// - If a value is not a string, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, or if `f` returns an error, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn strings<F>(&mut self, mut f: F) -> Result<(), ParseAnyError>
+    where
+        F: FnMut(ParsedString<'json>) -> Result<(), ParseAnyError>,
+    {
+        self.fold_strings((), |(), string| f(string))
+    }
+
+    /// Applies `f` to the accumulator, passing in each value in the array, which must all be strings.
+    ///
+    /// The initial value of the accumulator is `init`.
+    ///
+    /// # Errors
+    /// - If a value is not a string, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, or if `f` returns an error, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn fold_strings<B, F>(&mut self, init: B, mut f: F) -> Result<B, ParseAnyError>
+    where
+        F: FnMut(B, ParsedString<'json>) -> Result<B, ParseAnyError>,
+    {
+        self.fold(init, |accumulator, value| {
+            f(accumulator, value.read_string()?)
+        })
+    }
+
+    /// Writes each value in the array, which must all be strings, to `out`, with `sep` between them.
+    ///
+    /// The strings are written decoded (as from `escaped()`, with escape sequences processed), without allocating.
+    ///
+    /// # Errors
+    /// - If a value is not a string, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If writing to `out` fails, this will return [`ParseAnyError::Write`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// let mut document = Document::new(r#"["a", "b\nc", "d"]"#);
+    /// let mut array = document
+    ///     .next()?
+    ///     .expect("expected a value")
+    ///     .read_array()?;
+    ///
+    /// let mut out = String::new();
+    /// array.join_to(", ", &mut out)?;
+    /// assert_eq!(out, "a, b\nc, d");
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn join_to<W>(&mut self, sep: &str, out: &mut W) -> Result<(), ParseAnyError>
+    where
+        W: fmt::Write + ?Sized,
+    {
+        self.fold_strings(true, |first, string| {
+            if !first {
+                out.write_str(sep)?;
+            }
+            for c in string.chars() {
+                out.write_char(c)?;
+            }
+
+            Ok(false)
+        })?;
+
+        Ok(())
+    }
+
+    /// Runs `f` for each value in the array, which must all be numbers.
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, or if `f` returns an error, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn numbers<F>(&mut self, mut f: F) -> Result<(), ParseAnyError>
+    where
+        F: FnMut(ParsedNumber<'json>) -> Result<(), ParseAnyError>,
+    {
+        self.fold_numbers((), |(), number| f(number))
+    }
+
+    /// Applies `f` to the accumulator, passing in each value in the array, which must all be numbers.
+    ///
+    /// The initial value of the accumulator is `init`.
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, or if `f` returns an error, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn fold_numbers<B, F>(&mut self, init: B, mut f: F) -> Result<B, ParseAnyError>
+    where
+        F: FnMut(B, ParsedNumber<'json>) -> Result<B, ParseAnyError>,
+    {
+        self.fold(init, |accumulator, value| {
+            f(accumulator, value.read_number()?)
+        })
+    }
+
+    #[cfg(feature = "float")]
+    /// Finish parsing the array, returning the sum of the remaining values, which must all be numbers, as an [`f64`].
+    ///
+    /// If there are no remaining values, this will return `0.0`.
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn sum_f64(&mut self) -> Result<f64, ParseAnyError> {
+        self.fold_numbers(0.0, |sum, number| Ok(sum + number.as_f64()))
+    }
+
+    #[cfg(feature = "float")]
+    /// Finish parsing the array, returning the mean of the remaining values, which must all be numbers, as an [`f64`].
+    ///
+    /// If there are no remaining values, this will return [`None`].
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn mean_f64(&mut self) -> Result<Option<f64>, ParseAnyError> {
+        let (sum, count) = self.fold_numbers((0.0, 0_u64), |(sum, count), number| {
+            Ok((sum + number.as_f64(), count + 1))
+        })?;
+
+        #[allow(clippy::cast_precision_loss)]
+        Ok((count != 0).then(|| sum / count as f64))
+    }
+
+    #[cfg(feature = "float")]
+    /// Finish parsing the array, returning the largest of the remaining values, which must all be numbers.
+    ///
+    /// Numbers are compared as [`f64`]s. If several values are equal to the largest, the first is returned.
+    /// If there are no remaining values, this will return [`None`].
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn max_number(&mut self) -> Result<Option<ParsedNumber<'json>>, ParseAnyError> {
+        self.extreme_number(|number, max| number > max)
+    }
+
+    #[cfg(feature = "float")]
+    /// Finish parsing the array, returning the smallest of the remaining values, which must all be numbers.
+    ///
+    /// Numbers are compared as [`f64`]s. If several values are equal to the smallest, the first is returned.
+    /// If there are no remaining values, this will return [`None`].
+    ///
+    /// # Errors
+    /// - If a value is not a number, this will return [`ParseAnyError::TypeMismatch`].
+    /// - If parsing fails in this array or a child, the error is returned as a [`ParseAnyError`].
+    ///
+    /// If the error was in a value, that value is at index [`Self::index`] - 1.
+    pub fn min_number(&mut self) -> Result<Option<ParsedNumber<'json>>, ParseAnyError> {
+        self.extreme_number(|number, min| number < min)
+    }
+
+    #[cfg(feature = "float")]
+    /// Returns the first number for which `replaces` returns [`true`] against every number before it.
+    fn extreme_number(
+        &mut self,
+        replaces: fn(f64, f64) -> bool,
+    ) -> Result<Option<ParsedNumber<'json>>, ParseAnyError> {
+        let mut extreme: Option<(ParsedNumber<'json>, f64)> = None;
+
+        while let Some(mut value) = self.next()? {
+            let number = value.read_number()?;
+            let float = number.as_f64();
+
+            if extreme.is_none_or(|(_, current)| replaces(float, current)) {
+                extreme = Some((number, float));
+            }
+        }
+
+        Ok(extreme.map(|(number, _)| number))
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of values that have been yielded from the array.
+    ///
+    /// Values yielded again because they were not finished are only counted once.
+    /// Once the last value yielded has been finished, this is the index of the next value to be yielded; until then, that value is yielded again, so it is at index [`Self::index`] - 1.
+    pub const fn index(&self) -> usize {
+        self.consumed
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the array has been fully parsed, so the parent can continue.
+    pub const fn is_exhausted(&self) -> bool {
+        matches!(self.machine, Machine::End)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this array is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) fn span(&self) -> Option<&'json str> {
+        self.is_exhausted()
+            .then(|| span(self.start, self.remaining))
+    }
+
+    fff_impl! {
+        type: "array"
+        f(&mut Any<'json, '_>) -> Result<_, ParseAnyError>;
+        accumulator, index, mut value =>
+            f(&mut value),
+            f(accumulator, &mut value),
+            f(index, &mut value),
+            f(accumulator, index, &mut value),
+            value;
+        ParseAnyError::Array;
+        index: "The index counts from the start of the array, including values yielded before this was called." Self::next_index
+    }
+}
+
+debug_impl!("Array", Array<'json, 'p>, index: consumed);
+
+#[cfg(test)]
+mod test {
+    use core::{
+        fmt,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    #[cfg(feature = "float")]
+    use crate::number::ParsedNumber;
+    use crate::{
+        any::{Any, FromAny, ParseAnyError, ValueKind},
+        object::ParseObjectError,
+        string::ParsedString,
+        test_parent::TestParent,
+    };
+
+    use super::ParseArrayError;
+
+    #[test]
+    fn empty() {
+        let mut parent = TestParent::new("[]");
+        let mut array = parent.array();
+
+        let value = array.next().expect("failed to parse array");
+        assert!(value.is_none());
+
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn string() {
+        let expected_value = "value1";
+        let json = format!("[\"{expected_value}\"]");
+
+        let mut parent = TestParent::new(&json);
+        let mut array = parent.array();
+
+        let value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .string()
+            .expect("failed to get string from array")
+            .get()
+            .expect("failed to parse string");
+
+        assert_eq!(value, expected_value);
+
+        let next = array.next().expect("failed to parse array");
+        assert!(next.is_none());
+
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = 'j';
+        let json = format!("[{invalid}");
+
+        let mut parent = TestParent::new(&json);
+        let mut array = parent.array();
+
+        let error = array
+            .next()
+            .expect_err("failed to return error from invalid array");
+
+        assert_eq!(
+            error,
+            ParseArrayError::InvalidElement {
+                c: invalid,
+                or_end: true,
+                index: 0,
+            }
+        );
+
+        assert_eq!(parent.remaining, json);
+    }
+
+    #[test]
+    fn invalid_after_valid() {
+        let expected = "value1";
+        let invalid = 'j';
+
+        let json = format!("[\"{expected}\", {invalid}");
+
+        let mut parent = TestParent::new(&json);
+        let mut array = parent.array();
+
+        let value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .string()
+            .expect("failed to get string from array")
+            .get()
+            .expect("failed to parse string");
+
+        assert_eq!(value, expected);
+
+        let error = array
+            .next()
+            .expect_err("failed to return error from invalid array");
+
+        assert_eq!(
+            error,
+            ParseArrayError::InvalidElement {
+                c: invalid,
+                or_end: false,
+                index: 1,
+            }
+        );
+
+        assert_eq!(parent.remaining, json);
+    }
+
+    #[test]
+    fn nth() {
+        let json = r#"[1, [2, [3]], {"a": [4, 5]}, "6", 7]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let first = array
+            .nth(0)
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .number()
+            .expect("failed to get number from array")
+            .get()
+            .expect("failed to parse number");
+        assert_eq!(first, 1_u8);
+
+        let fourth = array
+            .nth(2)
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .string()
+            .expect("failed to get string from array")
+            .get()
+            .expect("failed to parse string");
+        assert_eq!(fourth, "6");
+
+        let value = array.nth(1).expect("failed to parse array");
+        assert!(value.is_none());
+
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn nth_skipped_error() {
+        let json = r#"[{"a" 1}, 2]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let error = array
+            .nth(1)
+            .expect_err("failed to return error from invalid skipped value");
+
+        assert_eq!(
+            error,
+            ParseAnyError::Object(ParseObjectError::ExpectedColon('1'))
+        );
+    }
+
+    #[test]
+    fn error_index() {
+        let cases = [
+            ("[", ParseArrayError::UnexpectedEnd { index: 0 }),
+            ("[1, 2", ParseArrayError::UnexpectedEnd { index: 1 }),
+            ("[1, 2, ", ParseArrayError::UnexpectedEnd { index: 2 }),
+            (
+                "[1, 2 x]",
+                ParseArrayError::ExpectedCommaOrEnd { c: 'x', index: 1 },
+            ),
+            ("[1, 2, ]", ParseArrayError::TrailingComma { index: 2 }),
+            (
+                "[[], x]",
+                ParseArrayError::InvalidElement {
+                    c: 'x',
+                    or_end: false,
+                    index: 1,
+                },
+            ),
+        ];
+
+        for (json, error) in cases {
+            let mut parent = TestParent::new(json);
+            assert_eq!(parent.array().finish(), Err(error.into()), "{json}");
+        }
+    }
+
+    #[test]
+    fn recover_to_next_element() {
+        let cases = [
+            ("[1, x, 3]", &[1, 3][..]),
+            // The value before the error is valid
+            ("[1, 2 x, 3]", &[1, 2, 3]),
+            ("[1, -x, 3]", &[1, 3]),
+            (r#"[1, "a\q, ]", 3]"#, &[1, 3]),
+            (r#"[1, [2, {"a": [x]}, "]"], 3]"#, &[1, 3]),
+            ("[1, tru}, 3]", &[1, 3]),
+        ];
+
+        for (json, expected) in cases {
+            let mut parent = TestParent::new(json);
+            let mut array = parent.array();
+
+            let mut values = [0; 3];
+            let mut count = 0;
+            loop {
+                let number = match array.next() {
+                    Ok(Some(mut value)) => value.read_number(),
+                    Ok(None) => break,
+                    Err(error) => {
+                        // Errors are repeated until recovering
+                        assert_eq!(array.next().map(|_| ()), Err(error), "{json}");
+                        Err(error.into())
+                    }
+                };
+
+                match number {
+                    Ok(number) => {
+                        values[count] = number.as_u8().expect("expected an integer");
+                        count += 1;
+                    }
+                    Err(_) => array.recover_to_next_element().expect("failed to recover"),
+                }
+            }
+
+            assert_eq!(&values[..count], expected, "{json}");
+            assert_eq!(array.index(), 3, "{json}");
+            assert!(parent.remaining.is_empty(), "{json}");
+        }
+
+        let mut parent = TestParent::new("[x");
+        let mut array = parent.array();
+        array
+            .next()
+            .expect_err("failed to return error from invalid array");
+        assert_eq!(
+            array.recover_to_next_element(),
+            Err(ParseArrayError::UnexpectedEnd { index: 0 })
+        );
+    }
+
+    #[test]
+    fn next_typed() {
+        let json = r#"[1, -2, "a", true, null, 300, 1.5, "b", null, {}]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        assert_eq!(array.next_typed::<u8>(), Ok(Some(1)));
+        assert_eq!(array.next_typed::<i64>(), Ok(Some(-2)));
+        let string = array
+            .next_typed::<ParsedString>()
+            .expect("failed to parse array");
+        assert_eq!(string.map(ParsedString::unescaped), Some("a"));
+        assert_eq!(array.next_typed::<bool>(), Ok(Some(true)));
+        assert_eq!(array.next_typed::<Option<bool>>(), Ok(Some(None)));
+
+        // Out of range and wrong type are distinct errors
+        assert_eq!(
+            array.next_typed::<u8>(),
+            Err(ParseAnyError::NumberNotRepresentable { target: "u8" })
+        );
+        assert_eq!(
+            array.next_typed::<u32>(),
+            Err(ParseAnyError::NumberNotRepresentable { target: "u32" })
+        );
+        assert_eq!(
+            array.next_typed::<u32>(),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::String
+            })
+        );
+        assert_eq!(
+            array.next_typed::<bool>(),
+            Err(ParseAnyError::UnexpectedNull)
+        );
+        assert_eq!(array.index() - 1, 8);
+    }
+
+    #[test]
+    fn for_each_pair() {
+        let json = r#"[["a", 1], ["b", [2]], ["c", {"d": 3}]]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut keys = [""; 3];
+        let mut count = 0;
+        parent
+            .array()
+            .for_each_pair(
+                |key| Ok(key.read_string()?.unescaped()),
+                |key, value| {
+                    keys[count] = key;
+                    // The values are finished automatically
+                    assert_ne!(value.kind(), ValueKind::String);
+                    count += 1;
+                    Ok(())
+                },
+            )
+            .expect("failed to parse array");
+        assert_eq!(keys, ["a", "b", "c"]);
+        assert!(parent.remaining.is_empty());
+
+        let cases = [
+            (
+                r#"[["a", 1], "b"]"#,
+                ParseAnyError::TypeMismatch {
+                    expected: ValueKind::Array,
+                    found: ValueKind::String,
+                },
+            ),
+            (
+                "[[]]",
+                ParseArrayError::TooFewElements {
+                    expected: 2,
+                    found: 0,
+                }
+                .into(),
+            ),
+            (
+                r#"[["a", 1], ["b"]]"#,
+                ParseArrayError::TooFewElements {
+                    expected: 2,
+                    found: 1,
+                }
+                .into(),
+            ),
+            (
+                r#"[["a", 1, 2]]"#,
+                ParseArrayError::TooManyElements { capacity: 2 }.into(),
+            ),
+        ];
+
+        for (json, error) in cases {
+            let mut parent = TestParent::new(json);
+            let result = parent.array().for_each_pair(|_| Ok(()), |(), _| Ok(()));
+            assert_eq!(result, Err(error), "{json}");
+        }
+    }
+
+    #[test]
+    fn strings_numbers() {
+        let mut parent = TestParent::new(r#"["a", "b\"", "c"]"#);
+        let mut count = 0;
+        parent
+            .array()
+            .strings(|string| {
+                assert_eq!(string, ["a", "b\"", "c"][count]);
+                count += 1;
+                Ok(())
+            })
+            .expect("failed to parse array");
+        assert_eq!(count, 3);
+        assert!(parent.remaining.is_empty());
+
+        let mut parent = TestParent::new("[1, 2, 3]");
+        let sum = parent
+            .array()
+            .fold_numbers(0, |sum, number| {
+                Ok(sum + number.as_u32().expect("expected an integer"))
+            })
+            .expect("failed to parse array");
+        assert_eq!(sum, 6);
+
+        let mut parent = TestParent::new(r#"["a", 1]"#);
+        let mut array = parent.array();
+        assert_eq!(
+            array.fold_strings(0, |count, _| Ok(count + 1)),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::String,
+                found: ValueKind::Number
+            })
+        );
+        assert_eq!(array.index() - 1, 1);
+
+        let mut parent = TestParent::new("[1, [2]]");
+        let mut array = parent.array();
+        assert_eq!(
+            array.numbers(|_| Ok(())),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::Array
+            })
+        );
+        assert_eq!(array.index() - 1, 1);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn sum_mean() {
+        let json = "[1, 2.5, -0.5e1, 1e1]";
+
+        let mut parent = TestParent::new(json);
+        assert_eq!(parent.array().sum_f64(), Ok(8.5));
+        assert!(parent.remaining.is_empty());
+
+        let mut parent = TestParent::new(json);
+        assert_eq!(parent.array().mean_f64(), Ok(Some(2.125)));
+
+        let mut parent = TestParent::new("[]");
+        assert_eq!(parent.array().sum_f64(), Ok(0.0));
+        let mut parent = TestParent::new("[]");
+        assert_eq!(parent.array().mean_f64(), Ok(None));
+
+        let mut parent = TestParent::new("[1, 2, null]");
+        let mut array = parent.array();
+        assert_eq!(
+            array.mean_f64(),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::Literal
+            })
+        );
+        assert_eq!(array.index() - 1, 2);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn max_min_number() {
+        let json = "[3, -1.5, 1e2, 100, -1.5e0]";
+
+        let mut parent = TestParent::new(json);
+        let max = parent.array().max_number().expect("failed to parse array");
+        assert_eq!(max.map(ParsedNumber::as_str), Some("1e2"));
+        assert!(parent.remaining.is_empty());
+
+        let mut parent = TestParent::new(json);
+        let min = parent.array().min_number().expect("failed to parse array");
+        assert_eq!(min.map(ParsedNumber::as_str), Some("-1.5"));
+
+        let mut parent = TestParent::new("[]");
+        let max = parent.array().max_number().expect("failed to parse array");
+        assert!(max.is_none());
+
+        let mut parent = TestParent::new(r#"[1, 2, "3", 4]"#);
+        let mut array = parent.array();
+        assert_eq!(
+            array.min_number().map(|_| ()),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::String
+            })
+        );
+        assert_eq!(array.index() - 1, 2);
+    }
+
+    #[test]
+    fn position() {
+        let json = r#"[{"id": 1}, {"id": 2}, {"id": 3}, 4]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let has_id = |id: u8| {
+            move |value: &mut Any| {
+                let object = value.mut_object().expect("expected an object");
+                let (_, mut value) = object.next()?.expect("failed to get value from object");
+                Ok(value.read_number()? == id)
+            }
+        };
+
+        let position = array.position(has_id(2)).expect("failed to parse array");
+        assert_eq!(position, Some(1));
+        assert_eq!(array.index() - 1, 1);
+
+        let position = array.position(has_id(3)).expect("failed to parse array");
+        assert_eq!(position, Some(0));
+
+        let next = array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .read_number()
+            .expect("failed to read number");
+        assert_eq!(next, 4);
+
+        let position = array.position(|_| Ok(true)).expect("failed to parse array");
+        assert_eq!(position, None);
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn any_all() {
+        let json = "[1, 2, 30, 4]";
+        let is_big = |value: &mut Any| Ok(value.read_number()? > 10_u8);
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+        let any = array
+            .any::<_, ParseAnyError>(is_big)
+            .expect("failed to parse array");
+        assert!(any);
+        // The rest is left to be parsed
+        assert_eq!(array.index(), 3);
+        assert_eq!(array.count().expect("failed to parse array"), 1);
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+        let all = array
+            .all::<_, ParseAnyError>(|value| Ok(value.read_number()? < 10_u8))
+            .expect("failed to parse array");
+        assert!(!all);
+        assert_eq!(array.index(), 3);
+
+        let mut parent = TestParent::new("[]");
+        let mut array = parent.array();
+        assert!(!array
+            .any::<_, ParseAnyError>(is_big)
+            .expect("failed to parse array"));
+        assert!(array
+            .all::<_, ParseAnyError>(is_big)
+            .expect("failed to parse array"));
+    }
+
+    #[test]
+    fn skip() {
+        let json = r#"[1, [2, 3], {"a": 4}, 5] "#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let skipped = array.skip(3).expect("failed to parse array");
+        assert_eq!(skipped, 3);
+
+        let sum = array
+            .fold::<_, _, ParseAnyError>(0, |sum, value| {
+                Ok(sum + value.read_number()?.as_u8().unwrap_or_default())
+            })
+            .expect("failed to parse array");
+        assert_eq!(sum, 5);
+
+        let skipped = array.skip(2).expect("failed to parse array");
+        assert_eq!(skipped, 0);
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new("[1, 2]");
+        let skipped = parent.array().skip(5).expect("failed to parse array");
+        assert_eq!(skipped, 2);
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let json = r#"[0, [1], {"a": 2}, 3]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let value = array
+            .get(1)
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+        assert!(value.array().is_some());
+
+        let value = array
+            .get(3)
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .number()
+            .expect("failed to get number from array")
+            .get()
+            .expect("failed to parse number");
+        assert_eq!(value, 3_u8);
+
+        let error = array
+            .get(2)
+            .expect_err("failed to return error for consumed index");
+        assert_eq!(
+            error,
+            ParseAnyError::Array(ParseArrayError::AlreadyConsumed {
+                index: 2,
+                consumed: 4
+            })
+        );
+
+        let value = array.get(4).expect("failed to parse array");
+        assert!(value.is_none());
+
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn count() {
+        let json = r#"[1, [2, 3], {"a": [4]}, "]", null] "#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .finish()
+            .expect("failed to parse value");
+
+        let count = array.count().expect("failed to parse array");
+        assert_eq!(count, 4);
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new("[]");
+        let count = parent.array().count().expect("failed to parse array");
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut parent = TestParent::new("[ \n ] ");
+        let mut array = parent.array();
+        assert_eq!(array.is_empty(), Ok(true));
+        assert!(array.next().expect("failed to parse array").is_none());
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new("[ 1 , 2 ]");
+        let mut array = parent.array();
+        assert_eq!(array.is_empty(), Ok(false));
+        assert_eq!(array.next_typed::<u8>(), Ok(Some(1)));
+        assert_eq!(array.is_empty(), Ok(false));
+        assert_eq!(array.next_typed::<u8>(), Ok(Some(2)));
+        assert_eq!(array.is_empty(), Ok(true));
+        assert_eq!(array.index(), 2);
+
+        let mut parent = TestParent::new("[1, ]");
+        let mut array = parent.array();
+        assert_eq!(array.next_typed::<u8>(), Ok(Some(1)));
+        assert_eq!(array.is_empty(), Ok(false));
+        assert_eq!(
+            array.next().map(|_| ()),
+            Err(ParseArrayError::TrailingComma { index: 1 })
+        );
+
+        for json in ["[", "[  ", "[1, "] {
+            let mut parent = TestParent::new(json);
+            let mut array = parent.array();
+            if json.contains('1') {
+                assert_eq!(array.next_typed::<u8>(), Ok(Some(1)));
+            }
+            assert!(
+                matches!(array.is_empty(), Err(ParseArrayError::UnexpectedEnd { .. })),
+                "{json}"
+            );
+        }
+    }
+
+    #[test]
+    fn peek() {
+        let mut parent = TestParent::new(r#"[ "a" , [1], x] "#);
+        let mut array = parent.array();
+
+        assert_eq!(array.peek(), Ok(Some(ValueKind::String)));
+        assert_eq!(array.peek(), Ok(Some(ValueKind::String)));
+        assert_eq!(array.index(), 0);
+        let value = array
+            .next()
+            .expect("failed to parse array")
+            .expect("expected a value");
+        assert_eq!(value.kind(), ValueKind::String);
+        // A yielded value that has not been finished is peeked again
+        assert_eq!(array.peek(), Ok(Some(ValueKind::String)));
+        assert_eq!(array.index(), 1);
+
+        assert_eq!(
+            array
+                .next_typed::<ParsedString>()
+                .map(|s| s.map(ParsedString::unescaped)),
+            Ok(Some("a"))
+        );
+        assert_eq!(array.peek(), Ok(Some(ValueKind::Array)));
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("expected a value")
+            .finish()
+            .expect("failed to parse value");
+
+        let error = ParseArrayError::InvalidElement {
+            c: 'x',
+            or_end: false,
+            index: 2,
+        };
+        assert_eq!(array.peek(), Err(error));
+        assert_eq!(array.next().map(|value| value.is_some()), Err(error));
+
+        let mut parent = TestParent::new("[ ] ");
+        let mut array = parent.array();
+        assert_eq!(array.peek(), Ok(None));
+        assert_eq!(array.next().map(|value| value.is_some()), Ok(false));
+        assert_eq!(parent.remaining, " ");
+    }
+
+    #[test]
+    fn whitespace() {
+        let mut parent = TestParent::new("[ \u{3000}\n\t1 ,\r\n  \u{a0}2\u{2003} ] ");
+        let mut array = parent.array();
+        assert_eq!(array.numbers(|_| Ok(())), Ok(()));
+        assert_eq!(array.index(), 2);
+        assert_eq!(parent.remaining, " ");
+
+        for (json, error) in [
+            (
+                "[  \u{3000} é]",
+                ParseArrayError::InvalidElement {
+                    c: 'é',
+                    or_end: true,
+                    index: 0,
+                },
+            ),
+            (
+                "[1   \u{ff0c}]",
+                ParseArrayError::ExpectedCommaOrEnd {
+                    c: '\u{ff0c}',
+                    index: 0,
+                },
+            ),
+            ("[1,  \n ", ParseArrayError::UnexpectedEnd { index: 1 }),
+        ] {
+            let mut parent = TestParent::new(json);
+            assert_eq!(parent.array().finish(), Err(error.into()), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn rollback() {
+        let mut parent = TestParent::new("[123, 4] ");
+        let mut array = parent.array();
+
+        let checkpoint = array.checkpoint();
+        let number = array
+            .next()
+            .expect("failed to parse array")
+            .expect("expected a value")
+            .read_number()
+            .expect("failed to parse number");
+        assert_eq!(number.as_u32(), Some(123));
+        assert_eq!(array.index(), 1);
+
+        // Parse the same value again, as a string of digits
+        array.rollback(checkpoint);
+        assert_eq!(array.index(), 0);
+        let digits = array
+            .next()
+            .expect("failed to parse array")
+            .expect("expected a value")
+            .read_number()
+            .expect("failed to parse number");
+        assert_eq!(digits.as_str(), "123");
+
+        let checkpoint = array.checkpoint();
+        array.finish().expect("failed to parse array");
+        assert!(array.is_exhausted());
+
+        // The array can be rolled back after it is exhausted, as long as it is exhausted again
+        array.rollback(checkpoint);
+        assert!(!array.is_exhausted());
+        let number = array
+            .next()
+            .expect("failed to parse array")
+            .expect("expected a value")
+            .read_number()
+            .expect("failed to parse number");
+        assert_eq!(number.as_u32(), Some(4));
+        array.finish().expect("failed to parse array");
+        assert_eq!(parent.remaining, " ");
+    }
+
+    #[test]
+    fn expect_len() {
+        let mut parent = TestParent::new("[1, [2], 3] ");
+        let mut array = parent.array();
+        array.next().expect("failed to parse array");
+        array.expect_len(3).expect("wrong length");
+        assert_eq!(parent.remaining, " ");
+
+        for (json, expected, actual) in [("[]", 1, 0), ("[1, 2]", 1, 2), ("[1]", 0, 1)] {
+            let mut parent = TestParent::new(json);
+            assert_eq!(
+                parent.array().expect_len(expected),
+                Err(ParseArrayError::LengthMismatch { expected, actual }.into()),
+                "{json:?}"
+            );
+        }
+
+        let mut parent = TestParent::new("[1, 2 3]");
+        assert_eq!(
+            parent.array().expect_len(2),
+            Err(ParseArrayError::ExpectedCommaOrEnd { c: '3', index: 1 }.into())
+        );
+    }
+
+    #[test]
+    fn finish_expect_empty() {
+        let mut parent = TestParent::new(r#"["a", {"b": 1}]"#);
+        let mut array = parent.array();
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("expected a value")
+            .finish()
+            .expect("failed to parse value");
+        // The value yielded last does not need to be finished
+        array.next().expect("failed to parse array");
+        array.finish_expect_empty().expect("values remain");
+
+        let mut parent = TestParent::new("[1, 2, 3]");
+        let mut array = parent.array();
+        array.next().expect("failed to parse array");
+        assert_eq!(
+            array.finish_expect_empty(),
+            Err(ParseArrayError::LengthMismatch {
+                expected: 1,
+                actual: 3
+            }
+            .into())
+        );
+        assert!(array.is_exhausted());
+    }
+
+    #[test]
+    fn join_to() {
+        /// A fixed-capacity writer, like those used without an allocator.
+        struct Buffer {
+            bytes: [u8; 8],
+            len: usize,
+        }
+
+        impl fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.bytes
+                    .get_mut(self.len..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut parent = TestParent::new(r#"["a", "\u00e9"] "#);
+        let mut buffer = Buffer {
+            bytes: [0; 8],
+            len: 0,
+        };
+        parent
+            .array()
+            .join_to(" | ", &mut buffer)
+            .expect("failed to join array");
+        assert_eq!(&buffer.bytes[..buffer.len], "a | é".as_bytes());
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new(r#"["a", "b", "c", "d"]"#);
+        buffer.len = 0;
+        let mut array = parent.array();
+        assert_eq!(array.join_to(", ", &mut buffer), Err(ParseAnyError::Write));
+        assert_eq!(array.index(), 4);
+
+        let mut parent = TestParent::new(r#"["a", 1]"#);
+        let mut out = String::new();
+        let mut array = parent.array();
+        assert_eq!(
+            array.join_to(", ", &mut out),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::String,
+                found: ValueKind::Number
+            })
+        );
+        assert_eq!(array.index(), 2);
+        assert_eq!(out, "a");
+    }
+
+    #[test]
+    fn iter_with() {
+        let mut parent = TestParent::new(r#"["a", "b", "c"] "#);
+        let mut array = parent.array();
+
+        let mut iter = array.iter_with(|value| value.read_string().map(ParsedString::unescaped));
+        assert_eq!(iter.next(), Some(Ok("a")));
+        assert_eq!(iter.nth(1), Some(Ok("c")));
+        assert_eq!(iter.next(), None);
+        assert!(array.is_exhausted());
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new(r#"[1, "2", 3, x]"#);
+        let mut array = parent.array();
+        let values: Vec<_> = array
+            .iter_with(|value| Ok(value.read_number()?.as_u8()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                Ok(Some(1)),
+                Err(ParseAnyError::TypeMismatch {
+                    expected: ValueKind::Number,
+                    found: ValueKind::String
+                })
+            ]
+        );
+
+        let mut parent = TestParent::new("[1, x, 3]");
+        let mut array = parent.array();
+        let mut iter = array.iter_with(|value| Ok(value.kind()));
+        assert_eq!(iter.next(), Some(Ok(ValueKind::Number)));
+        assert_eq!(
+            iter.next(),
+            Some(Err(ParseArrayError::InvalidElement {
+                c: 'x',
+                or_end: false,
+                index: 1
+            }
+            .into()))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn raw() {
+        let json = r#"[1, "]", [[2], "[\"]"], {"a": [3]}] , "#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .finish()
+            .expect("failed to parse value");
+
+        let raw = array.raw().expect("failed to parse array");
+        assert_eq!(raw, json.trim_end_matches([' ', ',']));
+        assert_eq!(parent.remaining, " , ");
+
+        let mut parent = TestParent::new("[1, [2, x]]");
+        assert_eq!(
+            parent.array().raw(),
+            Err(ParseArrayError::InvalidElement {
+                c: 'x',
+                or_end: false,
+                index: 1
+            }
+            .into())
+        );
+
+        // Calling it again after an error returns the error again
+        let mut parent = TestParent::new("[1 2]");
+        let mut array = parent.array();
+        let error = ParseArrayError::ExpectedCommaOrEnd { c: '2', index: 0 }.into();
+        assert_eq!(array.raw(), Err(error));
+        assert_eq!(array.raw(), Err(error));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_raw() {
+        let json = r#"[0, "a\"b" , {"c": [1, 2]}, [], true, -1.5e3] "#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array")
+            .finish()
+            .expect("failed to parse value");
+
+        let values = array.collect_raw().expect("failed to parse array");
+        assert_eq!(
+            values,
+            [r#""a\"b""#, r#"{"c": [1, 2]}"#, "[]", "true", "-1.5e3"]
+        );
+        assert_eq!(parent.remaining, " ");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_raw_error() {
+        let json = r#"[1, "a", [2, j]]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let error = array
+            .collect_raw()
+            .expect_err("failed to return error from invalid value");
+        assert_eq!(
+            error,
+            ParseAnyError::Array(ParseArrayError::InvalidElement {
+                c: 'j',
+                or_end: false,
+                index: 1,
+            })
+        );
+        assert_eq!(array.index() - 1, 2);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "float"))]
+    #[test]
+    fn try_map() {
+        let json = r#"[{"id": 1}, {"id": 2, "x": [3]}, {"id": 3}] "#;
+
+        let mut parent = TestParent::new(json);
+        let ids = parent
+            .array()
+            .try_map(|value| {
+                let object = value.mut_object().expect("expected an object");
+                let (_, mut id) = object.next()?.expect("failed to get value from object");
+                id.read_number()?
+                    .as_u8()
+                    .ok_or(ParseAnyError::NumberNotRepresentable { target: "u8" })
+            })
+            .expect("failed to parse array");
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new("[]");
+        let values = parent
+            .array()
+            .try_map(|value| value.read_number().map(ParsedNumber::as_f64))
+            .expect("failed to parse array");
+        assert!(values.is_empty());
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[cfg(all(feature = "alloc", feature = "float"))]
+    #[test]
+    fn try_map_error() {
+        let mut parent = TestParent::new(r#"[1, 2, "3", 4]"#);
+        let mut array = parent.array();
+
+        let error = array
+            .try_map(|value| value.read_number().map(ParsedNumber::as_f64))
+            .expect_err("failed to return error from conversion");
+        assert_eq!(
+            error,
+            ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::String
+            }
+        );
+        assert_eq!(array.index() - 1, 2);
+
+        // The failed value was not started, so the array can continue from it
+        let rest = array.collect_raw().expect("failed to parse array");
+        assert_eq!(rest, [r#""3""#, "4"]);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "float"))]
+    #[test]
+    fn collect_numbers() {
+        let json = "[1, -2.5, 3e2, 0]";
+
+        let mut parent = TestParent::new(json);
+        let numbers = parent
+            .array()
+            .collect_numbers()
+            .expect("failed to parse array");
+        assert_eq!(numbers, [1.0, -2.5, 300.0, 0.0]);
+        assert!(parent.remaining.is_empty());
+
+        let mut parent = TestParent::new(r#"[1, 2, "3"]"#);
+        let mut array = parent.array();
+        let error = array
+            .collect_numbers()
+            .expect_err("failed to return error from non-number");
+        assert_eq!(
+            error,
+            ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::String
+            }
+        );
+        assert_eq!(array.index() - 1, 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_integers() {
+        let mut parent = TestParent::new("[1, -2, 300]");
+        let integers = parent
+            .array()
+            .collect_integers::<i64>()
+            .expect("failed to parse array");
+        assert_eq!(integers, [1, -2, 300]);
+
+        for json in ["[1, 2.5]", "[1, 300]", "[1, -1]"] {
+            let mut parent = TestParent::new(json);
+            let mut array = parent.array();
+            let error = array
+                .collect_integers::<u8>()
+                .expect_err("failed to return error from unrepresentable number");
+            assert_eq!(
+                error,
+                ParseAnyError::NumberNotRepresentable { target: "u8" },
+                "{json}"
+            );
+            assert_eq!(array.index() - 1, 1);
+        }
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn fill_slice() {
+        let mut buf = [0.0_f32; 4];
+
+        let mut parent = TestParent::new("[1.5, -2, 3e2] ");
+        let filled = parent
+            .array()
+            .fill_slice(&mut buf)
+            .expect("failed to parse array");
+        assert_eq!(buf[..filled], [1.5, -2.0, 300.0]);
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new("[1, 2, 3, 4, 5]");
+        let error = parent
+            .array()
+            .fill_slice(&mut buf)
+            .expect_err("failed to return error from long array");
+        assert_eq!(
+            error,
+            ParseAnyError::Array(ParseArrayError::TooManyElements { capacity: 4 })
+        );
+
+        let mut buf = [0_u8; 2];
+        let mut parent = TestParent::new("[1, 256]");
+        let error = parent
+            .array()
+            .fill_slice(&mut buf)
+            .expect_err("failed to return error from unrepresentable number");
+        assert_eq!(
+            error,
+            ParseAnyError::NumberNotRepresentable { target: "u8" }
+        );
+    }
+
+    #[test]
+    fn fill_slice_truncating() {
+        let mut buf = [0_i32; 2];
+
+        let mut parent = TestParent::new("[1, 2, 3]");
+        let mut array = parent.array();
+
+        let filled = array
+            .fill_slice_truncating(&mut buf)
+            .expect("failed to parse array");
+        assert_eq!(filled, 2);
+        assert_eq!(buf, [1, 2]);
+
+        let filled = array
+            .fill_slice_truncating(&mut buf)
+            .expect("failed to parse array");
+        assert_eq!(filled, 1);
+        assert_eq!(buf[..filled], [3]);
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn fixed() {
+        let mut parent = TestParent::new("[] ");
+        let values: [u8; 0] = parent.array().fixed().expect("failed to parse array");
+        assert_eq!(values, [0_u8; 0]);
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new(r#"["a", "b\tc"]"#);
+        let [a, b] = parent
+            .array()
+            .fixed::<2, ParsedString>()
+            .expect("failed to parse array");
+        assert_eq!(a, "a");
+        assert_eq!(b, "b\tc");
+        assert!(parent.remaining.is_empty());
+
+        let mut parent = TestParent::new("[1]");
+        let error = parent
+            .array()
+            .fixed::<2, f64>()
+            .expect_err("failed to return error from short array");
+        assert_eq!(
+            error,
+            ParseAnyError::Array(ParseArrayError::TooFewElements {
+                expected: 2,
+                found: 1
+            })
+        );
+
+        let mut parent = TestParent::new(r#"[1, 2, "3"]"#);
+        let error = parent
+            .array()
+            .fixed::<2, f64>()
+            .expect_err("failed to return error from long array");
+        assert_eq!(
+            error,
+            ParseAnyError::Array(ParseArrayError::TooManyElements { capacity: 2 })
+        );
+
+        let mut parent = TestParent::new(r#"[1, "2"]"#);
+        let error = parent
+            .array()
+            .fixed::<2, f64>()
+            .expect_err("failed to return error from wrong element type");
+        assert_eq!(
+            error,
+            ParseAnyError::TypeMismatch {
+                expected: ValueKind::Number,
+                found: ValueKind::String
+            }
+        );
+    }
+
+    #[test]
+    fn fixed_drops_partial() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted;
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        impl<'json> FromAny<'json> for Counted {
+            fn from_any(value: &mut Any<'json, '_>) -> Result<Self, ParseAnyError> {
+                value.read_number().map(|_| Self)
+            }
+        }
+
+        let mut parent = TestParent::new("[1, 2]");
+        let error = parent.array().fixed::<3, Counted>();
+        assert!(error.is_err());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+
+        let mut parent = TestParent::new("[1, 2]");
+        let values = parent
+            .array()
+            .fixed::<2, Counted>()
+            .expect("failed to parse array");
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+        drop(values);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn index() {
+        let json = "[ 1 ,\n [2, 3] , \"4\" ]";
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+        assert_eq!(array.index(), 0);
+
+        for expected in 1..=3 {
+            let mut value = array
+                .next()
+                .expect("failed to parse array")
+                .expect("failed to get value from array");
+            value.finish().expect("failed to parse value");
+            assert_eq!(array.index(), expected);
+        }
+
+        assert!(array.next().expect("failed to parse array").is_none());
+        assert_eq!(array.index(), 3);
+    }
+
+    #[test]
+    fn index_debug() {
+        let mut parent = TestParent::new("[1, 2]");
+        let mut array = parent.array();
+
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+
+        // The unfinished value is yielded again, but only counted once
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+
+        assert_eq!(array.index(), 1);
+        assert!(format!("{array:?}").contains("index: 1"));
+    }
+
+    #[test]
+    fn for_each_indexed() {
+        let json = r#"[10, 11, [12], {"a": 13}]"#;
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        array
+            .next()
+            .expect("failed to parse array")
+            .expect("failed to get value from array");
+
+        let mut indices = [0; 4];
+        let mut count = 0;
+        array
+            .for_each_indexed::<_, ParseAnyError>(|i, _| {
+                indices[count] = i;
+                count += 1;
+                Ok(())
+            })
+            .expect("failed to parse array");
+
+        assert_eq!(count, 4);
+        assert_eq!(indices, [0, 1, 2, 3]);
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn fold_indexed() {
+        let json = "[5, 5, 5, 5]";
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let sum = array
+            .fold_indexed::<_, _, ParseAnyError>(0, |sum, i, value| {
+                let n = value
+                    .mut_number()
+                    .expect("failed to get number from array")
+                    .get()?
+                    .as_u32()
+                    .expect("failed to get number as u32");
+                Ok(sum + u32::try_from(i).expect("index out of range") * n)
+            })
+            .expect("failed to parse array");
+
+        assert_eq!(sum, 30);
+    }
+
+    #[test]
+    fn for_each_indexed_error() {
+        let json = "[1, 2, x]";
+
+        let mut parent = TestParent::new(json);
+        let mut array = parent.array();
+
+        let mut last = None;
+        let error = array
+            .for_each_indexed::<_, ParseAnyError>(|i, _| {
+                last = Some(i);
+                Ok(())
+            })
+            .expect_err("failed to return error from invalid array");
+
+        assert_eq!(last, Some(1));
+        assert_eq!(
+            error,
+            ParseAnyError::Array(ParseArrayError::InvalidElement {
+                c: 'x',
+                or_end: false,
+                index: 2,
+            })
+        );
     }
 }
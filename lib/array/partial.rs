@@ -0,0 +1,68 @@
+use core::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+/// A partially initialised array, used to build `[T; N]` one value at a time.
+///
+/// The initialised values are dropped if this is dropped before it is full.
+pub struct PartialArray<T, const N: usize> {
+    values: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            values: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Push `value` to the end of the array.
+    ///
+    /// This must not be called when the array is full; if it is, `value` is dropped.
+    pub fn push(&mut self, value: T) {
+        if let Some(slot) = self.values.get_mut(self.len) {
+            slot.write(value);
+            self.len += 1;
+        }
+    }
+
+    /// Get the array if it is full, otherwise, return `self`.
+    pub fn into_array(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: all `N` values have been initialised, and `MaybeUninit<T>`
+        // has the same layout as `T`. `this` is not dropped, so the values
+        // are not dropped twice.
+        Ok(unsafe { ptr::read(ptr::from_ref(&this.values).cast::<[T; N]>()) })
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        for value in &mut self.values[..self.len] {
+            // SAFETY: the first `self.len` values have been initialised
+            unsafe { value.assume_init_drop() };
+        }
+    }
+}
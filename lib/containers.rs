@@ -1,8 +1,16 @@
 use crate::{
-    any::Any, array::Array, literal::Literal, number::Number, object::Object, string::String,
-    Parent,
+    any::{Any, ValueKind},
+    array::Array,
+    literal::Literal,
+    number::Number,
+    object::Object,
+    string::String,
+    Parent, ParseOptions,
 };
 
+/// The byte order mark, which documents may start with.
+pub const BOM: char = '\u{feff}';
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ParsePrompt {
     String,
@@ -32,25 +40,33 @@ impl ParsePrompt {
         }
     }
 
+    /// Returns the kind of value that will be created.
     #[inline]
-    pub const fn keep_first(self) -> bool {
-        matches!(self, Self::Number | Self::Literal)
+    pub const fn kind(self) -> ValueKind {
+        match self {
+            Self::String => ValueKind::String,
+            Self::Number => ValueKind::Number,
+            Self::Object => ValueKind::Object,
+            Self::Array => ValueKind::Array,
+            Self::Literal => ValueKind::Literal,
+        }
     }
 
     pub fn create<'json, 'p>(
         self,
         parent: &'p mut dyn Parent<'json>,
         remaining: &'json str,
+        options: ParseOptions,
     ) -> Any<'json, 'p>
     where
         'json: 'p,
     {
         match self {
-            Self::String => Any::String(String::new(parent, remaining)),
-            Self::Number => Any::Number(Number::new(parent, remaining)),
-            Self::Object => Any::Object(Object::new(parent, remaining)),
-            Self::Array => Any::Array(Array::new(parent, remaining)),
-            Self::Literal => Any::Literal(Literal::new(parent, remaining)),
+            Self::String => Any::String(String::new(parent, remaining, options)),
+            Self::Number => Any::Number(Number::new(parent, remaining, options)),
+            Self::Object => Any::Object(Object::new(parent, remaining, options)),
+            Self::Array => Any::Array(Array::new(parent, remaining, options)),
+            Self::Literal => Any::Literal(Literal::new(parent, remaining, options)),
         }
     }
 }
@@ -62,37 +78,73 @@ impl From<ParsePrompt> for ParseStatus {
     }
 }
 
+/// Skip the leading whitespace in `json` and get the next character.
+///
+/// Returns the remaining JSON, starting at the character, and the character, or [`None`] if `json` ends first.
+/// Runs of ASCII whitespace are skipped at once and ASCII characters are not decoded, as they make up most JSON.
+#[inline]
+pub fn next_char(json: &str) -> (&str, Option<char>) {
+    let json = json.trim_ascii_start();
+
+    let c = match json.as_bytes().first() {
+        Some(&b) if b.is_ascii() => Some(char::from(b)),
+        Some(_) => json.chars().next(),
+        None => None,
+    };
+
+    (json, c)
+}
+
+/// Get the part of `start` that is not in `end`, where `end` is a suffix of `start`.
+#[inline]
+pub fn span<'json>(start: &'json str, end: &'json str) -> &'json str {
+    &start[..start.len() - end.len()]
+}
+
 macro_rules! fff_impl {
     (
         type: $type_name:literal
         $f:ident ( $( $param:ty ),* ) -> Result<_, $error:ty>;
-        $acc:ident, $pat:pat =>
+        $acc:ident, $index:ident, $pat:pat =>
             $call:expr,
             $call_fold:expr,
+            $call_indexed:expr,
+            $call_fold_indexed:expr,
             $value:expr;
-        $any_err:expr
+        $any_err:expr;
+        index: $index_doc:literal $( $start:path )?
     ) => {
         $crate::containers::fff_impl! {
             type: $type_name
             value: "value"
             $f( $($param),* ) -> Result<_, $error>;
-            $acc, $pat =>
+            $acc, $index, $pat =>
                 $call,
                 $call_fold,
+                $call_indexed,
+                $call_fold_indexed,
                 $value;
-            $any_err
+            $any_err;
+            index: $index_doc $( $start )?
         }
     };
 
+    // The index of the first value, from `$start(self)` if it is given
+    (@start $self:ident) => { 0 };
+    (@start $self:ident $start:path) => { $start($self) };
+
     (
         type: $type_name:literal
         value: $value_name:literal
         $f:ident ( $( $param:ty ),* ) -> Result<_, $error:ty>;
-        $acc:ident, $pat:pat =>
+        $acc:ident, $index:ident, $pat:pat =>
             $call:expr,
             $call_fold:expr,
+            $call_indexed:expr,
+            $call_fold_indexed:expr,
             $value:expr;
-        $any_err:expr
+        $any_err:expr;
+        index: $index_doc:literal $( $start:path )?
     ) => {
         #[doc = concat!("Runs `f` for each ", $value_name, " in the ", $type_name, ".")]
         ///
@@ -139,6 +191,57 @@ macro_rules! fff_impl {
             Ok($acc)
         }
 
+        #[doc = concat!("Runs `f` for each ", $value_name, " in the ", $type_name, ", passing in the index of the ", $value_name, ".")]
+        ///
+        #[doc = $index_doc]
+        /// [`Any::finish`] is automatically called on all values, so it is not needed in `f`.
+        ///
+        /// # Errors
+        #[doc = concat!("If parsing fails in this ", $type_name, " or if `f` returns an error, an instance of `E` is returned.")]
+        #[doc = concat!("If you do not need a custom error type, use [`", stringify!($error), "`] as `E`.")]
+        pub fn for_each_indexed<F, E>(&mut self, mut $f: F) -> Result<(), E>
+        where
+            F: FnMut(usize, $($param),*) -> Result<(), $error>,
+            E: From<$error>,
+        {
+            let mut $index = $crate::containers::fff_impl!(@start self $( $start )?);
+
+            while let Some( $pat ) = self.next().map_err($any_err)? {
+                $call_indexed?;
+                $value.finish().map_err(From::from)?;
+                $index += 1;
+            }
+
+            Ok(())
+        }
+
+        #[doc = concat!("Applies `f` to the accumulator, passing in each ", $value_name, " in the ", $type_name, " and its index.")]
+        ///
+        /// The initial value of the accumulator is `init`.
+        #[doc = $index_doc]
+        ///
+        /// [`Any::finish`] is automatically called on all values, so it is not needed in `f`.
+        ///
+        /// # Errors
+        #[doc = concat!("If parsing fails in this ", $type_name, " or if `f` returns an error, an instance of `E` is returned.")]
+        #[doc = concat!("If you do not need a custom error type, use [`", stringify!($error), "`] as `E`.")]
+        pub fn fold_indexed<B, F, E>(&mut self, init: B, mut $f: F) -> Result<B, E>
+        where
+            F: FnMut(B, usize, $($param),*) -> Result<B, $error>,
+            E: From<$error>,
+        {
+            let mut $acc = init;
+            let mut $index = $crate::containers::fff_impl!(@start self $( $start )?);
+
+            while let Some( $pat ) = self.next().map_err($any_err)? {
+                $acc = $call_fold_indexed?;
+                $value.finish().map_err(From::from)?;
+                $index += 1;
+            }
+
+            Ok($acc)
+        }
+
         #[doc = concat!("Runs `f` for each ", $value_name, " in the ", $type_name, ", stopping if `f` returns [`Some`].")]
         ///
         /// [`Any::finish`] is automatically called on each value iterated over, so it is not needed in `f`.
@@ -162,6 +265,56 @@ macro_rules! fff_impl {
 
             Ok(None)
         }
+
+        #[doc = concat!("Returns [`true`] if `f` returns [`true`] for any ", $value_name, " in the ", $type_name, ", like [`Iterator::any`].")]
+        ///
+        #[doc = concat!("This stops at the first ", $value_name, " that `f` returns [`true`] for, leaving the rest of the ", $type_name, " to be parsed.")]
+        /// [`Any::finish`] is automatically called on each value iterated over, so it is not needed in `f`.
+        ///
+        /// # Errors
+        #[doc = concat!("If parsing fails in this ", $type_name, " or if `f` returns an error, an instance of `E` is returned.")]
+        #[doc = concat!("If you do not need a custom error type, use [`", stringify!($error), "`] as `E`.")]
+        pub fn any<F, E>(&mut self, mut $f: F) -> Result<bool, E>
+        where
+            F: FnMut( $($param),* ) -> Result<bool, $error>,
+            E: From<$error>,
+        {
+            while let Some( $pat ) = self.next().map_err($any_err)? {
+                let result = $call?;
+                $value.finish().map_err(From::from)?;
+
+                if result {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
+
+        #[doc = concat!("Returns [`true`] if `f` returns [`true`] for every ", $value_name, " in the ", $type_name, ", like [`Iterator::all`].")]
+        ///
+        #[doc = concat!("This stops at the first ", $value_name, " that `f` returns [`false`] for, leaving the rest of the ", $type_name, " to be parsed.")]
+        /// [`Any::finish`] is automatically called on each value iterated over, so it is not needed in `f`.
+        ///
+        /// # Errors
+        #[doc = concat!("If parsing fails in this ", $type_name, " or if `f` returns an error, an instance of `E` is returned.")]
+        #[doc = concat!("If you do not need a custom error type, use [`", stringify!($error), "`] as `E`.")]
+        pub fn all<F, E>(&mut self, mut $f: F) -> Result<bool, E>
+        where
+            F: FnMut( $($param),* ) -> Result<bool, $error>,
+            E: From<$error>,
+        {
+            while let Some( $pat ) = self.next().map_err($any_err)? {
+                let result = $call?;
+                $value.finish().map_err(From::from)?;
+
+                if !result {
+                    return Ok(false);
+                }
+            }
+
+            Ok(true)
+        }
     };
 }
 pub(crate) use fff_impl;
@@ -19,21 +19,23 @@ impl<'json, 'p> fmt::Debug for ParentDebugger<'json, 'p> {
 }
 
 macro_rules! debug_impl {
-    ( $name:literal, $t:ty ) => {
+    ( $name:literal, $t:ty $(, $field:ident: $value:ident )* ) => {
         impl<'json, 'p> core::fmt::Debug for $t {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 f.debug_struct($name)
                     .field("parents", &$crate::debug::ParentDebugger::new(self.parent))
+                    $( .field(stringify!($field), &self.$value) )*
                     .field("remaining_json", &self.remaining)
                     .finish()
             }
         }
     };
 
-    ( $name:literal, $t:ty, no_parents) => {
+    ( $name:literal, $t:ty, no_parents $(, $field:ident: $value:ident )* ) => {
         impl<'json> core::fmt::Debug for $t {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 f.debug_struct($name)
+                    $( .field(stringify!($field), &self.$value) )*
                     .field("remaining_json", &self.remaining)
                     .finish()
             }
@@ -51,3 +53,17 @@ impl<'a, T: fmt::Display> fmt::Debug for DisplayAsDebug<'a, T> {
         self.0.fmt(f)
     }
 }
+
+/// Displays a character, rendering control characters as `U+XXXX` so that they can't corrupt the output.
+pub struct DisplayChar(pub char);
+
+impl fmt::Display for DisplayChar {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_control() {
+            write!(f, "U+{:04X}", u32::from(self.0))
+        } else {
+            fmt::Display::fmt(&self.0, f)
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::fmt;
 
-use crate::{any, array, literal, number, object, string};
+use crate::{any, array, debug::DisplayChar, literal, number, object, string};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The error returned when parsing a [`Document`](super::Document) fails.
@@ -11,6 +11,8 @@ pub enum ParseDocumentError {
     InvalidElement(char),
     /// A non-whitespace character was found after the first value.
     UnexpectedCharacter(char),
+    /// A non-whitespace control character (such as a NUL byte) was found outside of a value.
+    ControlCharacter(char),
 }
 
 impl fmt::Display for ParseDocumentError {
@@ -22,17 +24,27 @@ impl fmt::Display for ParseDocumentError {
             ),
             Self::InvalidElement(c) => write!(
                 f,
-                "Invalid character ({c}) in JSON document (expected an element)!"
+                "Invalid character ({c}) in JSON document (expected an element)!",
+                c = DisplayChar(*c)
             ),
             Self::UnexpectedCharacter(c) => {
-                write!(f, "Unexpected character ({c}) at the end of JSON document!")
+                write!(
+                    f,
+                    "Unexpected character ({c}) at the end of JSON document!",
+                    c = DisplayChar(*c)
+                )
             }
+            Self::ControlCharacter(c) => write!(
+                f,
+                "Invalid control character ({c}) in JSON document!",
+                c = DisplayChar(*c)
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseDocumentError {}
+impl core::error::Error for ParseDocumentError {}
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -55,9 +67,9 @@ impl fmt::Display for ParseAnyDocumentError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseAnyDocumentError {
+impl core::error::Error for ParseAnyDocumentError {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         Some(match self {
             Self::Document(err) => err,
             Self::Any(err) => err,
@@ -113,3 +125,24 @@ impl From<literal::ParseLiteralError> for ParseAnyDocumentError {
         Self::Any(value.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ParseDocumentError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseDocumentError::InvalidElement('\u{1}').to_string(),
+            "Invalid character (U+0001) in JSON document (expected an element)!"
+        );
+        assert_eq!(
+            ParseDocumentError::UnexpectedCharacter('j').to_string(),
+            "Unexpected character (j) at the end of JSON document!"
+        );
+        assert_eq!(
+            ParseDocumentError::ControlCharacter('\0').to_string(),
+            "Invalid control character (U+0000) in JSON document!"
+        );
+    }
+}
@@ -1,17 +1,59 @@
+use core::str::{self, Utf8Error};
+
 use crate::{
-    any::Any,
-    containers::{fff_impl, ParsePrompt, ParseStatus},
+    any::{Any, FromAny, ValueKind},
+    array::Array,
+    containers::{fff_impl, span, ParsePrompt, ParseStatus, BOM},
     debug::debug_impl,
-    Parent,
+    object::Object,
+    Parent, ParseOptions,
 };
 
 mod error;
 pub use error::{ParseAnyDocumentError, ParseDocumentError};
 
+#[must_use = "call `MustFinish::finish` to check for characters after the value"]
+// Not `Copy`, so that `MustFinish::finish` consumes it
+#[allow(missing_copy_implementations)]
+#[derive(Debug, PartialEq, Eq)]
+/// A token returned by [`Document::next_must_finish`], reminding the caller to finish the document.
+///
+/// The compiler warns if it is discarded, so forgetting to check for characters after the value is not silent.
+pub struct MustFinish {
+    _private: (),
+}
+
+impl MustFinish {
+    /// Finish parsing `document`, consuming the token.
+    ///
+    /// This is the same as calling [`Document::finish`].
+    ///
+    /// # Errors
+    /// If parsing fails in the document or a child, the error is returned as a [`ParseAnyDocumentError`].
+    #[inline]
+    pub fn finish(self, document: &mut Document<'_>) -> Result<(), ParseAnyDocumentError> {
+        document.finish()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A saved parsing position in a [`Document`], created by [`Document::checkpoint`].
+pub struct DocumentCheckpoint<'json> {
+    remaining: &'json str,
+    parse_status: Option<ParseStatus>,
+}
+
+// Not `Copy`, so that copying the parser is explicit
+#[allow(missing_copy_implementations)]
+#[derive(Clone)]
 /// A JSON document created from a string.
 pub struct Document<'json> {
+    start: &'json str,
     remaining: &'json str,
     parse_status: Option<ParseStatus>,
+    options: ParseOptions,
+    last_value: Option<&'json str>,
+    prefix: bool,
 }
 
 impl<'json> Parent<'json> for Document<'json> {
@@ -19,6 +61,11 @@ impl<'json> Parent<'json> for Document<'json> {
     where
         'json: 'a,
     {
+        if let Some(ParseStatus::Prompted(_)) = self.parse_status {
+            // The value started at the old remaining
+            self.last_value = Some(span(self.remaining, remaining));
+        }
+
         self.remaining = remaining;
         self.parse_status = Some(ParseStatus::Done);
     }
@@ -32,10 +79,70 @@ impl<'json> Document<'json> {
     #[must_use]
     #[inline]
     /// Create a new JSON document from a string.
+    ///
+    /// A byte order mark (`U+FEFF`) at the start of `json` is skipped.
     pub const fn new(json: &'json str) -> Self {
+        Self::with_options(json, ParseOptions::new())
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new JSON document from a string, parsed with `options`.
+    ///
+    /// A byte order mark (`U+FEFF`) at the start of `json` is skipped.
+    pub const fn with_options(json: &'json str, options: ParseOptions) -> Self {
         Self {
+            start: json,
             remaining: json,
             parse_status: None,
+            options,
+            last_value: None,
+            prefix: false,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new JSON document from a string that only starts with JSON.
+    ///
+    /// The document is complete once its value has been finished, so anything after the value is left in [`Self::remaining`], rather than causing [`ParseDocumentError::UnexpectedCharacter`].
+    /// Whitespace after the value is not consumed, so the remainder starts directly after the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// let mut document = Document::new_prefix(r#"{"a": 1} trailing data"#);
+    /// document.finish()?;
+    ///
+    /// assert_eq!(document.remaining(), " trailing data");
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub const fn new_prefix(json: &'json str) -> Self {
+        let mut document = Self::new(json);
+        document.prefix = true;
+        document
+    }
+
+    #[inline]
+    /// Create a new JSON document from bytes, which must be valid UTF-8.
+    ///
+    /// # Errors
+    /// If `bytes` is not valid UTF-8, this will return a [`Utf8Error`], which has the byte offset of the invalid sequence in [`Utf8Error::valid_up_to`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// assert!(Document::from_bytes(b"[1, 2]").is_ok());
+    ///
+    /// let error = Document::from_bytes(b"[\"\xff\"]").expect_err("expected invalid UTF-8");
+    /// assert_eq!(error.valid_up_to(), 2);
+    /// ```
+    pub const fn from_bytes(bytes: &'json [u8]) -> Result<Self, Utf8Error> {
+        match str::from_utf8(bytes) {
+            Ok(json) => Ok(Self::new(json)),
+            Err(error) => Err(error),
         }
     }
 
@@ -48,15 +155,49 @@ impl<'json> Document<'json> {
     /// If parsing fails, this will return a [`ParseDocumentError`].
     /// Parsing will fail if the first non-whitespace character does not hint at a valid value or if there are any non-whitespace characters after the first value.
     pub fn next(&mut self) -> Result<Option<Any<'json, '_>>, ParseDocumentError> {
+        let Some(prompt) = self.advance()? else {
+            return Ok(None);
+        };
+
+        self.parse_status = Some(prompt.into());
+
+        let (remaining, options) = (self.remaining, self.options);
+        Ok(Some(prompt.create(self, remaining, options)))
+    }
+
+    /// Returns the kind of the value in the document, without yielding it.
+    ///
+    /// Whitespace before the value is skipped, so [`Self::next`] continues from the value.
+    /// Once the value has been finished, this will return [`None`].
+    ///
+    /// # Errors
+    /// If parsing fails, this will return a [`ParseDocumentError`], the same as [`Self::next`] would.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::ValueKind, document::Document};
+    ///
+    /// let mut document = Document::new(" [1, 2]");
+    /// assert_eq!(document.peek()?, Some(ValueKind::Array));
+    ///
+    /// let mut array = document.next()?.expect("expected a value").read_array()?;
+    /// assert_eq!(array.peek()?, Some(ValueKind::Number));
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> Result<Option<ValueKind>, ParseDocumentError> {
+        Ok(self.advance()?.map(ParsePrompt::kind))
+    }
+
+    /// Parse up to the start of the value, returning how it should be parsed, without starting it.
+    ///
+    /// Returns [`None`] if the value has been finished.
+    fn advance(&mut self) -> Result<Option<ParsePrompt>, ParseDocumentError> {
         loop {
             let end = match self.parse_status {
                 None => false,
-
-                Some(ParseStatus::Prompted(prompt)) => {
-                    let remaining = self.remaining;
-                    return Ok(Some(prompt.create(self, remaining)));
-                }
-
+                Some(ParseStatus::Prompted(prompt)) => return Ok(Some(prompt)),
+                Some(ParseStatus::Done) if self.prefix => return Ok(None),
                 Some(ParseStatus::Done) => true,
             };
 
@@ -68,16 +209,15 @@ impl<'json> Document<'json> {
                 };
             };
 
-            if c.is_whitespace() {
+            if c.is_whitespace() || (c == BOM && self.offset() == 0) {
                 // do nothing
+            } else if c.is_control() {
+                return Err(ParseDocumentError::ControlCharacter(c));
             } else if end {
                 return Err(ParseDocumentError::UnexpectedCharacter(c));
             } else if let Some(prompt) = ParsePrompt::get(c) {
-                self.parse_status = Some(prompt.into());
-
-                if prompt.keep_first() {
-                    continue;
-                }
+                // Values are created from their first character
+                return Ok(Some(prompt));
             } else {
                 return Err(ParseDocumentError::InvalidElement(c));
             }
@@ -86,6 +226,128 @@ impl<'json> Document<'json> {
         }
     }
 
+    /// Get the value from the document, which must be an object.
+    ///
+    /// The document still needs to be finished after the object.
+    ///
+    /// # Errors
+    /// - If there is no value left in the document, this will return [`ParseDocumentError::UnexpectedEnd`].
+    /// - If the value is not an object, this will return [`ParseAnyError::TypeMismatch`](crate::any::ParseAnyError::TypeMismatch).
+    /// - If parsing the document fails, the error is returned as a [`ParseAnyDocumentError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// let mut document = Document::new(r#"{"name": "zjson"}"#);
+    ///
+    /// let mut root = document.expect_object()?;
+    /// while let Some((key, mut value)) = root.next()? {
+    ///     assert_eq!(key, "name");
+    ///     value.finish()?;
+    /// }
+    ///
+    /// document.finish()?;
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn expect_object(&mut self) -> Result<Object<'json, '_>, ParseAnyDocumentError> {
+        let value = self.next()?.ok_or(ParseDocumentError::UnexpectedEnd)?;
+        Ok(value.read_object()?)
+    }
+
+    /// Get the value from the document, which must be an array.
+    ///
+    /// The document still needs to be finished after the array.
+    ///
+    /// # Errors
+    /// - If there is no value left in the document, this will return [`ParseDocumentError::UnexpectedEnd`].
+    /// - If the value is not an array, this will return [`ParseAnyError::TypeMismatch`](crate::any::ParseAnyError::TypeMismatch).
+    /// - If parsing the document fails, the error is returned as a [`ParseAnyDocumentError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// let mut document = Document::new("[1, 2, 3]");
+    ///
+    /// assert_eq!(document.expect_array()?.count()?, 3);
+    ///
+    /// document.finish()?;
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn expect_array(&mut self) -> Result<Array<'json, '_>, ParseAnyDocumentError> {
+        let value = self.next()?.ok_or(ParseDocumentError::UnexpectedEnd)?;
+        Ok(value.read_array()?)
+    }
+
+    /// Parse the value in the document into `T`, then finish the document.
+    ///
+    /// # Errors
+    /// - If there is no value left in the document, this will return [`ParseDocumentError::UnexpectedEnd`].
+    /// - If the value cannot be parsed into `T`, or if parsing fails in the document or the value, the error is returned as a [`ParseAnyDocumentError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use zjson::document::Document;
+    ///
+    /// let json = r#"{"primes": [2, 3, 5], "squares": [1, 4, null]}"#;
+    /// let parsed: BTreeMap<String, Vec<Option<u8>>> = Document::new(json).parse()?;
+    ///
+    /// assert_eq!(parsed["primes"], [Some(2), Some(3), Some(5)]);
+    /// assert_eq!(parsed["squares"], [Some(1), Some(4), None]);
+    ///
+    /// let name: &str = Document::new(r#" "zjson" "#).parse()?;
+    /// assert_eq!(name, "zjson");
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn parse<T: FromAny<'json>>(&mut self) -> Result<T, ParseAnyDocumentError> {
+        let mut value = self.next()?.ok_or(ParseDocumentError::UnexpectedEnd)?;
+        let parsed = T::from_any(&mut value)?;
+        value.finish()?;
+
+        self.finish()?;
+        Ok(parsed)
+    }
+
+    /// Try to get the next value from the document, along with a [`MustFinish`] token.
+    ///
+    /// This is the same as [`Self::next`], except that the compiler warns if the token is not used.
+    /// Calling [`MustFinish::finish`] after using the value makes sure that there are no characters after it.
+    ///
+    /// # Errors
+    /// If parsing fails, this will return a [`ParseDocumentError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::{Document, ParseAnyDocumentError};
+    ///
+    /// let mut document = Document::new("[1, 2] x");
+    ///
+    /// let (mut value, token) = document.next_must_finish()?.expect("expected a value");
+    /// value.finish()?;
+    ///
+    /// assert!(token.finish(&mut document).is_err());
+    /// # Ok::<(), ParseAnyDocumentError>(())
+    /// ```
+    ///
+    /// Discarding the token is a warning:
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// # use zjson::document::{Document, ParseAnyDocumentError};
+    /// # let mut document = Document::new("[1, 2] x");
+    /// document.next_must_finish()?.expect("expected a value");
+    /// # Ok::<(), ParseAnyDocumentError>(())
+    /// ```
+    pub fn next_must_finish(
+        &mut self,
+    ) -> Result<Option<(Any<'json, '_>, MustFinish)>, ParseDocumentError> {
+        Ok(self
+            .next()?
+            .map(|value| (value, MustFinish { _private: () })))
+    }
+
     /// Finish parsing this document.
     /// This can be used to make sure that there are no errors after the first value.
     ///
@@ -101,14 +363,98 @@ impl<'json> Document<'json> {
         Ok(())
     }
 
+    #[must_use]
+    #[inline]
+    /// Save the current parsing position, so that it can be returned to with [`Self::rollback`].
+    ///
+    /// Cloning the document also saves the position, but a clone can be parsed independently.
+    /// Values from a clone must not be mixed with values from the original, as each only updates the document it came from.
+    pub const fn checkpoint(&self) -> DocumentCheckpoint<'json> {
+        DocumentCheckpoint {
+            remaining: self.remaining,
+            parse_status: self.parse_status,
+        }
+    }
+
+    #[inline]
+    /// Return to a parsing position saved with [`Self::checkpoint`], so that values can be parsed again.
+    ///
+    /// `checkpoint` must have been created by this document.
+    pub const fn rollback(&mut self, checkpoint: DocumentCheckpoint<'json>) {
+        self.remaining = checkpoint.remaining;
+        self.parse_status = checkpoint.parse_status;
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this document is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the part of the input that has not been parsed yet.
+    ///
+    /// This is updated when the value is finished, so after finishing the value, it starts directly after it, with any trailing whitespace or invalid characters.
+    /// While the value is unfinished, it starts at the beginning of the value.
+    /// After [`Self::finish`] succeeds, it is empty.
+    pub const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of bytes of the input that have been parsed.
+    ///
+    /// Like [`Self::remaining`], this is updated as values are finished.
+    pub const fn offset(&self) -> usize {
+        self.start.len() - self.remaining.len()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the value has been finished and only whitespace is left, so the document is complete.
+    ///
+    /// For a document created with [`Self::new_prefix`], anything may be left after the value.
+    pub fn is_finished(&self) -> bool {
+        self.parse_status == Some(ParseStatus::Done)
+            && (self.prefix || self.remaining.chars().all(char::is_whitespace))
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the source text of the value, once it has been finished.
+    ///
+    /// This is the exact slice of the input that the value was parsed from, so strings include their quotes and escape sequences.
+    /// It does not include whitespace around the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::document::Document;
+    ///
+    /// let mut document = Document::new(r#" {"a": [1, 2]} "#);
+    /// assert_eq!(document.last_value_raw(), None);
+    ///
+    /// document.finish()?;
+    /// assert_eq!(document.last_value_raw(), Some(r#"{"a": [1, 2]}"#));
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub const fn last_value_raw(&self) -> Option<&'json str> {
+        self.last_value
+    }
+
     fff_impl! {
         type: "document"
         f(&mut Any<'json, '_>) -> Result<_, ParseAnyDocumentError>;
-        accumulator, mut value =>
+        accumulator, index, mut value =>
             f(&mut value),
             f(accumulator, &mut value),
+            f(index, &mut value),
+            f(accumulator, index, &mut value),
             value;
-        ParseAnyDocumentError::Document
+        ParseAnyDocumentError::Document;
+        index: "A document has only one value, so the index is always 0."
     }
 }
 
@@ -116,7 +462,18 @@ debug_impl!("Document", Document<'json>, no_parents);
 
 #[cfg(test)]
 mod test {
-    use super::{Document, ParseDocumentError};
+    extern crate alloc;
+
+    use alloc::collections::BTreeMap;
+
+    use crate::{
+        any::{Any, ParseAnyError, ValueKind},
+        array::{Array, ParseArrayError},
+        object::ParseObjectError,
+        ParseOptions,
+    };
+
+    use super::{Document, ParseAnyDocumentError, ParseDocumentError};
 
     #[test]
     fn parse_string() {
@@ -163,6 +520,316 @@ mod test {
         assert_eq!(error, ParseDocumentError::UnexpectedCharacter('"'));
     }
 
+    #[test]
+    fn remaining() {
+        let json = " [1, 2] x";
+        let mut document = Document::new(json);
+        assert_eq!(document.remaining(), json);
+        assert_eq!(document.offset(), 0);
+
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .array()
+            .expect("expected array from document");
+        array.next().expect("failed to parse array");
+        assert_eq!(document.remaining(), "[1, 2] x");
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse array");
+        assert_eq!(document.remaining(), " x");
+        assert_eq!(document.offset(), 7);
+
+        assert_eq!(
+            document.finish(),
+            Err(ParseDocumentError::UnexpectedCharacter('x').into())
+        );
+        assert_eq!(document.remaining(), "x");
+
+        let mut document = Document::new("true \n");
+        document.finish().expect("failed to parse document");
+        assert_eq!(document.remaining(), "");
+        assert_eq!(document.offset(), 6);
+    }
+
+    #[test]
+    fn last_value_raw() {
+        for (json, raw) in [
+            (r#" "a\"b" "#, r#""a\"b""#),
+            ("12.5e3 \n", "12.5e3"),
+            ("-0", "-0"),
+            ("null\t", "null"),
+            (" [1, [2], {}]", "[1, [2], {}]"),
+        ] {
+            let mut document = Document::new(json);
+            let mut value = document
+                .next()
+                .expect("failed to parse document")
+                .expect("got no values in document");
+            value.finish().expect("failed to parse value");
+
+            assert_eq!(document.last_value_raw(), Some(raw), "{json}");
+            document.finish().expect("failed to parse document");
+            assert_eq!(document.last_value_raw(), Some(raw), "{json}");
+        }
+
+        let mut document = Document::new("[1, 2]");
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .array()
+            .expect("expected array from document");
+        array.next().expect("failed to parse array");
+        assert_eq!(document.last_value_raw(), None);
+    }
+
+    #[test]
+    fn with_options() {
+        let json = r#"{"a": [1, [2, ], {"b": 3, }, ], }"#;
+        let options = ParseOptions::new().allow_trailing_commas(true);
+
+        let mut document = Document::with_options(json, options);
+        assert_eq!(document.options(), options);
+        document.finish().expect("failed to parse document");
+        assert_eq!(document.last_value_raw(), Some(json));
+
+        let mut document = Document::new(json);
+        assert_eq!(document.options(), ParseOptions::default());
+        assert_eq!(
+            document.finish(),
+            Err(ParseAnyError::Array(ParseArrayError::TrailingComma { index: 1 }).into())
+        );
+
+        let mut document = Document::new("{\"a\": 1, }");
+        assert_eq!(
+            document.finish(),
+            Err(ParseAnyError::Object(ParseObjectError::TrailingComma).into())
+        );
+
+        // Commas are still required between values
+        let mut document = Document::with_options("[1 2]", options);
+        assert_eq!(
+            document.finish(),
+            Err(
+                ParseAnyError::Array(ParseArrayError::ExpectedCommaOrEnd { c: '2', index: 0 })
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn expect_container() {
+        let mut document = Document::new(r#" {"a": 1} "#);
+        let mut object = document.expect_object().expect("failed to get object");
+        object.finish().expect("failed to parse object");
+        document.finish().expect("failed to parse document");
+
+        let mut document = Document::new("[1, 2]");
+        let mut array = document.expect_array().expect("failed to get array");
+        assert_eq!(array.count(), Ok(2));
+        document.finish().expect("failed to parse document");
+
+        let mismatch = |expected, found| {
+            Some(ParseAnyDocumentError::from(ParseAnyError::TypeMismatch {
+                expected,
+                found,
+            }))
+        };
+        assert_eq!(
+            Document::new("[]").expect_object().err(),
+            mismatch(ValueKind::Object, ValueKind::Array)
+        );
+        assert_eq!(
+            Document::new("null").expect_array().err(),
+            mismatch(ValueKind::Array, ValueKind::Literal)
+        );
+
+        assert_eq!(
+            Document::new(" ").expect_object().err(),
+            Some(ParseDocumentError::UnexpectedEnd.into())
+        );
+        assert_eq!(
+            Document::new("").expect_array().err(),
+            Some(ParseDocumentError::UnexpectedEnd.into())
+        );
+    }
+
+    #[test]
+    fn is_finished() {
+        let mut document = Document::new(" [1] \n");
+        assert!(!document.is_finished());
+
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .array()
+            .expect("expected array from document");
+        array.next().expect("failed to parse array");
+        assert!(!document.is_finished());
+
+        document.finish().expect("failed to parse document");
+        assert!(document.is_finished());
+
+        let mut document = Document::new("[1] x");
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse array");
+        assert!(!document.is_finished());
+
+        let mut document = Document::new_prefix("[1] x");
+        document.finish().expect("failed to parse document");
+        assert!(document.is_finished());
+    }
+
+    #[test]
+    fn prefix() {
+        for (json, remaining) in [
+            (r#"{"a":1}garbage"#, "garbage"),
+            ("[1, 2]\n\nnext", "\n\nnext"),
+            (r#""a""b""#, r#""b""#),
+            ("true", ""),
+            (" 12 34", " 34"),
+        ] {
+            let mut document = Document::new_prefix(json);
+            document.finish().expect("failed to parse document");
+            assert_eq!(document.remaining(), remaining, "{json}");
+            assert!(document.next().expect("failed to parse document").is_none());
+        }
+
+        let mut document = Document::new_prefix("[1, x]garbage");
+        assert!(document.finish().is_err());
+
+        let mut document = Document::new_prefix(" ");
+        assert_eq!(
+            document.finish(),
+            Err(ParseDocumentError::UnexpectedEnd.into())
+        );
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let mut document = Document::new("\u{feff} \n[]");
+        document.finish().expect("failed to parse document");
+        assert_eq!(document.offset(), 7);
+
+        // Only a single byte order mark at the start is skipped
+        for json in [" \u{feff}[]", "\u{feff}\u{feff}[]"] {
+            assert_eq!(
+                Document::new(json).finish(),
+                Err(ParseDocumentError::InvalidElement('\u{feff}').into()),
+                "{json:?}"
+            );
+        }
+        assert_eq!(
+            Document::new("[\u{feff}]").finish(),
+            Err(ParseAnyError::Array(ParseArrayError::InvalidElement {
+                c: '\u{feff}',
+                or_end: true,
+                index: 0
+            })
+            .into())
+        );
+    }
+
+    #[test]
+    fn peek() {
+        let mut document = Document::new(" {} x");
+        assert_eq!(document.peek(), Ok(Some(ValueKind::Object)));
+        assert_eq!(document.peek(), Ok(Some(ValueKind::Object)));
+        assert_eq!(document.remaining(), "{} x");
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse object");
+        assert_eq!(
+            document.peek(),
+            Err(ParseDocumentError::UnexpectedCharacter('x'))
+        );
+
+        assert_eq!(Document::new("true").peek(), Ok(Some(ValueKind::Literal)));
+        assert_eq!(
+            Document::new("  ").peek(),
+            Err(ParseDocumentError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Document::new("x").peek(),
+            Err(ParseDocumentError::InvalidElement('x'))
+        );
+    }
+
+    #[test]
+    fn rollback() {
+        let mut document = Document::new(" [1, 2] ");
+        let checkpoint = document.checkpoint();
+        let clone = document.clone();
+
+        document.finish().expect("failed to parse document");
+        assert_eq!(document.remaining(), "");
+
+        document.rollback(checkpoint);
+        assert_eq!(document.remaining(), " [1, 2] ");
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .read_array()
+            .expect("expected array from document");
+        assert_eq!(array.next_typed::<u8>(), Ok(Some(1)));
+
+        // The clone was not advanced by parsing the original
+        assert_eq!(clone.remaining(), " [1, 2] ");
+        assert_eq!(clone.offset(), 0);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Document::new(" 42 ").parse::<u16>(), Ok(42));
+        assert_eq!(Document::new("null").parse::<Option<u16>>(), Ok(None));
+        assert_eq!(
+            Document::new("[true, false]").parse::<Vec<bool>>(),
+            Ok(vec![true, false])
+        );
+        assert_eq!(
+            Document::new(r#"["a", "b\u0063"]"#).parse::<Vec<&str>>(),
+            Err(ParseAnyError::EscapedString.into())
+        );
+        assert_eq!(
+            Document::new("[1] 2").parse::<Vec<u8>>(),
+            Err(ParseDocumentError::UnexpectedCharacter('2').into())
+        );
+        assert_eq!(
+            Document::new(r#"{"a": 1}"#).parse::<Vec<u8>>(),
+            Err(ParseAnyError::TypeMismatch {
+                expected: ValueKind::Array,
+                found: ValueKind::Object
+            }
+            .into())
+        );
+
+        let mut document = Document::new(r#"{"a\u0062": [1, 2]}"#);
+        let map = document
+            .parse::<BTreeMap<String, Vec<u8>>>()
+            .expect("failed to parse document");
+        assert_eq!(map["ab"], [1, 2]);
+        assert_eq!(
+            document.parse::<u8>(),
+            Err(ParseDocumentError::UnexpectedEnd.into())
+        );
+    }
+
     #[test]
     fn empty() {
         let error = Document::new("")
@@ -210,4 +877,149 @@ mod test {
 
         assert_eq!(error, ParseDocumentError::UnexpectedCharacter(invalid));
     }
+
+    #[test]
+    fn control_character() {
+        let mut document = Document::new("\0");
+
+        let error = document
+            .next()
+            .expect_err("failed to return error after parsing invalid document");
+
+        assert_eq!(error, ParseDocumentError::ControlCharacter('\0'));
+
+        let mut document = Document::new("[] \0");
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse array");
+
+        let error = document
+            .next()
+            .expect_err("failed to return error after parsing invalid document");
+
+        assert_eq!(error, ParseDocumentError::ControlCharacter('\0'));
+    }
+
+    /// Documents with empty containers in every position, and their compact forms.
+    const EMPTY_CONTAINERS: [(&str, &str); 14] = [
+        ("[]", "[]"),
+        ("{}", "{}"),
+        (" [ ] ", "[]"),
+        ("{ \n}", "{}"),
+        ("[[]]", "[[]]"),
+        ("[{}]", "[{}]"),
+        (r#"{"a":{}}"#, r#"{"a": {}}"#),
+        (r#"{"a":[]}"#, r#"{"a": []}"#),
+        ("[1,[]]", "[1, []]"),
+        ("[[] ,1]", "[[], 1]"),
+        ("[1, {} , 2]", "[1, {}, 2]"),
+        ("[ [ ] , { } ]", "[[], {}]"),
+        ("[[[[]]]]", "[[[[]]]]"),
+        (
+            r#"{"a": [], "b" :{ }, "c":[[],{}]}"#,
+            r#"{"a": [], "b": {}, "c": [[], {}]}"#,
+        ),
+    ];
+
+    #[test]
+    fn empty_containers_finish() {
+        for (json, _) in EMPTY_CONTAINERS {
+            Document::new(json)
+                .finish()
+                .unwrap_or_else(|err| panic!("failed to parse {json:?}: {err}"));
+        }
+    }
+
+    #[test]
+    fn empty_containers_next() {
+        for (json, compact) in EMPTY_CONTAINERS {
+            let preview = crate::preview(json, crate::preview::PreviewBudget::new());
+            assert_eq!(preview.as_str(), compact, "{json:?}");
+        }
+    }
+
+    #[test]
+    fn empty_containers_for_each() {
+        fn count_values(value: &mut Any) -> Result<usize, ParseAnyError> {
+            match value {
+                Any::Array(array) => array.fold(1, |count, value| Ok(count + count_values(value)?)),
+                Any::Object(object) => {
+                    object.fold(1, |count, _, value| Ok(count + count_values(value)?))
+                }
+                _ => {
+                    value.finish()?;
+                    Ok(1)
+                }
+            }
+        }
+
+        for (json, compact) in EMPTY_CONTAINERS {
+            let expected = compact.matches(['[', '{', '1', '2']).count();
+
+            let mut document = Document::new(json);
+            let count = document
+                .fold::<_, _, ParseAnyDocumentError>(0, |count, value| {
+                    Ok(count + count_values(value)?)
+                })
+                .expect("failed to parse document");
+            assert_eq!(count, expected, "{json:?}");
+        }
+    }
+
+    #[test]
+    fn empty_containers_array_apis() {
+        fn array<'p>(document: &'p mut Document<'static>) -> Array<'static, 'p> {
+            document
+                .next()
+                .expect("failed to parse document")
+                .and_then(Any::array)
+                .expect("expected an array")
+        }
+
+        let cases: [(&str, &[&str]); 7] = [
+            ("[]", &[]),
+            ("[[]]", &["[]"]),
+            ("[ {} ]", &["{}"]),
+            ("[1,[]]", &["1", "[]"]),
+            ("[[] ,1]", &["[]", "1"]),
+            ("[ [ ] , { } ]", &["[ ]", "{ }"]),
+            ("[[[[]]], {\n}]", &["[[[]]]", "{\n}"]),
+        ];
+
+        for (json, elements) in cases {
+            let mut document = Document::new(json);
+            let raw = array(&mut document)
+                .collect_raw()
+                .expect("failed to parse array");
+            assert_eq!(raw, elements, "{json:?}");
+            document.finish().expect("failed to parse document");
+
+            let mut document = Document::new(json);
+            let count = array(&mut document).count().expect("failed to parse array");
+            assert_eq!(count, elements.len(), "{json:?}");
+            document.finish().expect("failed to parse document");
+
+            let mut document = Document::new(json);
+            let skipped = array(&mut document)
+                .skip(usize::MAX)
+                .expect("failed to parse array");
+            assert_eq!(skipped, elements.len(), "{json:?}");
+            document.finish().expect("failed to parse document");
+
+            for (i, element) in elements.iter().enumerate() {
+                let mut document = Document::new(json);
+                let subdocument = array(&mut document)
+                    .get(i)
+                    .expect("failed to parse array")
+                    .expect("failed to get value from array")
+                    .into_subdocument()
+                    .expect("failed to parse value");
+                assert_eq!(subdocument.remaining, *element, "{json:?}[{i}]");
+                document.finish().expect("failed to parse document");
+            }
+        }
+    }
 }
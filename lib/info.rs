@@ -0,0 +1,227 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::preview::PreviewBudget;
+use crate::{shape::DEFAULT_MAX_DEPTH, MAX_ERROR_SIZE};
+
+/// [`true`] if the `alloc` feature is enabled.
+///
+/// These constants let code that uses this crate check its features at compile time, as its `cfg`s cannot be seen from other crates.
+pub const HAS_ALLOC: bool = cfg!(feature = "alloc");
+/// [`true`] if the `float` feature is enabled.
+pub const HAS_FLOAT: bool = cfg!(feature = "float");
+/// [`true`] if the `rayon` feature is enabled.
+pub const HAS_RAYON: bool = cfg!(feature = "rayon");
+/// [`true`] if the `serde` feature is enabled.
+pub const HAS_SERDE: bool = cfg!(feature = "serde");
+/// [`true`] if the `std` feature is enabled.
+pub const HAS_STD: bool = cfg!(feature = "std");
+
+/// The enabled features of this crate.
+const FEATURES: &[&str] = &[
+    #[cfg(feature = "alloc")]
+    "alloc",
+    #[cfg(feature = "float")]
+    "float",
+    #[cfg(feature = "rayon")]
+    "rayon",
+    #[cfg(feature = "serde")]
+    "serde",
+    #[cfg(feature = "std")]
+    "std",
+];
+
+/// The enabled optional modules of this crate.
+const MODULES: &[&str] = &[
+    #[cfg(feature = "std")]
+    "io",
+    #[cfg(feature = "rayon")]
+    "multi_document::par",
+    #[cfg(feature = "std")]
+    "multi_document::read",
+    #[cfg(feature = "alloc")]
+    "preview",
+    #[cfg(feature = "serde")]
+    "serialize",
+    #[cfg(feature = "alloc")]
+    "testing::corpus",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// Information about how this crate was built, returned by [`build_info`].
+///
+/// The [`Display`](fmt::Display) implementation renders it in a form suitable for bug reports.
+///
+/// Some fields only exist with some features, so this cannot be constructed or exhaustively matched outside of this crate.
+pub struct BuildInfo {
+    /// The version of this crate.
+    pub version: &'static str,
+    /// The names of the enabled features.
+    pub features: &'static [&'static str],
+    /// The names of the enabled optional modules.
+    pub modules: &'static [&'static str],
+    /// The value of [`MAX_ERROR_SIZE`].
+    pub max_error_size: usize,
+    /// The default depth limit, [`DEFAULT_MAX_DEPTH`].
+    pub default_max_depth: usize,
+    #[cfg(feature = "alloc")]
+    /// The default [`PreviewBudget`].
+    pub preview_budget: PreviewBudget,
+}
+
+/// Returns information about how this crate was built, for diagnostics.
+///
+/// # Examples
+/// ```
+/// let info = zjson::build_info();
+///
+/// assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+/// assert_eq!(info.features.contains(&"alloc"), zjson::info::HAS_ALLOC);
+/// println!("{info}");
+/// ```
+#[must_use]
+#[inline]
+pub const fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        features: FEATURES,
+        modules: MODULES,
+        max_error_size: MAX_ERROR_SIZE,
+        default_max_depth: DEFAULT_MAX_DEPTH,
+        #[cfg(feature = "alloc")]
+        preview_budget: PreviewBudget::new(),
+    }
+}
+
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "zjson {}", self.version)?;
+        writeln!(f, "features: {}", List(self.features))?;
+        writeln!(f, "modules: {}", List(self.modules))?;
+        writeln!(f, "max error size: {} bytes", self.max_error_size)?;
+        write!(f, "default max depth: {}", self.default_max_depth)?;
+
+        #[cfg(feature = "alloc")]
+        write!(
+            f,
+            "\npreview budget: {} values, {} bytes, {} bytes per string",
+            self.preview_budget.max_values,
+            self.preview_budget.max_bytes,
+            self.preview_budget.max_string_bytes
+        )?;
+
+        Ok(())
+    }
+}
+
+struct List(&'static [&'static str]);
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("(none)");
+        }
+
+        for (i, item) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(item)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{build_info, HAS_ALLOC, HAS_FLOAT, HAS_RAYON, HAS_SERDE, HAS_STD};
+
+    /// Check the features and modules of the build against those expected in this configuration.
+    fn assert_features(features: &[&str], modules: &[&str]) {
+        let info = build_info();
+        assert_eq!(info.features, features);
+        assert_eq!(info.modules, modules);
+
+        let has = |feature| features.contains(&feature);
+        assert_eq!(
+            [HAS_ALLOC, HAS_FLOAT, HAS_RAYON, HAS_SERDE, HAS_STD],
+            [
+                has("alloc"),
+                has("float"),
+                has("rayon"),
+                has("serde"),
+                has("std")
+            ]
+        );
+    }
+
+    #[cfg(all(
+        feature = "std",
+        feature = "float",
+        not(feature = "rayon"),
+        not(feature = "serde")
+    ))]
+    #[test]
+    fn default_features() {
+        assert_features(
+            &["alloc", "float", "std"],
+            &["io", "multi_document::read", "preview", "testing::corpus"],
+        );
+    }
+
+    #[cfg(all(
+        feature = "std",
+        not(feature = "float"),
+        not(feature = "rayon"),
+        not(feature = "serde")
+    ))]
+    #[test]
+    fn no_default_features_with_std() {
+        assert_features(
+            &["alloc", "std"],
+            &["io", "multi_document::read", "preview", "testing::corpus"],
+        );
+    }
+
+    #[cfg(all(feature = "float", feature = "rayon", feature = "serde"))]
+    #[test]
+    fn all_features() {
+        assert_features(
+            &["alloc", "float", "rayon", "serde", "std"],
+            &[
+                "io",
+                "multi_document::par",
+                "multi_document::read",
+                "preview",
+                "serialize",
+                "testing::corpus",
+            ],
+        );
+    }
+
+    #[cfg(not(any(
+        feature = "alloc",
+        feature = "float",
+        feature = "rayon",
+        feature = "serde"
+    )))]
+    #[test]
+    fn no_default_features() {
+        assert_features(&[], &[]);
+    }
+
+    #[test]
+    fn display() {
+        let info = build_info().to_string();
+
+        assert!(info.starts_with(concat!("zjson ", env!("CARGO_PKG_VERSION"), "\n")));
+        assert!(info.contains("\nfeatures: "));
+        // `alloc` is listed first when it is enabled
+        assert_eq!(info.contains("\nfeatures: alloc"), cfg!(feature = "alloc"));
+        assert!(info.contains("\nmax error size: "));
+        assert!(info.contains("\ndefault max depth: 256"));
+        assert_eq!(info.contains("\npreview budget: "), cfg!(feature = "alloc"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::io;
+
+use crate::{
+    any::ParseAnyError,
+    array::ParseArrayError,
+    document::{ParseAnyDocumentError, ParseDocumentError},
+    literal::ParseLiteralError,
+    multi_document::{ParseAnyMultiDocumentError, ParseMultiDocumentError},
+    number::ParseNumberError,
+    object::ParseObjectError,
+    shape::ShapeHashError,
+    string::ParseStringError,
+};
+
+macro_rules! into_io_impl {
+    ( $( $t:ty ),* $(,)? ) => {
+        $(
+            impl From<$t> for io::Error {
+                #[inline]
+                /// Converts the error into an [`io::Error`] of kind [`io::ErrorKind::InvalidData`].
+                ///
+                /// The original error can be recovered with [`io::Error::downcast`] or [`io::Error::get_ref`].
+                fn from(value: $t) -> Self {
+                    Self::new(io::ErrorKind::InvalidData, value)
+                }
+            }
+        )*
+    };
+}
+
+into_io_impl!(
+    ParseAnyError,
+    ParseArrayError,
+    ParseDocumentError,
+    ParseAnyDocumentError,
+    ParseLiteralError,
+    ParseMultiDocumentError,
+    ParseAnyMultiDocumentError,
+    ParseNumberError,
+    ParseObjectError,
+    ParseStringError,
+    ShapeHashError,
+);
+
+#[cfg(test)]
+mod test {
+    use std::{hash::DefaultHasher, io};
+
+    use crate::{
+        any::ParseAnyError,
+        array::ParseArrayError,
+        document::{Document, ParseAnyDocumentError},
+        shape::{shape_hash, ShapeHashError, ShapeHashOptions},
+    };
+
+    #[test]
+    fn into_io() {
+        let error = Document::new("[1, j]")
+            .finish()
+            .expect_err("failed to return error from invalid document");
+
+        let io_error = io::Error::from(error);
+        assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), error.to_string());
+
+        let recovered = io_error
+            .downcast::<ParseAnyDocumentError>()
+            .expect("failed to downcast io error");
+        assert_eq!(recovered, error);
+        assert_eq!(
+            recovered,
+            ParseAnyDocumentError::Any(ParseAnyError::Array(ParseArrayError::InvalidElement {
+                c: 'j',
+                or_end: false,
+                index: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn shape_hash_into_io() {
+        let error = shape_hash(
+            &mut Document::new("[[1]]"),
+            &mut DefaultHasher::new(),
+            ShapeHashOptions::new().max_depth(1),
+        )
+        .expect_err("failed to return error from nested document");
+
+        let io_error = io::Error::from(error);
+        assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), error.to_string());
+
+        let recovered = io_error
+            .downcast::<ShapeHashError>()
+            .expect("failed to downcast io error");
+        assert_eq!(recovered, ShapeHashError::TooDeep);
+    }
+}
@@ -23,6 +23,7 @@
     clippy::module_name_repetitions,
     clippy::missing_panics_doc,
     clippy::cargo_common_metadata,
+    clippy::elidable_lifetime_names,
     clippy::panic_in_result_fn
 )]
 
@@ -35,6 +36,10 @@ pub mod array;
 mod debug;
 /// Types related to JSON documents.
 pub mod document;
+/// Information about how this crate was built.
+pub mod info;
+/// Finding every problem in a JSON document.
+pub mod lint;
 /// Types related to JSON `true`, `false` and `null` values.
 pub mod literal;
 /// Types related to JSON documents with multiple values.
@@ -43,10 +48,39 @@ pub mod multi_document;
 pub mod number;
 /// Types related to JSON objects.
 pub mod object;
+#[cfg(feature = "alloc")]
+/// Bounded previews of JSON documents, for logging.
+pub mod preview;
+/// Hashing the shape of JSON documents.
+pub mod shape;
 /// Types related to JSON strings.
 pub mod string;
+/// Helpers for testing code built on top of this crate.
+pub mod testing;
+/// Validation of incomplete JSON documents.
+pub mod validate;
+
+pub use info::build_info;
+pub use lint::lint;
+pub use options::{ParseOptions, ParseOptionsOverrides};
+#[cfg(feature = "alloc")]
+pub use preview::preview;
+pub use shape::shape_hash;
+pub use validate::{validate, validate_multi, validate_prefix};
+
+/// The maximum size, in bytes, of every error type in this crate: three words (24 bytes on 64-bit targets).
+///
+/// All error types are also [`Copy`], so the last error can be stored in a fixed-size slot without allocating.
+/// Errors carry small positions that fit in this size, such as the index of the array element ([`array::ParseArrayError`]) or the byte offset in a string ([`string::ParseStringError`]) where parsing failed.
+/// Anything larger is provided by methods on the parsers (such as [`string::String::error_offset`]) instead.
+pub const MAX_ERROR_SIZE: usize = 3 * core::mem::size_of::<usize>();
 
 mod containers;
+#[cfg(feature = "std")]
+mod io;
+mod options;
+#[cfg(feature = "serde")]
+mod serialize;
 mod status;
 #[cfg(test)]
 mod test_parent;
@@ -58,3 +92,44 @@ trait Parent<'json> {
 
     fn debug_parents(&self, list: &mut fmt::DebugList<'_, '_>);
 }
+
+#[cfg(test)]
+mod test {
+    use core::mem::size_of;
+
+    use crate::MAX_ERROR_SIZE;
+
+    const fn assert_copy<T: Copy>() {}
+
+    macro_rules! assert_error_size {
+        ( $( $t:ty ),* $(,)? ) => {
+            $(
+                assert_copy::<$t>();
+                assert!(
+                    size_of::<$t>() <= MAX_ERROR_SIZE,
+                    "{} is {} bytes, more than {MAX_ERROR_SIZE}",
+                    stringify!($t),
+                    size_of::<$t>(),
+                );
+            )*
+        };
+    }
+
+    #[test]
+    fn error_size() {
+        assert_error_size!(
+            crate::any::ParseAnyError,
+            crate::array::ParseArrayError,
+            crate::document::ParseDocumentError,
+            crate::document::ParseAnyDocumentError,
+            crate::literal::ParseLiteralError,
+            crate::multi_document::ParseMultiDocumentError,
+            crate::multi_document::ParseAnyMultiDocumentError,
+            crate::number::ParseNumberError,
+            crate::object::ParseObjectError,
+            crate::object::ExpectOnlyError<'_>,
+            crate::shape::ShapeHashError,
+            crate::string::ParseStringError,
+        );
+    }
+}
@@ -0,0 +1,721 @@
+use core::{fmt, ops::ControlFlow};
+
+use crate::{
+    any::{Any, ParseAnyError, ValueKind},
+    array::{self, find_separator, ParseArrayError},
+    containers::{next_char, ParsePrompt, ParseStatus},
+    document::{Document, ParseAnyDocumentError, ParseDocumentError},
+    object::{self, ParseObjectError},
+    string::{ParsedString, String},
+    validate::is_value_end,
+    Parent, ParseOptions,
+};
+
+/// The maximum number of issues that [`lint`] reports, so that a badly broken document cannot cause a flood of them.
+pub const MAX_ISSUES: usize = 100;
+
+/// The maximum depth of objects and arrays that [`lint`] checks.
+///
+/// Deeper objects and arrays are reported as [`LintCode::TooDeep`] and skipped.
+pub const MAX_DEPTH: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// The kind of a [`LintIssue`].
+pub enum LintCode {
+    /// The input ended before the document was complete.
+    UnexpectedEnd,
+    /// A character was found where it is not allowed, such as a missing comma or colon.
+    UnexpectedCharacter,
+    /// A comma was found before the end of an object or array, and trailing commas are not allowed by the [`ParseOptions`].
+    TrailingComma,
+    /// A string (or object name) is invalid, such as with an invalid escape sequence.
+    InvalidString,
+    /// A number is invalid.
+    InvalidNumber,
+    /// A `true`, `false` or `null` literal is invalid.
+    InvalidLiteral,
+    /// There is more than one value in the document.
+    TrailingCharacters,
+    /// Objects and arrays are nested deeper than [`MAX_DEPTH`].
+    TooDeep,
+}
+
+impl LintCode {
+    #[must_use]
+    /// Returns a short, stable name for the code, like `unexpected-end`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::UnexpectedEnd => "unexpected-end",
+            Self::UnexpectedCharacter => "unexpected-character",
+            Self::TrailingComma => "trailing-comma",
+            Self::InvalidString => "invalid-string",
+            Self::InvalidNumber => "invalid-number",
+            Self::InvalidLiteral => "invalid-literal",
+            Self::TrailingCharacters => "trailing-characters",
+            Self::TooDeep => "too-deep",
+        }
+    }
+
+    /// Returns the code for the issue described by `message`.
+    const fn of(message: LintMessage) -> Self {
+        let error = match message {
+            LintMessage::Parse(ParseAnyDocumentError::Document(error)) => {
+                return match error {
+                    ParseDocumentError::UnexpectedEnd => Self::UnexpectedEnd,
+                    ParseDocumentError::UnexpectedCharacter(_) => Self::TrailingCharacters,
+                    ParseDocumentError::InvalidElement(_)
+                    | ParseDocumentError::ControlCharacter(_) => Self::UnexpectedCharacter,
+                }
+            }
+            LintMessage::Parse(ParseAnyDocumentError::Any(error)) => error,
+            LintMessage::TooDeep => return Self::TooDeep,
+        };
+
+        if is_value_end(error) {
+            return Self::UnexpectedEnd;
+        }
+
+        match error {
+            ParseAnyError::String(_) | ParseAnyError::Object(ParseObjectError::InvalidName(_)) => {
+                Self::InvalidString
+            }
+            ParseAnyError::Number(_) => Self::InvalidNumber,
+            ParseAnyError::Literal(_) => Self::InvalidLiteral,
+            ParseAnyError::Array(ParseArrayError::TrailingComma { .. })
+            | ParseAnyError::Object(ParseObjectError::TrailingComma) => Self::TrailingComma,
+            _ => Self::UnexpectedCharacter,
+        }
+    }
+}
+
+impl fmt::Display for LintCode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A description of a [`LintIssue`].
+pub enum LintMessage {
+    /// Parsing failed with this error.
+    Parse(ParseAnyDocumentError),
+    /// An object or array was nested deeper than [`MAX_DEPTH`], so it was not checked.
+    TooDeep,
+}
+
+impl fmt::Display for LintMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => error.fmt(f),
+            Self::TooDeep => write!(
+                f,
+                "JSON value is nested more than {MAX_DEPTH} levels deep, so it was not checked!"
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// A problem found by [`lint`].
+pub struct LintIssue {
+    /// The byte offset in the document of the character where the problem was found.
+    ///
+    /// For invalid numbers and literals, and strings that are not terminated, this is the start of the value.
+    pub offset: usize,
+    /// The kind of problem.
+    pub code: LintCode,
+    /// A description of the problem.
+    pub message: LintMessage,
+    /// If this is not the first issue, so it may have been caused by skipping past an earlier one.
+    pub possibly_spurious: bool,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} [{}]",
+            self.message, self.offset, self.code
+        )?;
+        if self.possibly_spurious {
+            write!(f, " (possibly spurious)")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// The result of [`lint`].
+pub struct LintSummary {
+    /// The number of issues reported.
+    pub issues: usize,
+    /// If linting stopped after reporting [`MAX_ISSUES`] issues, so there may be more.
+    pub truncated: bool,
+}
+
+impl LintSummary {
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if no issues were found, so the document is valid.
+    pub const fn is_valid(&self) -> bool {
+        self.issues == 0
+    }
+}
+
+/// Check `json` for every problem that can be found, giving each to `sink` as a [`LintIssue`].
+///
+/// Unlike [`validate`](crate::validate()), which stops at the first error, this skips past each error and continues:
+/// - In an object or array, parsing continues after the next comma or terminator of the same object or array, skipping over nested values and strings.
+/// - If there is no comma or terminator, as when a string or nested value is not closed, parsing continues from the start of the next line, as the next value in the same object or array.
+/// - If there is no next line, linting stops.
+/// - An error in the root value, or after it, is reported and ends linting.
+///
+/// Skipping is a guess at where the document continues, so everything after the first issue is marked [`LintIssue::possibly_spurious`].
+/// At most [`MAX_ISSUES`] issues are reported, and objects and arrays deeper than [`MAX_DEPTH`] are not checked.
+/// Objects and arrays are checked in a loop, rather than recursively, so deeply nested input cannot overflow the stack.
+///
+/// # Examples
+/// ```
+/// use zjson::{lint::LintCode, ParseOptions};
+///
+/// let json = r#"{"a": [1, 2 3], "b": tru, "c": {"d" 4}}"#;
+///
+/// let mut issues = Vec::new();
+/// let summary = zjson::lint(json, &ParseOptions::new(), &mut |issue| issues.push(issue));
+///
+/// assert_eq!(summary.issues, 3);
+/// assert_eq!(
+///     issues.iter().map(|issue| (issue.offset, issue.code)).collect::<Vec<_>>(),
+///     [
+///         (12, LintCode::UnexpectedCharacter),
+///         (21, LintCode::InvalidLiteral),
+///         (36, LintCode::UnexpectedCharacter),
+///     ]
+/// );
+/// assert!(!issues[0].possibly_spurious);
+/// ```
+pub fn lint(json: &str, options: &ParseOptions, sink: &mut impl FnMut(LintIssue)) -> LintSummary {
+    let mut linter = Linter {
+        json,
+        remaining: json,
+        options: *options,
+        levels: [None; MAX_DEPTH],
+        depth: 0,
+        sink,
+        summary: LintSummary::default(),
+    };
+
+    let _ = linter.document();
+    linter.summary
+}
+
+/// The state of an object or array being checked by a [`Linter`].
+enum Level<'json> {
+    Array {
+        machine: array::Machine,
+        consumed: usize,
+    },
+    Object(object::Machine<'json>),
+}
+
+/// Checks a document, recovering from errors.
+struct Linter<'json, 's> {
+    json: &'json str,
+    remaining: &'json str,
+    options: ParseOptions,
+    /// The enclosing objects and arrays: the number of values consumed for arrays, or [`None`] for objects.
+    levels: [Option<usize>; MAX_DEPTH],
+    depth: usize,
+    sink: &'s mut dyn FnMut(LintIssue),
+    summary: LintSummary,
+}
+
+impl<'json> Parent<'json> for Linter<'json, '_> {
+    fn set_remaining<'a>(&'a mut self, remaining: &'json str)
+    where
+        'json: 'a,
+    {
+        self.remaining = remaining;
+    }
+
+    fn debug_parents(&self, list: &mut fmt::DebugList<'_, '_>) {
+        list.entry(&"Linter");
+    }
+}
+
+impl<'json> Linter<'json, '_> {
+    /// Returns the byte offset of the remaining JSON.
+    const fn offset(&self) -> usize {
+        self.json.len() - self.remaining.len()
+    }
+
+    /// Report an issue, breaking if no more should be reported.
+    fn issue(&mut self, offset: usize, message: LintMessage) -> ControlFlow<()> {
+        (self.sink)(LintIssue {
+            offset,
+            code: LintCode::of(message),
+            message,
+            possibly_spurious: self.summary.issues > 0,
+        });
+
+        self.summary.issues += 1;
+        if self.summary.issues >= MAX_ISSUES {
+            self.summary.truncated = true;
+            return ControlFlow::Break(());
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Report a parsing error at `offset`.
+    fn error(&mut self, offset: usize, error: impl Into<ParseAnyDocumentError>) -> ControlFlow<()> {
+        self.issue(offset, LintMessage::Parse(error.into()))
+    }
+
+    /// Check the document, and that there is nothing after its value.
+    fn document(&mut self) -> ControlFlow<()> {
+        let mut document = Document::with_options(self.json, self.options);
+
+        match document.peek() {
+            Ok(Some(ValueKind::Object | ValueKind::Array)) => {
+                self.remaining = document.remaining();
+                self.container()?;
+                document.set_remaining(self.remaining);
+            }
+
+            Ok(Some(_)) => {
+                let start = document.offset();
+                let result = match document.next() {
+                    Ok(Some(value)) => finish(value),
+                    _ => Ok(()),
+                };
+
+                if let Err((error, offset)) = result {
+                    return self.error(start + offset, error);
+                }
+            }
+
+            Ok(None) => {}
+            Err(error) => return self.error(document.offset(), error),
+        }
+
+        match document.finish() {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(error) => self.error(document.offset(), error),
+        }
+    }
+
+    /// Check the object or array at the start of the remaining JSON, leaving the JSON after it.
+    fn container(&mut self) -> ControlFlow<()> {
+        let mut level = self.open();
+
+        loop {
+            let start = self.offset();
+            let prompt = match &mut level {
+                Level::Array { machine, consumed } => self.array(machine, consumed),
+                Level::Object(machine) => self.object(machine),
+            };
+
+            match prompt {
+                Ok(Some(ParsePrompt::Object | ParsePrompt::Array)) => {
+                    if self.depth + 1 < MAX_DEPTH {
+                        self.levels[self.depth] = match level {
+                            Level::Array { consumed, .. } => Some(consumed),
+                            Level::Object(_) => None,
+                        };
+                        self.depth += 1;
+                        level = self.open();
+                        continue;
+                    }
+
+                    self.issue(self.offset(), LintMessage::TooDeep)?;
+                }
+
+                Ok(Some(prompt)) => {
+                    let (remaining, options) = (self.remaining, self.options);
+                    let value_start = self.offset();
+                    let Err((error, offset)) = finish(prompt.create(self, remaining, options))
+                    else {
+                        continue;
+                    };
+
+                    self.error(value_start + offset, error)?;
+                }
+
+                Ok(None) => match self.pop() {
+                    Some(parent) => {
+                        level = parent;
+                        continue;
+                    }
+                    None => return ControlFlow::Continue(()),
+                },
+
+                Err((error, offset)) => {
+                    let offset = offset.map_or_else(|| self.offset(), |offset| start + offset);
+                    self.error(offset, error)?;
+                }
+            }
+
+            self.recover(&mut level)?;
+        }
+    }
+
+    /// Start the object or array at the start of the remaining JSON.
+    fn open(&mut self) -> Level<'json> {
+        let level = if self.remaining.starts_with('{') {
+            Level::Object(object::Machine::In { postcomma: false })
+        } else {
+            Level::Array {
+                machine: array::Machine::In { postcomma: false },
+                consumed: 0,
+            }
+        };
+
+        // Skip the opening bracket or brace
+        self.remaining = &self.remaining[1..];
+        level
+    }
+
+    /// Return to the enclosing object or array after a nested one has ended, or [`None`] if it was the outermost.
+    fn pop(&mut self) -> Option<Level<'json>> {
+        self.depth = self.depth.checked_sub(1)?;
+
+        // The nested value has been checked, so the enclosing one continues after it
+        Some(self.levels[self.depth].map_or(
+            Level::Object(object::Machine::Element {
+                // The name is not used once the value has been parsed
+                name: ParsedString::new(""),
+                element: ParseStatus::Done,
+            }),
+            |consumed| Level::Array {
+                machine: array::Machine::Element(ParseStatus::Done),
+                consumed,
+            },
+        ))
+    }
+
+    /// Skip past an error in `level`, to the next comma or terminator, or to the next line if there is neither.
+    ///
+    /// Breaks if there is nothing left to check.
+    fn recover(&mut self, level: &mut Level<'json>) -> ControlFlow<()> {
+        let terminator = match level {
+            Level::Array { .. } => ']',
+            Level::Object(_) => '}',
+        };
+
+        let (skip, end) = match find_separator(self.remaining, terminator) {
+            Some((i, end)) => (i + 1, Some(end)),
+            None => match self.remaining.find('\n') {
+                Some(i) => (i + 1, None),
+                None => return ControlFlow::Break(()),
+            },
+        };
+
+        let skipped = &self.remaining[..skip];
+        self.remaining = &self.remaining[skip..];
+
+        match level {
+            Level::Array { machine, consumed } => {
+                // Count a value that failed before it was started
+                if matches!(machine, array::Machine::In { .. })
+                    && !skipped[..skipped.len() - 1].trim().is_empty()
+                {
+                    *consumed += 1;
+                }
+
+                *machine = match end {
+                    Some(true) => array::Machine::End,
+                    Some(false) => array::Machine::In { postcomma: true },
+                    None => array::Machine::In { postcomma: false },
+                };
+            }
+
+            Level::Object(machine) => {
+                *machine = match end {
+                    Some(true) => object::Machine::End,
+                    Some(false) => object::Machine::In { postcomma: true },
+                    None => object::Machine::In { postcomma: false },
+                };
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Parse up to the start of the next value in an array, returning how it should be parsed.
+    ///
+    /// Errors are returned with the offset from the remaining JSON before this was called, if it is not the remaining JSON after the error.
+    fn array(
+        &mut self,
+        machine: &mut array::Machine,
+        consumed: &mut usize,
+    ) -> Result<Option<ParsePrompt>, (ParseAnyError, Option<usize>)> {
+        loop {
+            if *machine == array::Machine::End {
+                return Ok(None);
+            }
+
+            let (remaining, c) = next_char(self.remaining);
+            self.remaining = remaining;
+            let c = c.ok_or_else(|| {
+                let index = machine.index(*consumed);
+                (ParseArrayError::UnexpectedEnd { index }.into(), None)
+            })?;
+
+            match machine.apply(c, *consumed, self.options) {
+                Ok(array::Machine::Element(ParseStatus::Prompted(prompt))) => {
+                    *machine = array::Machine::Element(ParseStatus::Done);
+                    *consumed += 1;
+
+                    // Values are created from their first character, so don't remove it
+                    return Ok(Some(prompt));
+                }
+
+                Ok(next) => *machine = next,
+                Err(error) => return Err((error.into(), None)),
+            }
+
+            self.remaining = &self.remaining[c.len_utf8()..];
+        }
+    }
+
+    /// Parse up to the start of the next value in an object, returning how it should be parsed.
+    ///
+    /// Errors are returned as in [`Self::array`].
+    fn object(
+        &mut self,
+        machine: &mut object::Machine<'json>,
+    ) -> Result<Option<ParsePrompt>, (ParseAnyError, Option<usize>)> {
+        let start = self.offset();
+
+        loop {
+            match *machine {
+                object::Machine::End => return Ok(None),
+
+                object::Machine::Name(None) => {
+                    let (remaining, options) = (self.remaining, self.options);
+                    let name_start = self.offset() - start;
+                    let mut name = String::new(self, remaining, options);
+
+                    match name.get() {
+                        Ok(name) => *machine = object::Machine::Name(Some(name)),
+                        Err(error) => {
+                            let offset = name_start
+                                + error
+                                    .offset()
+                                    .and_then(|_| name.error_offset())
+                                    .unwrap_or(0);
+                            return Err((
+                                ParseObjectError::InvalidName(error).into(),
+                                Some(offset),
+                            ));
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+
+            let (remaining, c) = next_char(self.remaining);
+            self.remaining = remaining;
+            let c = c.ok_or_else(|| (ParseObjectError::UnexpectedEnd.into(), None))?;
+
+            // Names and values are created from their first character, so don't remove `c` from `self.remaining`
+            match machine.apply(c, self.options) {
+                Ok(object::Machine::Element {
+                    name,
+                    element: ParseStatus::Prompted(prompt),
+                }) => {
+                    *machine = object::Machine::Element {
+                        name,
+                        element: ParseStatus::Done,
+                    };
+                    return Ok(Some(prompt));
+                }
+
+                Ok(next @ object::Machine::Name(None)) => {
+                    *machine = next;
+                    continue;
+                }
+
+                Ok(next) => *machine = next,
+                Err(error) => return Err((error.into(), None)),
+            }
+
+            self.remaining = &self.remaining[c.len_utf8()..];
+        }
+    }
+}
+
+/// Finish a string, number or literal, returning the error and its offset from the start of the value if it fails.
+///
+/// The offset is only known for errors in escape sequences, so it is zero for others.
+fn finish(value: Any) -> Result<(), (ParseAnyError, usize)> {
+    match value {
+        Any::String(mut string) => string.finish().map_err(|error| {
+            (
+                error.into(),
+                error
+                    .offset()
+                    .and_then(|_| string.error_offset())
+                    .unwrap_or(0),
+            )
+        }),
+        mut value => value.finish().map_err(|error| (error, 0)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lint, LintCode, LintIssue, LintSummary, MAX_DEPTH, MAX_ISSUES};
+    use crate::ParseOptions;
+
+    /// Lint `json`, returning the offsets and codes of the first issues.
+    fn lint_issues(
+        json: &str,
+        options: ParseOptions,
+    ) -> (LintSummary, [Option<(usize, LintCode)>; 4]) {
+        let mut issues = [None; 4];
+        let mut i = 0;
+        let summary = lint(json, &options, &mut |issue: LintIssue| {
+            assert_eq!(issue.possibly_spurious, i > 0, "{json:?}: {issue}");
+            if let Some(slot) = issues.get_mut(i) {
+                *slot = Some((issue.offset, issue.code));
+            }
+            i += 1;
+        });
+
+        assert_eq!(summary.issues, i, "{json:?}");
+        (summary, issues)
+    }
+
+    #[test]
+    fn valid() {
+        for json in [
+            "1",
+            r#"  {"a": [1, 2.5e3, {"b": null}], "c": "\u00e9\n"} "#,
+            "[[], {}, [[true]]]",
+        ] {
+            let (summary, _) = lint_issues(json, ParseOptions::new());
+            assert!(summary.is_valid(), "{json:?}");
+            assert!(!summary.truncated);
+        }
+    }
+
+    #[test]
+    fn three_errors() {
+        use LintCode::{
+            InvalidLiteral, InvalidNumber, InvalidString, TrailingComma, UnexpectedCharacter,
+        };
+
+        let cases = [
+            (
+                r#"[{"a": 1, "b": x}, [1, 2 3], {"c" 4}, "ok"]"#,
+                [
+                    (15, UnexpectedCharacter),
+                    (25, UnexpectedCharacter),
+                    (34, UnexpectedCharacter),
+                ],
+            ),
+            (
+                "{\n  \"name\": \"\\q\",\n  \"count\": -x,\n  \"flag\": tru,\n  \"ok\": [1, 2]\n}",
+                [
+                    (13, InvalidString),
+                    (29, InvalidNumber),
+                    (43, InvalidLiteral),
+                ],
+            ),
+            (
+                r#"{"a": [1, 2, ], "b": {"c": [[nul]]}, "d\ud800": [1, 2]}"#,
+                [
+                    (13, TrailingComma),
+                    (29, InvalidLiteral),
+                    (44, InvalidString),
+                ],
+            ),
+        ];
+
+        for (json, expected) in cases {
+            let (summary, issues) = lint_issues(json, ParseOptions::new());
+            assert_eq!(summary.issues, 3, "{json:?}");
+            assert_eq!(issues[..3], expected.map(Some), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn resync() {
+        // The unterminated string has no comma or terminator after it, so linting continues from the next line
+        let json = "[\n  \"a,\n  1 2,\n  3\n]";
+        let (summary, issues) = lint_issues(json, ParseOptions::new());
+        assert_eq!(summary.issues, 2);
+        assert_eq!(
+            issues[..2],
+            [
+                Some((4, LintCode::UnexpectedEnd)),
+                Some((12, LintCode::UnexpectedCharacter))
+            ]
+        );
+    }
+
+    #[test]
+    fn document_errors() {
+        for (json, expected) in [
+            ("", (0, LintCode::UnexpectedEnd)),
+            ("  x", (2, LintCode::UnexpectedCharacter)),
+            ("[1, 2", (5, LintCode::UnexpectedEnd)),
+            ("{} x", (3, LintCode::TrailingCharacters)),
+            ("01", (1, LintCode::TrailingCharacters)),
+            ("\"ab\\x\"", (3, LintCode::InvalidString)),
+        ] {
+            let (summary, issues) = lint_issues(json, ParseOptions::new());
+            assert_eq!(summary.issues, 1, "{json:?}");
+            assert_eq!(issues[0], Some(expected), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn options() {
+        let json = r#"{"a": [1, 2,], "b": "\x41",}"#;
+
+        let (summary, issues) = lint_issues(json, ParseOptions::new());
+        assert_eq!(summary.issues, 3);
+        assert_eq!(
+            issues[..3],
+            [
+                Some((12, LintCode::TrailingComma)),
+                Some((21, LintCode::InvalidString)),
+                Some((27, LintCode::TrailingComma)),
+            ]
+        );
+
+        let options = ParseOptions::new()
+            .allow_trailing_commas(true)
+            .allow_json5_escapes(true);
+        assert!(lint_issues(json, options).0.is_valid());
+    }
+
+    #[test]
+    fn max_issues() {
+        let json = format!("[{}1]", "x, ".repeat(MAX_ISSUES * 2));
+        let (summary, issues) = lint_issues(&json, ParseOptions::new());
+        assert_eq!(summary.issues, MAX_ISSUES);
+        assert!(summary.truncated);
+        assert_eq!(issues[1], Some((4, LintCode::UnexpectedCharacter)));
+    }
+
+    #[test]
+    fn too_deep() {
+        let json = format!("[{}1{}, x]", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+        let (summary, issues) = lint_issues(&json, ParseOptions::new());
+        assert_eq!(summary.issues, 2);
+        assert_eq!(issues[0], Some((MAX_DEPTH, LintCode::TooDeep)));
+        assert_eq!(
+            issues[1],
+            Some((2 * MAX_DEPTH + 4, LintCode::UnexpectedCharacter))
+        );
+    }
+}
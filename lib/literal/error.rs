@@ -1,5 +1,7 @@
 use core::fmt;
 
+use crate::debug::DisplayChar;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The error returned when parsing a [`Literal`](super::Literal) fails.
 pub enum ParseLiteralError {
@@ -13,10 +15,31 @@ impl fmt::Display for ParseLiteralError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnexpectedEnd => write!(f, "Unexpected end of JSON literal!"),
-            Self::UnexpectedCharacter(c) => write!(f, "Invalid character ({c}) in JSON literal!"),
+            Self::UnexpectedCharacter(c) => write!(
+                f,
+                "Invalid character ({c}) in JSON literal!",
+                c = DisplayChar(*c)
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseLiteralError {}
+impl core::error::Error for ParseLiteralError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseLiteralError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseLiteralError::UnexpectedCharacter('\0').to_string(),
+            "Invalid character (U+0000) in JSON literal!"
+        );
+        assert_eq!(
+            ParseLiteralError::UnexpectedCharacter('x').to_string(),
+            "Invalid character (x) in JSON literal!"
+        );
+    }
+}
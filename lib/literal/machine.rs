@@ -20,7 +20,7 @@ pub enum Machine {
 }
 
 impl Machine {
-    pub fn apply(self, c: char) -> Result<Self, ParseLiteralError> {
+    pub const fn apply(self, c: char) -> Result<Self, ParseLiteralError> {
         match self {
             Self::Start => match c {
                 w if w.is_whitespace() => Ok(Self::Start),
@@ -1,4 +1,4 @@
-use crate::{debug::debug_impl, Parent};
+use crate::{containers::span, debug::debug_impl, Parent, ParseOptions};
 
 mod error;
 mod machine;
@@ -11,11 +11,22 @@ pub use parsed::ParsedLiteral;
 pub struct Literal<'json, 'p> {
     parent: &'p mut dyn Parent<'json>,
     remaining: &'json str,
+    end: Option<&'json str>,
+    options: ParseOptions,
 }
 
 impl<'json, 'p> Literal<'json, 'p> {
-    pub(crate) fn new(parent: &'p mut dyn Parent<'json>, remaining: &'json str) -> Self {
-        Self { parent, remaining }
+    pub(crate) fn new(
+        parent: &'p mut dyn Parent<'json>,
+        remaining: &'json str,
+        options: ParseOptions,
+    ) -> Self {
+        Self {
+            parent,
+            remaining,
+            end: None,
+            options,
+        }
     }
 
     /// Try to parse the literal.
@@ -32,6 +43,7 @@ impl<'json, 'p> Literal<'json, 'p> {
                 let next_i = i + c.len_utf8();
                 let remaining = &self.remaining[next_i..];
                 self.parent.set_remaining(remaining);
+                self.end = Some(remaining);
                 return Ok(value);
             }
         }
@@ -49,6 +61,32 @@ impl<'json, 'p> Literal<'json, 'p> {
     pub fn finish(&mut self) -> Result<(), ParseLiteralError> {
         self.get().map(drop)
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the literal has been successfully parsed, so the parent can continue.
+    pub const fn is_finished(&self) -> bool {
+        self.end.is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this literal is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) fn span(&self) -> Option<&'json str> {
+        self.end.map(|end| span(self.remaining, end))
+    }
 }
 
 debug_impl!("Literal", Literal<'json, 'p>);
@@ -1,4 +1,4 @@
-use core::{convert::identity, fmt, ops::Not};
+use core::fmt;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// A parsed JSON literal.
@@ -73,8 +73,8 @@ impl PartialEq<Option<bool>> for ParsedLiteral {
     #[inline]
     fn eq(&self, other: &Option<bool>) -> bool {
         match self {
-            Self::True => other.map_or(false, identity),
-            Self::False => other.map_or(false, Not::not),
+            Self::True => *other == Some(true),
+            Self::False => *other == Some(false),
             Self::Null => other.is_none(),
         }
     }
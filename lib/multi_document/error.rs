@@ -1,12 +1,56 @@
 use core::fmt;
 
-use crate::{any, array, literal, number, object, string};
+use crate::{any, array, debug::DisplayChar, literal, number, object, string};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The error returned when parsing a [`MultiDocument`](super::MultiDocument) fails.
 pub enum ParseMultiDocumentError {
     /// A character that was not the start of a valid element was found.
     InvalidElement(char),
+    /// A non-whitespace control character (such as a NUL byte) was found outside of a value.
+    ControlCharacter(char),
+    /// In newline-delimited JSON, a value started on the same line as the last value.
+    ValueOnSameLine {
+        /// The line that the value started on, starting at 1.
+        line: usize,
+    },
+    /// In newline-delimited JSON, a line was blank, and blank lines are not allowed.
+    BlankLine {
+        /// The blank line, starting at 1.
+        line: usize,
+    },
+    /// In a JSON text sequence, a value did not start with a record separator.
+    MissingRecordSeparator {
+        /// The index of the value in the sequence.
+        index: usize,
+    },
+    /// In a JSON text sequence, a record did not end with a newline.
+    TruncatedRecord {
+        /// The index of the record's value in the sequence.
+        index: usize,
+    },
+    /// In comma-separated values, a value was not separated from the last value by a comma.
+    ExpectedComma {
+        /// The index of the value.
+        index: usize,
+    },
+    /// In comma-separated values, a comma was found before the first value or after another comma.
+    UnexpectedComma {
+        /// The index of the value expected instead of the comma.
+        index: usize,
+    },
+    /// In comma-separated values, the values ended directly after a comma.
+    TrailingComma {
+        /// The index of the value expected after the comma.
+        index: usize,
+    },
+    /// A value started directly after a number or literal, and values must be separated.
+    MissingValueSeparator(char),
+    /// More values were found than the limit set by [`MultiDocument::with_max_documents`](super::MultiDocument::with_max_documents).
+    TooManyDocuments {
+        /// The maximum number of values.
+        limit: usize,
+    },
 }
 
 impl fmt::Display for ParseMultiDocumentError {
@@ -14,14 +58,55 @@ impl fmt::Display for ParseMultiDocumentError {
         match self {
             Self::InvalidElement(c) => write!(
                 f,
-                "Invalid character ({c}) in JSON document (expected an element)!"
+                "Invalid character ({c}) in JSON document (expected an element)!",
+                c = DisplayChar(*c)
+            ),
+            Self::ControlCharacter(c) => write!(
+                f,
+                "Invalid control character ({c}) in JSON document!",
+                c = DisplayChar(*c)
+            ),
+            Self::ValueOnSameLine { line } => write!(
+                f,
+                "Multiple values on line {line} of newline-delimited JSON document!"
+            ),
+            Self::BlankLine { line } => {
+                write!(f, "Blank line {line} in newline-delimited JSON document!")
+            }
+            Self::MissingRecordSeparator { index } => write!(
+                f,
+                "Missing record separator before element {index} of JSON text sequence!"
+            ),
+            Self::TruncatedRecord { index } => {
+                write!(f, "Truncated record {index} in JSON text sequence!")
+            }
+            Self::ExpectedComma { index } => write!(
+                f,
+                "Missing comma before element {index} of comma-separated JSON values!"
+            ),
+            Self::UnexpectedComma { index } => write!(
+                f,
+                "Unexpected comma at element {index} of comma-separated JSON values!"
+            ),
+            Self::TrailingComma { index } => write!(
+                f,
+                "Trailing comma at element {index} of comma-separated JSON values!"
+            ),
+            Self::MissingValueSeparator(c) => write!(
+                f,
+                "Missing whitespace before character ({c}) in JSON document (expected a separator after the last value)!",
+                c = DisplayChar(*c)
+            ),
+            Self::TooManyDocuments { limit } => write!(
+                f,
+                "Too many elements in JSON document (the limit is {limit})!"
             ),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseMultiDocumentError {}
+impl core::error::Error for ParseMultiDocumentError {}
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -44,9 +129,9 @@ impl fmt::Display for ParseAnyMultiDocumentError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseAnyMultiDocumentError {
+impl core::error::Error for ParseAnyMultiDocumentError {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         Some(match self {
             Self::MultiDocument(err) => err,
             Self::Any(err) => err,
@@ -102,3 +187,24 @@ impl From<literal::ParseLiteralError> for ParseAnyMultiDocumentError {
         Self::Any(value.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ParseMultiDocumentError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseMultiDocumentError::InvalidElement('j').to_string(),
+            "Invalid character (j) in JSON document (expected an element)!"
+        );
+        assert_eq!(
+            ParseMultiDocumentError::ControlCharacter('\0').to_string(),
+            "Invalid control character (U+0000) in JSON document!"
+        );
+        assert_eq!(
+            ParseMultiDocumentError::ValueOnSameLine { line: 3 }.to_string(),
+            "Multiple values on line 3 of newline-delimited JSON document!"
+        );
+    }
+}
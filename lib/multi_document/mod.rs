@@ -1,17 +1,79 @@
+use core::str::{self, Utf8Error};
+
 use crate::{
-    any::Any,
-    containers::{fff_impl, ParsePrompt, ParseStatus},
+    any::{Any, ValueKind},
+    containers::{fff_impl, span, ParsePrompt, ParseStatus, BOM},
     debug::debug_impl,
-    Parent,
+    Parent, ParseOptions,
 };
 
 mod error;
 pub use error::{ParseAnyMultiDocumentError, ParseMultiDocumentError};
+mod progress;
+pub use progress::OnProgress;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+pub use par::{par_for_each_lines, LineError};
+#[cfg(feature = "std")]
+mod read;
+#[cfg(feature = "std")]
+pub use read::ReadMultiDocument;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A saved parsing position in a [`MultiDocument`], created by [`MultiDocument::checkpoint`].
+pub struct MultiDocumentCheckpoint<'json> {
+    remaining: &'json str,
+    parse_status: ParseStatus,
+    line: usize,
+    separators: usize,
+    consumed: usize,
+    unseparated: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// What must separate the values in a [`MultiDocument`].
+enum Separator {
+    /// Any whitespace, or nothing.
+    Whitespace,
+    /// A newline, as in newline-delimited JSON.
+    Newline { allow_blank_lines: bool },
+    /// A record separator before each value, as in JSON text sequences.
+    Record { allow_truncated: bool },
+    /// A comma, as between the values of an array.
+    Comma,
+}
+
+/// The record separator (`U+001E`), which starts each value in a JSON text sequence.
+pub const RS: char = '\u{1e}';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The progress of parsing a [`MultiDocument`], passed to the hook given to [`MultiDocument::on_progress`].
+pub struct Progress {
+    /// The number of values that have been yielded.
+    pub values: usize,
+    /// The number of bytes of the input before the latest value.
+    pub bytes_consumed: usize,
+}
 
+// Not `Copy`, so that copying the parser is explicit
+#[allow(missing_copy_implementations)]
+#[derive(Clone)]
 /// A JSON document created from a string with multiple elements (or none).
 pub struct MultiDocument<'json> {
+    start: &'json str,
     remaining: &'json str,
     parse_status: ParseStatus,
+    options: ParseOptions,
+    separator: Separator,
+    require_separation: bool,
+    line: usize,
+    /// The number of separators since the end of the last value, or the start.
+    separators: usize,
+    consumed: usize,
+    max_documents: Option<usize>,
+    /// If the last value needs separating from the next value, and nothing has separated them yet.
+    unseparated: bool,
 }
 
 impl<'json> Parent<'json> for MultiDocument<'json> {
@@ -19,6 +81,16 @@ impl<'json> Parent<'json> for MultiDocument<'json> {
     where
         'json: 'a,
     {
+        // Values can contain newlines as whitespace in objects and arrays
+        let value = span(self.remaining, remaining);
+        self.line += value.bytes().filter(|&b| b == b'\n').count();
+        self.separators = 0;
+        self.unseparated = self.require_separation
+            && matches!(
+                self.parse_status,
+                ParseStatus::Prompted(ParsePrompt::Number | ParsePrompt::Literal)
+            );
+
         self.remaining = remaining;
         self.parse_status = ParseStatus::Done;
     }
@@ -32,10 +104,256 @@ impl<'json> MultiDocument<'json> {
     #[must_use]
     #[inline]
     /// Create a new JSON multi-document from a string.
+    ///
+    /// A byte order mark (`U+FEFF`) at the start of `json` is skipped.
     pub const fn new(json: &'json str) -> Self {
+        Self::with_options(json, ParseOptions::new())
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new JSON multi-document from a string, parsed with `options`.
+    ///
+    /// A byte order mark (`U+FEFF`) at the start of `json` is skipped.
+    pub const fn with_options(json: &'json str, options: ParseOptions) -> Self {
         Self {
+            start: json,
             remaining: json,
             parse_status: ParseStatus::Done,
+            options,
+            separator: Separator::Whitespace,
+            require_separation: false,
+            line: 1,
+            separators: 0,
+            consumed: 0,
+            max_documents: None,
+            unseparated: false,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new newline-delimited JSON (NDJSON) multi-document from a string.
+    ///
+    /// Each value must start on a new line, otherwise [`ParseMultiDocumentError::ValueOnSameLine`] is returned.
+    /// By default, blank lines (including whitespace-only lines) are not allowed, and return [`ParseMultiDocumentError::BlankLine`]; use [`Self::allow_blank_lines`] to skip them.
+    /// A newline after the last value is allowed, but not required.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::ndjson("{\"a\": 1}\n[2]\n");
+    /// while let Some(mut value) = document.next()? {
+    ///     value.finish()?;
+    /// }
+    /// assert_eq!(document.current_line(), 3);
+    ///
+    /// let mut document = MultiDocument::ndjson("1\n2 3\n");
+    /// let error = document.finish().expect_err("expected two values on a line");
+    /// assert_eq!(error, ParseMultiDocumentError::ValueOnSameLine { line: 2 }.into());
+    /// # Ok::<(), zjson::multi_document::ParseAnyMultiDocumentError>(())
+    /// ```
+    pub const fn ndjson(json: &'json str) -> Self {
+        let mut document = Self::new(json);
+        document.separator = Separator::Newline {
+            allow_blank_lines: false,
+        };
+        document
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new JSON text sequence (RFC 7464) multi-document from a string.
+    ///
+    /// Each value must be in a record that starts with a record separator ([`RS`]) and ends with a newline.
+    /// A value without a record separator before it returns [`ParseMultiDocumentError::MissingRecordSeparator`].
+    /// Empty records are skipped.
+    ///
+    /// A record that does not end with a newline is truncated, and returns [`ParseMultiDocumentError::TruncatedRecord`].
+    /// By default, as recommended by the RFC, a truncated final record is skipped instead, as it may still be being written; use [`Self::allow_truncated_records`] to change this.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::json_seq("\u{1e}[1]\n\u{1e}{\"a\": 2}\n\u{1e}[3, ");
+    /// let mut values = 0;
+    /// while let Some(mut value) = document.next()? {
+    ///     value.finish()?;
+    ///     values += 1;
+    /// }
+    /// assert_eq!(values, 2);
+    ///
+    /// let mut document = MultiDocument::json_seq("\u{1e}1\n2\n");
+    /// let error = document.finish().expect_err("expected a missing record separator");
+    /// assert_eq!(error, ParseMultiDocumentError::MissingRecordSeparator { index: 1 }.into());
+    /// # Ok::<(), zjson::multi_document::ParseAnyMultiDocumentError>(())
+    /// ```
+    pub const fn json_seq(json: &'json str) -> Self {
+        let mut document = Self::new(json);
+        document.separator = Separator::Record {
+            allow_truncated: true,
+        };
+        document
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new multi-document from a string of comma-separated values, like the contents of an array without the brackets.
+    ///
+    /// Exactly one comma must separate each pair of values.
+    /// A missing comma returns [`ParseMultiDocumentError::ExpectedComma`], a comma before the first value or after another comma returns [`ParseMultiDocumentError::UnexpectedComma`], and a comma after the last value returns [`ParseMultiDocumentError::TrailingComma`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::comma_separated(r#"1, "a", [2]"#);
+    /// let mut values = 0;
+    /// while let Some(mut value) = document.next()? {
+    ///     value.finish()?;
+    ///     values += 1;
+    /// }
+    /// assert_eq!(values, 3);
+    ///
+    /// let mut document = MultiDocument::comma_separated("1,,2");
+    /// let error = document.finish().expect_err("expected a double comma");
+    /// assert_eq!(error, ParseMultiDocumentError::UnexpectedComma { index: 1 }.into());
+    /// # Ok::<(), zjson::multi_document::ParseAnyMultiDocumentError>(())
+    /// ```
+    pub const fn comma_separated(json: &'json str) -> Self {
+        let mut document = Self::new(json);
+        document.separator = Separator::Comma;
+        document
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set whether a truncated final record is skipped in a multi-document created with [`Self::json_seq`].
+    ///
+    /// Other truncated records always return an error.
+    pub const fn allow_truncated_records(mut self, allow: bool) -> Self {
+        if let Separator::Record { allow_truncated } = &mut self.separator {
+            *allow_truncated = allow;
+        }
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set whether a number or literal must be separated from the next value, by whitespace or the separator of the multi-document.
+    ///
+    /// Without this, `truefalse` is parsed as `true` followed by `false`, and `1"a"` as `1` followed by `"a"`.
+    /// With it, these return [`ParseMultiDocumentError::MissingValueSeparator`].
+    /// Strings, objects and arrays end with a delimiter, so they do not need separating.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::new("[1][2] true false");
+    /// assert!(document.finish().is_ok());
+    ///
+    /// let mut document = MultiDocument::new("truefalse").require_separation(true);
+    /// let error = document.finish().expect_err("expected a missing separator");
+    /// assert_eq!(error, ParseMultiDocumentError::MissingValueSeparator('f').into());
+    /// ```
+    pub const fn require_separation(mut self, require: bool) -> Self {
+        self.require_separation = require;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Limit the number of values that can be parsed from the multi-document to `max`.
+    ///
+    /// Once `max` values have been yielded, [`Self::next`] returns [`ParseMultiDocumentError::TooManyDocuments`] instead of another value, and so do [`Self::finish`] and the `for_each` and `fold` methods.
+    /// This guards against untrusted input with an unbounded number of values.
+    /// By default, there is no limit.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::new("1 2 3").with_max_documents(3);
+    /// assert!(document.finish().is_ok());
+    ///
+    /// let mut document = MultiDocument::new("1 2 3 4").with_max_documents(3);
+    /// let error = document.finish().expect_err("expected too many values");
+    /// assert_eq!(error, ParseMultiDocumentError::TooManyDocuments { limit: 3 }.into());
+    /// ```
+    pub const fn with_max_documents(mut self, max: usize) -> Self {
+        self.max_documents = Some(max);
+        self
+    }
+
+    /// Returns a view of the multi-document that calls `f` with the progress of parsing every `every_n_values` values, to report progress through a long input.
+    ///
+    /// `f` is called from [`OnProgress::next`] when it yields a new value, so it is also called from [`OnProgress::finish`] and the `for_each` and `fold` methods.
+    /// The progress is approximate, as the bytes consumed only advance when values finish, so they do not include the latest value.
+    /// Parsing continues from the multi-document's position, and the multi-document can be used again once the view is dropped.
+    ///
+    /// # Panics
+    /// Panics if `every_n_values` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, Progress};
+    ///
+    /// let mut reports = Vec::new();
+    ///
+    /// let mut document = MultiDocument::new("1 2 3 4 5");
+    /// document
+    ///     .on_progress(2, |progress: Progress| reports.push(progress.values))
+    ///     .finish()?;
+    ///
+    /// assert_eq!(reports, [2, 4]);
+    /// # Ok::<(), zjson::multi_document::ParseAnyMultiDocumentError>(())
+    /// ```
+    pub fn on_progress<F>(&mut self, every_n_values: usize, f: F) -> OnProgress<'_, 'json, F>
+    where
+        F: FnMut(Progress),
+    {
+        assert!(
+            every_n_values != 0,
+            "progress must be reported at least every value"
+        );
+
+        OnProgress::new(self, every_n_values, f)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set whether blank lines are skipped in a multi-document created with [`Self::ndjson`].
+    ///
+    /// Other multi-documents always skip blank lines.
+    pub const fn allow_blank_lines(mut self, allow: bool) -> Self {
+        if let Separator::Newline { allow_blank_lines } = &mut self.separator {
+            *allow_blank_lines = allow;
+        }
+        self
+    }
+
+    #[inline]
+    /// Create a new JSON multi-document from bytes, which must be valid UTF-8.
+    ///
+    /// # Errors
+    /// If `bytes` is not valid UTF-8, this will return a [`Utf8Error`], which has the byte offset of the invalid sequence in [`Utf8Error::valid_up_to`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::MultiDocument;
+    ///
+    /// assert!(MultiDocument::from_bytes(b"[1, 2]").is_ok());
+    ///
+    /// let error = MultiDocument::from_bytes(b"[\"\xff\"]").expect_err("expected invalid UTF-8");
+    /// assert_eq!(error.valid_up_to(), 2);
+    /// ```
+    pub const fn from_bytes(bytes: &'json [u8]) -> Result<Self, Utf8Error> {
+        match str::from_utf8(bytes) {
+            Ok(json) => Ok(Self::new(json)),
+            Err(error) => Err(error),
         }
     }
 
@@ -44,33 +362,318 @@ impl<'json> MultiDocument<'json> {
     ///
     /// # Errors
     /// If parsing fails, this will return a [`ParseMultiDocumentError`].
+    /// The index of the value that failed is returned by [`Self::index`].
     pub fn next(&mut self) -> Result<Option<Any<'json, '_>>, ParseMultiDocumentError> {
+        self.next_reporting(None)
+    }
+
+    /// [`Self::next`], calling `progress.1` every `progress.0` values.
+    fn next_reporting(
+        &mut self,
+        progress: Option<(usize, &mut dyn FnMut(Progress))>,
+    ) -> Result<Option<Any<'json, '_>>, ParseMultiDocumentError> {
+        let started = matches!(self.parse_status, ParseStatus::Prompted(_));
+
+        let Some(prompt) = self.advance()? else {
+            return Ok(None);
+        };
+
+        if !started {
+            if let Some(limit) = self.max_documents {
+                if self.consumed >= limit {
+                    return Err(ParseMultiDocumentError::TooManyDocuments { limit });
+                }
+            }
+
+            self.parse_status = prompt.into();
+            self.consumed += 1;
+
+            if let Some((every, f)) = progress {
+                if self.consumed.is_multiple_of(every) {
+                    f(Progress {
+                        values: self.consumed,
+                        bytes_consumed: self.start.len() - self.remaining.len(),
+                    });
+                }
+            }
+        }
+
+        let (remaining, options) = (self.remaining, self.options);
+        Ok(Some(prompt.create(self, remaining, options)))
+    }
+
+    /// Returns the kind of the next value in the multi-document, without yielding it.
+    ///
+    /// Whitespace before the value is skipped, so [`Self::next`] continues from the value.
+    /// Once there are no more values, this will return [`None`].
+    ///
+    /// # Errors
+    /// If parsing fails, this will return a [`ParseMultiDocumentError`], the same as [`Self::next`] would.
+    #[inline]
+    pub fn peek(&mut self) -> Result<Option<ValueKind>, ParseMultiDocumentError> {
+        Ok(self.advance()?.map(ParsePrompt::kind))
+    }
+
+    /// Returns [`true`] if there is another value in the multi-document.
+    ///
+    /// Whitespace before the value is skipped, as in [`Self::peek`].
+    /// A value that has been yielded but not finished is counted, as [`Self::next`] will yield it again.
+    ///
+    /// # Errors
+    /// If parsing fails, this will return a [`ParseMultiDocumentError`], the same as [`Self::next`] would.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::MultiDocument;
+    ///
+    /// let mut document = MultiDocument::new("1 2 3");
+    /// let mut sum = 0;
+    ///
+    /// while document.has_more()? {
+    ///     let mut value = document.next()?.expect("expected a value");
+    ///     sum += value.read_number()?.as_u32().expect("expected a u32");
+    /// }
+    ///
+    /// assert_eq!(sum, 6);
+    /// # Ok::<(), zjson::multi_document::ParseAnyMultiDocumentError>(())
+    /// ```
+    #[inline]
+    pub fn has_more(&mut self) -> Result<bool, ParseMultiDocumentError> {
+        Ok(self.advance()?.is_some())
+    }
+
+    /// Parse up to the start of the next value, returning how it should be parsed, without starting it.
+    ///
+    /// Returns [`None`] if there are no more values.
+    fn advance(&mut self) -> Result<Option<ParsePrompt>, ParseMultiDocumentError> {
         loop {
             if let ParseStatus::Prompted(prompt) = self.parse_status {
-                let remaining = self.remaining;
-                return Ok(Some(prompt.create(self, remaining)));
+                return Ok(Some(prompt));
             }
 
             let Some(c) = self.remaining.chars().next() else {
+                self.check_separator(true)?;
                 return Ok(None);
             };
 
-            if c.is_whitespace() {
+            if c == '\n' {
+                self.line += 1;
+                if matches!(self.separator, Separator::Newline { .. }) {
+                    self.separators += 1;
+                }
+            } else if c == RS && matches!(self.separator, Separator::Record { .. }) {
+                self.separators += 1;
+
+                if !self.check_record()? {
+                    // Skip the truncated final record
+                    let rest = &self.remaining[RS.len_utf8()..];
+                    self.line += rest.bytes().filter(|&b| b == b'\n').count();
+                    self.remaining = &rest[rest.len()..];
+                    return Ok(None);
+                }
+            } else if c == ',' && matches!(self.separator, Separator::Comma) {
+                if self.consumed == 0 || self.separators != 0 {
+                    return Err(ParseMultiDocumentError::UnexpectedComma {
+                        index: self.consumed,
+                    });
+                }
+                self.separators += 1;
+            } else if c.is_whitespace() || (c == BOM && self.offset() == 0) {
                 // do nothing
+            } else if c.is_control() {
+                return Err(ParseMultiDocumentError::ControlCharacter(c));
             } else if let Some(prompt) = ParsePrompt::get(c) {
-                self.parse_status = prompt.into();
-
-                if prompt.keep_first() {
-                    continue;
+                if self.unseparated {
+                    return Err(ParseMultiDocumentError::MissingValueSeparator(c));
                 }
+                self.check_separator(false)?;
+                // Values are created from their first character
+                return Ok(Some(prompt));
             } else {
                 return Err(ParseMultiDocumentError::InvalidElement(c));
             }
 
+            self.unseparated = false;
             self.remaining = &self.remaining[c.len_utf8()..];
         }
     }
 
+    /// Check the whitespace between the last value and the next value, or the end if `end`.
+    const fn check_separator(&self, end: bool) -> Result<(), ParseMultiDocumentError> {
+        let allow_blank_lines = match self.separator {
+            Separator::Whitespace => return Ok(()),
+            Separator::Newline { allow_blank_lines } => allow_blank_lines,
+            Separator::Record { .. } => {
+                return if self.separators == 0 && !end {
+                    Err(ParseMultiDocumentError::MissingRecordSeparator {
+                        index: self.consumed,
+                    })
+                } else {
+                    Ok(())
+                };
+            }
+            Separator::Comma => {
+                return match (self.consumed, self.separators, end) {
+                    (1.., 0, false) => Err(ParseMultiDocumentError::ExpectedComma {
+                        index: self.consumed,
+                    }),
+                    (_, 1.., true) => Err(ParseMultiDocumentError::TrailingComma {
+                        index: self.consumed,
+                    }),
+                    _ => Ok(()),
+                };
+            }
+        };
+
+        if self.consumed != 0 && self.separators == 0 && !end {
+            return Err(ParseMultiDocumentError::ValueOnSameLine { line: self.line });
+        }
+
+        // The line that the last value ended on is not blank
+        let blank = if self.consumed != 0 {
+            self.separators.saturating_sub(1)
+        } else {
+            self.separators
+        };
+        if blank != 0 && !allow_blank_lines {
+            return Err(ParseMultiDocumentError::BlankLine {
+                line: self.line - blank,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check the record starting with the record separator at the start of the remaining JSON.
+    ///
+    /// Returns [`false`] if it is a truncated final record that should be skipped.
+    fn check_record(&self) -> Result<bool, ParseMultiDocumentError> {
+        let record = &self.remaining[RS.len_utf8()..];
+        let (record, last) = record
+            .find(RS)
+            .map_or((record, true), |i| (&record[..i], false));
+
+        if record.ends_with('\n') || record.trim().is_empty() {
+            Ok(true)
+        } else if last
+            && matches!(
+                self.separator,
+                Separator::Record {
+                    allow_truncated: true
+                }
+            )
+        {
+            Ok(false)
+        } else {
+            Err(ParseMultiDocumentError::TruncatedRecord {
+                index: self.consumed,
+            })
+        }
+    }
+
+    /// Runs `f` for each value in the multi-document, passing in the source text of the value.
+    ///
+    /// Each value is checked before it is passed to `f`.
+    /// The source text is the exact slice of the input that the value was parsed from, without the whitespace or separators around it.
+    ///
+    /// # Errors
+    /// If parsing fails in this multi-document or if `f` returns an error, an instance of `E` is returned.
+    /// If you do not need a custom error type, use [`ParseAnyMultiDocumentError`] as `E`.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseAnyMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::ndjson("{\"a\": [1, 2]}\n  \"b\"\n3\n");
+    /// let mut records = Vec::new();
+    ///
+    /// document.for_each_raw(|raw| {
+    ///     records.push(raw);
+    ///     Ok::<_, ParseAnyMultiDocumentError>(())
+    /// })?;
+    ///
+    /// assert_eq!(records, [r#"{"a": [1, 2]}"#, r#""b""#, "3"]);
+    /// # Ok::<(), ParseAnyMultiDocumentError>(())
+    /// ```
+    pub fn for_each_raw<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&'json str) -> Result<(), E>,
+        E: From<ParseAnyMultiDocumentError>,
+    {
+        loop {
+            // Skip to the start of the value
+            if self
+                .peek()
+                .map_err(ParseAnyMultiDocumentError::from)?
+                .is_none()
+            {
+                return Ok(());
+            }
+            let start = self.remaining;
+
+            if let Some(mut value) = self.next().map_err(ParseAnyMultiDocumentError::from)? {
+                value.finish().map_err(ParseAnyMultiDocumentError::from)?;
+            }
+
+            f(span(start, self.remaining))?;
+        }
+    }
+
+    /// Skip the rest of the current line, so that parsing can continue after an error, such as in a corrupt record in newline-delimited JSON.
+    ///
+    /// If a value has been started, the line that it starts on is skipped.
+    /// Newlines in strings do not end the line, so the rest of a record with a newline in a string is skipped with it.
+    /// If a string is never closed, the line ends at the next newline instead, so that one corrupt record does not hide the records after it.
+    /// A record that failed before its value was started is counted in [`Self::index`], so the index of the next value is the same as if the record were valid.
+    ///
+    /// If there are no more lines, everything left is skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::{MultiDocument, ParseAnyMultiDocumentError};
+    ///
+    /// let mut document = MultiDocument::ndjson("1\n[2, x]\n\"3\"\n");
+    ///
+    /// let mut kinds = Vec::new();
+    /// let mut failed = Vec::new();
+    /// loop {
+    ///     let result = match document.next() {
+    ///         Ok(Some(mut value)) => value.finish().map(|()| value.kind()).map_err(Into::into),
+    ///         Ok(None) => break,
+    ///         Err(error) => Err(ParseAnyMultiDocumentError::from(error)),
+    ///     };
+    ///
+    ///     match result {
+    ///         Ok(kind) => kinds.push(kind),
+    ///         Err(_) => {
+    ///             failed.push(document.index() - 1);
+    ///             document.skip_to_next_line();
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(kinds.len(), 2);
+    /// assert_eq!(failed, [1]);
+    /// ```
+    pub fn skip_to_next_line(&mut self) {
+        let (skipped, rest) = match find_line_end(self.remaining) {
+            Some(i) => self.remaining.split_at(i + 1),
+            None => self.remaining.split_at(self.remaining.len()),
+        };
+
+        // Count a record that failed before it was started
+        if self.parse_status == ParseStatus::Done && !skipped.trim().is_empty() {
+            self.consumed += 1;
+        }
+
+        self.line += skipped.bytes().filter(|&b| b == b'\n').count();
+        self.separators = usize::from(
+            matches!(self.separator, Separator::Newline { .. }) && skipped.ends_with('\n'),
+        );
+        self.remaining = rest;
+        self.parse_status = ParseStatus::Done;
+    }
+
     /// Finish parsing this multi-document.
     /// This can be used to make sure that there are no errors after the used values.
     ///
@@ -86,22 +689,655 @@ impl<'json> MultiDocument<'json> {
         Ok(())
     }
 
+    #[must_use]
+    #[inline]
+    /// Save the current parsing position, so that it can be returned to with [`Self::rollback`].
+    ///
+    /// Cloning the multi-document also saves the position, but a clone can be parsed independently.
+    /// Values from a clone must not be mixed with values from the original, as each only updates the multi-document it came from.
+    pub const fn checkpoint(&self) -> MultiDocumentCheckpoint<'json> {
+        MultiDocumentCheckpoint {
+            remaining: self.remaining,
+            parse_status: self.parse_status,
+            line: self.line,
+            separators: self.separators,
+            consumed: self.consumed,
+            unseparated: self.unseparated,
+        }
+    }
+
+    #[inline]
+    /// Return to a parsing position saved with [`Self::checkpoint`], so that values can be parsed again.
+    ///
+    /// `checkpoint` must have been created by this multi-document.
+    pub const fn rollback(&mut self, checkpoint: MultiDocumentCheckpoint<'json>) {
+        self.remaining = checkpoint.remaining;
+        self.parse_status = checkpoint.parse_status;
+        self.line = checkpoint.line;
+        self.separators = checkpoint.separators;
+        self.consumed = checkpoint.consumed;
+        self.unseparated = checkpoint.unseparated;
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this multi-document is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the part of the input that has not been parsed yet.
+    ///
+    /// This is updated as each value is finished, so after finishing a value, it starts directly after it.
+    /// While a value is unfinished, it starts at the beginning of the value.
+    /// After [`Self::finish`] succeeds, it is empty.
+    pub const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of bytes of the input that have been parsed.
+    ///
+    /// Like [`Self::remaining`], this is updated as values are finished.
+    pub const fn offset(&self) -> usize {
+        self.start.len() - self.remaining.len()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of values that have been yielded from the multi-document.
+    ///
+    /// This is also the index of the next value to be yielded, so after an error in the multi-document, it is the index of the value that was expected.
+    /// After an error in a value, the index of that value is one less.
+    /// Values yielded again because they were not finished are only counted once.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::multi_document::MultiDocument;
+    ///
+    /// let mut document = MultiDocument::new("1 2 [3, x] 4");
+    /// let error = document.finish().expect_err("expected an invalid value");
+    ///
+    /// assert_eq!(document.index() - 1, 2);
+    /// println!("document #{} failed: {error}", document.index() - 1);
+    /// ```
+    pub const fn index(&self) -> usize {
+        self.consumed
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the line number, starting at 1, that parsing has reached.
+    ///
+    /// After [`Self::next`] yields a value, this is the line that the value starts on.
+    /// After an error in the multi-document, this is the line of the error.
+    /// Like [`Self::remaining`], this is updated as values are finished, so while a value is unfinished, it is the line that the value starts on.
+    pub const fn current_line(&self) -> usize {
+        self.line
+    }
+
     fff_impl! {
         type: "multi-document"
         f(&mut Any<'json, '_>) -> Result<_, ParseAnyMultiDocumentError>;
-        accumulator, mut value =>
+        accumulator, index, mut value =>
             f(&mut value),
             f(accumulator, &mut value),
+            f(index, &mut value),
+            f(accumulator, index, &mut value),
             value;
-        ParseAnyMultiDocumentError::MultiDocument
+        ParseAnyMultiDocumentError::MultiDocument;
+        index: "The index counts the documents passed to `f`, starting at 0."
+    }
+}
+
+/// Find the first newline in `json` that is not in a string, returning its byte index.
+///
+/// If a string is not closed before the end of `json`, the first newline is used instead.
+fn find_line_end(json: &str) -> Option<usize> {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, b) in json.bytes().enumerate() {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+        } else {
+            match b {
+                b'"' => in_string = true,
+                b'\n' => return Some(i),
+                _ => {}
+            }
+        }
+    }
+
+    if in_string {
+        json.find('\n')
+    } else {
+        None
     }
 }
 
-debug_impl!("MultiDocument", MultiDocument<'json>, no_parents);
+debug_impl!("MultiDocument", MultiDocument<'json>, no_parents, index: consumed);
 
 #[cfg(test)]
 mod test {
-    use super::{MultiDocument, ParseMultiDocumentError};
+    use super::{MultiDocument, ParseAnyMultiDocumentError, ParseMultiDocumentError, Progress};
+    use crate::any::ValueKind;
+
+    #[test]
+    fn byte_order_mark() {
+        let mut document = MultiDocument::new("\u{feff}\t1 2");
+        let count: Result<_, ParseAnyMultiDocumentError> =
+            document.fold(0, |count, _| Ok(count + 1));
+        assert_eq!(count, Ok(2));
+
+        assert_eq!(
+            MultiDocument::new("1 \u{feff}2").finish(),
+            Err(ParseMultiDocumentError::InvalidElement('\u{feff}').into())
+        );
+    }
+
+    #[test]
+    fn ndjson() {
+        let json = "{\"a\": 1}\n[\n2]\r\n\"b\"\ntrue";
+        let mut document = MultiDocument::ndjson(json);
+        let mut lines = [0; 4];
+
+        for line in &mut lines {
+            let mut value = document
+                .next()
+                .expect("failed to parse document")
+                .expect("got no values in document");
+            value.finish().expect("failed to parse value");
+            *line = document.current_line();
+        }
+        assert_eq!(lines, [1, 3, 4, 5]);
+        assert_eq!(document.next().map(|value| value.is_none()), Ok(true));
+
+        for (json, error) in [
+            ("1 2", ParseMultiDocumentError::ValueOnSameLine { line: 1 }),
+            (
+                "1\n[2]\"3\"",
+                ParseMultiDocumentError::ValueOnSameLine { line: 2 },
+            ),
+            ("1\n\n2", ParseMultiDocumentError::BlankLine { line: 2 }),
+            ("1\n  \n2", ParseMultiDocumentError::BlankLine { line: 2 }),
+            ("\n1", ParseMultiDocumentError::BlankLine { line: 1 }),
+            ("1\n2\n\n", ParseMultiDocumentError::BlankLine { line: 3 }),
+        ] {
+            let mut document = MultiDocument::ndjson(json);
+            assert_eq!(document.finish(), Err(error.into()), "{json:?}");
+        }
+
+        let count = |document: &mut MultiDocument| {
+            let mut values = 0;
+            while let Some(mut value) = document.next()? {
+                value.finish()?;
+                values += 1;
+            }
+            Ok::<_, ParseAnyMultiDocumentError>(values)
+        };
+
+        let mut document = MultiDocument::ndjson("\n1\n\n  \n2\n\n").allow_blank_lines(true);
+        assert_eq!(count(&mut document), Ok(2));
+        assert_eq!(document.current_line(), 7);
+
+        // Other multi-documents do not need newlines
+        let mut document = MultiDocument::new("1 2\n3");
+        assert_eq!(count(&mut document), Ok(3));
+        assert_eq!(document.current_line(), 2);
+    }
+
+    #[test]
+    fn json_seq() {
+        let json = "\u{1e}{\"a\": 1}\n\u{1e}\u{1e} [2]\r\n\u{1e}\n\u{1e}\"b\"\n";
+        let mut document = MultiDocument::json_seq(json);
+        let mut kinds = [None; 3];
+
+        for kind in &mut kinds {
+            let mut value = document
+                .next()
+                .expect("failed to parse document")
+                .expect("got no values in document");
+            *kind = Some(value.kind());
+            value.finish().expect("failed to parse value");
+        }
+        assert_eq!(
+            kinds,
+            [ValueKind::Object, ValueKind::Array, ValueKind::String].map(Some)
+        );
+        assert_eq!(document.next().map(|value| value.is_none()), Ok(true));
+
+        for (json, error) in [
+            (
+                "1\n",
+                ParseMultiDocumentError::MissingRecordSeparator { index: 0 },
+            ),
+            (
+                "\u{1e}1\n2\n",
+                ParseMultiDocumentError::MissingRecordSeparator { index: 1 },
+            ),
+            (
+                "\u{1e}1\u{1e}2\n",
+                ParseMultiDocumentError::TruncatedRecord { index: 0 },
+            ),
+            (
+                "\u{1e}1\n\u{1e}[2, \u{1e}3\n",
+                ParseMultiDocumentError::TruncatedRecord { index: 1 },
+            ),
+        ] {
+            let mut document = MultiDocument::json_seq(json);
+            assert_eq!(document.finish(), Err(error.into()), "{json:?}");
+        }
+
+        // The truncated final record may still be being written
+        let json = "\u{1e}1\n\u{1e}{\"a\": [2, ";
+        let mut document = MultiDocument::json_seq(json);
+        document.finish().expect("failed to parse document");
+        assert_eq!(document.remaining(), "");
+
+        let mut document = MultiDocument::json_seq(json).allow_truncated_records(false);
+        assert_eq!(
+            document.finish(),
+            Err(ParseMultiDocumentError::TruncatedRecord { index: 1 }.into())
+        );
+
+        // Record separators are control characters in other multi-documents
+        let mut document = MultiDocument::new("\u{1e}1\n");
+        assert_eq!(
+            document.finish(),
+            Err(ParseMultiDocumentError::ControlCharacter('\u{1e}').into())
+        );
+    }
+
+    #[test]
+    fn comma_separated() {
+        let mut document = MultiDocument::comma_separated("1,2 ,\n3");
+        let mut numbers = [0; 3];
+
+        for number in &mut numbers {
+            *number = document
+                .next()
+                .expect("failed to parse document")
+                .expect("got no values in document")
+                .read_number()
+                .expect("expected number")
+                .as_u8()
+                .expect("expected u8");
+        }
+        assert_eq!(numbers, [1, 2, 3]);
+        assert_eq!(document.next().map(|value| value.is_none()), Ok(true));
+
+        for (json, error) in [
+            (
+                "1,,2",
+                ParseMultiDocumentError::UnexpectedComma { index: 1 },
+            ),
+            (",1", ParseMultiDocumentError::UnexpectedComma { index: 0 }),
+            ("1,", ParseMultiDocumentError::TrailingComma { index: 1 }),
+            ("[1], ", ParseMultiDocumentError::TrailingComma { index: 1 }),
+            ("1 2", ParseMultiDocumentError::ExpectedComma { index: 1 }),
+        ] {
+            let mut document = MultiDocument::comma_separated(json);
+            assert_eq!(document.finish(), Err(error.into()), "{json:?}");
+        }
+
+        let mut document = MultiDocument::comma_separated(" ");
+        assert_eq!(document.finish(), Ok(()));
+    }
+
+    #[test]
+    fn index() {
+        let mut document = MultiDocument::new("1 [2, 3] x");
+        assert_eq!(document.index(), 0);
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse number");
+        assert_eq!(document.index(), 1);
+
+        // Yielding an unfinished value again does not count it again
+        let mut array = document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .array()
+            .expect("expected array from document");
+        array.next().expect("failed to parse array");
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse array");
+        assert_eq!(document.index(), 2);
+
+        assert_eq!(
+            document.finish(),
+            Err(ParseMultiDocumentError::InvalidElement('x').into())
+        );
+        assert_eq!(document.index(), 2);
+        assert!(format!("{document:?}").contains("index: 2"));
+    }
+
+    #[test]
+    fn skip_to_next_line() {
+        let json = "{\"a\": 1}\n{\"b\": \"x\\ny\", \"c\": ?}\n{\"d\": \"\\\"\"}\nx y\n[4]\n[5";
+        let mut document = MultiDocument::ndjson(json);
+        let mut results = [Ok(()); 6];
+
+        for result in &mut results {
+            *result = match document.next() {
+                Ok(Some(mut value)) => value.finish().map_err(ParseAnyMultiDocumentError::from),
+                Ok(None) => break,
+                Err(error) => Err(error.into()),
+            };
+
+            if result.is_err() {
+                document.skip_to_next_line();
+            }
+        }
+
+        assert_eq!(
+            results.map(|result| result.is_ok()),
+            [true, false, true, false, true, false]
+        );
+        assert_eq!(document.index(), 6);
+        assert_eq!(document.current_line(), 6);
+        assert_eq!(document.remaining(), "");
+    }
+
+    #[test]
+    fn skip_newline_in_string() {
+        // The raw newline in the string does not end the line, so `b` is not parsed as a record
+        let json = "[1, x, \"a\nb\"]\n2\n";
+        let mut document = MultiDocument::ndjson(json);
+        let mut results = [Ok(()); 2];
+        let mut index = 0;
+
+        loop {
+            let result = match document.next() {
+                Ok(Some(mut value)) => value.finish().map_err(ParseAnyMultiDocumentError::from),
+                Ok(None) => break,
+                Err(error) => Err(error.into()),
+            };
+
+            if result.is_err() {
+                document.skip_to_next_line();
+            }
+            results[index] = result;
+            index += 1;
+        }
+
+        assert_eq!(index, 2);
+        assert!(results[0].is_err());
+        assert_eq!(results[1], Ok(()));
+        assert_eq!(document.current_line(), 4);
+    }
+
+    #[test]
+    fn skip_unterminated_string() {
+        // The unterminated string does not hide the records after it
+        let json = "1\n{\"a\": \"x}\n{\"b\": 2}\n{\"c\": 3}\n4\n";
+        let mut document = MultiDocument::ndjson(json);
+        let mut raw = [""; 4];
+        let mut index = 0;
+
+        loop {
+            let start = document.remaining();
+            let result = match document.next() {
+                Ok(Some(mut value)) => value.finish().map_err(ParseAnyMultiDocumentError::from),
+                Ok(None) => break,
+                Err(error) => Err(error.into()),
+            };
+
+            match result {
+                Ok(()) => {
+                    let end = start.len() - document.remaining().len();
+                    raw[index] = start[..end].trim();
+                    index += 1;
+                }
+                Err(_) => document.skip_to_next_line(),
+            }
+        }
+
+        assert_eq!(raw, ["1", "{\"b\": 2}", "{\"c\": 3}", "4"]);
+    }
+
+    #[test]
+    fn require_separation() {
+        for (json, c) in [
+            ("truefalse", Some('f')),
+            ("1\"a\"", Some('"')),
+            ("null[1]", Some('[')),
+            ("-1{}", Some('{')),
+            ("1 2\ttrue\nnull", None),
+            ("\"a\"\"b\"[1]{}2", None),
+        ] {
+            let mut document = MultiDocument::new(json).require_separation(true);
+            let expected = c.map(|c| ParseMultiDocumentError::MissingValueSeparator(c).into());
+            assert_eq!(document.finish().err(), expected, "{json}");
+
+            // Without the option, the values are split
+            assert_eq!(MultiDocument::new(json).finish(), Ok(()), "{json}");
+        }
+
+        let mut document = MultiDocument::comma_separated("1,true").require_separation(true);
+        assert_eq!(document.finish(), Ok(()));
+    }
+
+    #[test]
+    fn for_each_raw() {
+        let json = " 1 \"a\\\"b\"\n[1, {\"c\": null}]true{}\r\n";
+        let mut document = MultiDocument::new(json);
+        let mut records = [""; 5];
+        let mut index = 0;
+
+        document
+            .for_each_raw(|raw| {
+                records[index] = raw;
+                index += 1;
+                Ok::<_, ParseAnyMultiDocumentError>(())
+            })
+            .expect("failed to parse document");
+        assert_eq!(
+            records,
+            ["1", "\"a\\\"b\"", "[1, {\"c\": null}]", "true", "{}"]
+        );
+        assert_eq!(document.index(), 5);
+
+        let mut document = MultiDocument::new("[1] [2, x]");
+        let mut index = 0;
+        let result = document.for_each_raw(|_| {
+            index += 1;
+            Ok::<_, ParseAnyMultiDocumentError>(())
+        });
+        assert!(result.is_err());
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn max_documents() {
+        let limit = ParseMultiDocumentError::TooManyDocuments { limit: 2 };
+
+        let mut document = MultiDocument::new("[1] {} ").with_max_documents(2);
+        assert_eq!(document.finish(), Ok(()));
+
+        let mut document = MultiDocument::new("[1] {} \"a\"").with_max_documents(2);
+        let mut values = 0;
+        let result: Result<(), ParseAnyMultiDocumentError> = document.for_each(|_| {
+            values += 1;
+            Ok(())
+        });
+        assert_eq!(result, Err(limit.into()));
+        assert_eq!(values, 2);
+        assert_eq!(document.index(), 2);
+        // The error is returned again, rather than stopping
+        assert_eq!(document.next().map(|value| value.is_some()), Err(limit));
+
+        let mut document = MultiDocument::new("1").with_max_documents(0);
+        assert_eq!(
+            document.finish(),
+            Err(ParseMultiDocumentError::TooManyDocuments { limit: 0 }.into())
+        );
+    }
+
+    #[test]
+    fn on_progress() {
+        let mut reports = Vec::new();
+        let mut document = MultiDocument::new("[1] 2 {} \"a\"   3 4");
+
+        // Only the view reports progress
+        document.clone().finish().expect("failed to parse document");
+
+        let result: Result<(), ParseAnyMultiDocumentError> = document
+            .on_progress(2, |progress| reports.push(progress))
+            .for_each(|_| Ok(()));
+        assert_eq!(result, Ok(()));
+        assert_eq!(document.index(), 6);
+
+        assert_eq!(
+            reports,
+            [
+                Progress {
+                    values: 2,
+                    bytes_consumed: 4
+                },
+                Progress {
+                    values: 4,
+                    bytes_consumed: 9
+                },
+                Progress {
+                    values: 6,
+                    bytes_consumed: 17
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn covariant() {
+        // The multi-document's lifetime can be shortened, as it only borrows the input
+        fn shorten<'a>(document: MultiDocument<'static>) -> MultiDocument<'a> {
+            document
+        }
+
+        let mut document = shorten(MultiDocument::new("1 2"));
+        assert_eq!(document.finish(), Ok(()));
+    }
+
+    #[test]
+    fn has_more() {
+        let mut document = MultiDocument::new(" 1 [2] \n");
+        let mut values = 0;
+
+        while document.has_more().expect("failed to parse document") {
+            document
+                .next()
+                .expect("failed to parse document")
+                .expect("got no values in document")
+                .finish()
+                .expect("failed to parse value");
+            values += 1;
+        }
+        assert_eq!(values, 2);
+        assert_eq!(document.remaining(), "");
+
+        let mut document = MultiDocument::new("  ");
+        assert_eq!(document.has_more(), Ok(false));
+
+        let mut document = MultiDocument::new("1 x");
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse number");
+        assert_eq!(
+            document.has_more(),
+            Err(ParseMultiDocumentError::InvalidElement('x'))
+        );
+    }
+
+    #[test]
+    fn peek() {
+        let mut document = MultiDocument::new(r#" "a" 1 "#);
+
+        assert_eq!(document.peek(), Ok(Some(ValueKind::String)));
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse string");
+
+        assert_eq!(document.peek(), Ok(Some(ValueKind::Number)));
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse number");
+
+        assert_eq!(document.peek(), Ok(None));
+    }
+
+    #[test]
+    fn rollback() {
+        let mut document = MultiDocument::new("1 2 x");
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse number");
+        let checkpoint = document.checkpoint();
+
+        assert!(document.finish().is_err());
+        document.rollback(checkpoint);
+        assert_eq!(document.remaining(), " 2 x");
+
+        let mut clone = document.clone();
+        assert!(clone.finish().is_err());
+        assert_eq!(document.remaining(), " 2 x");
+    }
+
+    #[test]
+    fn remaining() {
+        let mut document = MultiDocument::new("1 [2] x");
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse number");
+        assert_eq!(document.remaining(), " [2] x");
+        assert_eq!(document.offset(), 1);
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse array");
+        assert_eq!(document.remaining(), " x");
+
+        assert!(document.finish().is_err());
+        assert_eq!(document.remaining(), "x");
+        assert_eq!(document.offset(), 6);
+    }
 
     #[test]
     fn parse_string() {
@@ -156,6 +1392,18 @@ mod test {
         assert!(document.next().expect("failed to parse document").is_none());
     }
 
+    #[test]
+    fn fold_indexed() {
+        let mut document = MultiDocument::new("1 [2] {} 3");
+
+        let indices = document
+            .fold_indexed::<_, _, ParseAnyMultiDocumentError>(0, |indices, index, _| {
+                Ok(indices * 10 + index)
+            })
+            .expect("failed to parse documents");
+        assert_eq!(indices, 123);
+    }
+
     #[test]
     fn empty() {
         let mut document = MultiDocument::new("");
@@ -202,4 +1450,22 @@ mod test {
 
         assert_eq!(error, ParseMultiDocumentError::InvalidElement(invalid));
     }
+
+    #[test]
+    fn control_character() {
+        let mut document = MultiDocument::new("1 \0 2");
+
+        document
+            .next()
+            .expect("failed to parse document")
+            .expect("got no values in document")
+            .finish()
+            .expect("failed to parse number");
+
+        let error = document
+            .next()
+            .expect_err("failed to return error after parsing invalid document");
+
+        assert_eq!(error, ParseMultiDocumentError::ControlCharacter('\0'));
+    }
 }
@@ -0,0 +1,129 @@
+use core::fmt;
+
+use rayon::{iter::ParallelIterator, str::ParallelString};
+
+use crate::document::Document;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The error returned by [`par_for_each_lines`], with the line that it came from.
+pub struct LineError<E> {
+    /// The line that failed, starting at 1.
+    pub line: usize,
+    /// The error returned for the line.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for LineError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (on line {})", self.error, self.line)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for LineError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Runs `f` for each line of newline-delimited JSON (NDJSON), in parallel on the rayon thread pool.
+///
+/// Each line is given to `f` as its own [`Document`], which `f` must parse; blank lines (including whitespace-only lines) are skipped.
+/// Strings cannot contain raw newlines, so each value of valid NDJSON is on one line.
+/// Lines are not processed in order, but if `f` returns an error, the error from the first line that failed is returned.
+///
+/// # Errors
+/// If `f` returns an error, a [`LineError`] with the error and its line is returned.
+///
+/// # Examples
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use zjson::{any::Any, multi_document::par_for_each_lines};
+///
+/// let objects = AtomicUsize::new(0);
+/// let result = par_for_each_lines("{\"a\": 1}\n[2]\n\n{}\n", |mut document| {
+///     if let Some(Any::Object(_)) = document.next()? {
+///         objects.fetch_add(1, Ordering::Relaxed);
+///     }
+///     document.finish()
+/// });
+///
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(objects.into_inner(), 2);
+///
+/// let error = par_for_each_lines("1\n[2, \n3\n", |mut document| document.finish())
+///     .expect_err("expected an unfinished array");
+/// assert_eq!(error.line, 2);
+/// ```
+pub fn par_for_each_lines<F, E>(input: &str, f: F) -> Result<(), LineError<E>>
+where
+    F: Fn(Document<'_>) -> Result<(), E> + Sync,
+    E: Send,
+{
+    let error = input
+        .par_split('\n')
+        .filter(|line| !line.trim_start().is_empty())
+        .find_map_first(|line| f(Document::new(line)).err().map(|error| (line, error)));
+
+    match error {
+        None => Ok(()),
+        Some((line, error)) => {
+            // Only count the lines before an error, as it is the uncommon case
+            let offset = line.as_ptr() as usize - input.as_ptr() as usize;
+            let line = input[..offset].bytes().filter(|&b| b == b'\n').count() + 1;
+            Err(LineError { line, error })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use alloc::string::String;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::{par_for_each_lines, LineError};
+    use crate::document::{ParseAnyDocumentError, ParseDocumentError};
+
+    #[test]
+    fn lines() {
+        let mut json = String::new();
+        for i in 0..1000 {
+            json += if i % 10 == 0 {
+                "  \r\n"
+            } else {
+                "[1, {\"a\": \"b\"}]\r\n"
+            };
+        }
+
+        let count = AtomicUsize::new(0);
+        let result = par_for_each_lines(&json, |mut document| {
+            count.fetch_add(1, Ordering::Relaxed);
+            document.finish()
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(count.into_inner(), 900);
+    }
+
+    #[test]
+    fn first_error() {
+        let mut json = String::new();
+        for i in 1..=1000 {
+            json += if i % 100 == 0 { "1 2\n" } else { "1\n" };
+        }
+
+        let result = par_for_each_lines(&json, |mut document| document.finish());
+        assert_eq!(
+            result,
+            Err(LineError {
+                line: 100,
+                error: ParseAnyDocumentError::Document(ParseDocumentError::UnexpectedCharacter(
+                    '2'
+                ))
+            })
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use core::fmt;
+
+use super::{MultiDocument, ParseAnyMultiDocumentError, ParseMultiDocumentError, Progress};
+use crate::{any::Any, containers::fff_impl};
+
+/// A multi-document that reports its progress, created by [`MultiDocument::on_progress`].
+pub struct OnProgress<'a, 'json, H> {
+    document: &'a mut MultiDocument<'json>,
+    every: usize,
+    hook: H,
+}
+
+impl<'a, 'json, H> OnProgress<'a, 'json, H> {
+    #[inline]
+    pub(super) const fn new(document: &'a mut MultiDocument<'json>, every: usize, hook: H) -> Self {
+        Self {
+            document,
+            every,
+            hook,
+        }
+    }
+}
+
+impl<'a, 'json, H: FnMut(Progress)> OnProgress<'a, 'json, H> {
+    #[allow(clippy::should_implement_trait)]
+    /// Try to get the next value from the multi-document, as in [`MultiDocument::next`], reporting progress if it is a new value.
+    ///
+    /// # Errors
+    /// If parsing fails, this will return a [`ParseMultiDocumentError`].
+    #[inline]
+    pub fn next(&mut self) -> Result<Option<Any<'json, '_>>, ParseMultiDocumentError> {
+        self.document
+            .next_reporting(Some((self.every, &mut self.hook)))
+    }
+
+    /// Finish parsing the multi-document, as in [`MultiDocument::finish`], reporting progress.
+    ///
+    /// # Errors
+    /// If parsing fails in the multi-document or a child, the error is returned as a [`ParseAnyMultiDocumentError`].
+    pub fn finish(&mut self) -> Result<(), ParseAnyMultiDocumentError> {
+        while let Some(mut value) = self.next()? {
+            value.finish()?;
+        }
+
+        Ok(())
+    }
+
+    fff_impl! {
+        type: "multi-document"
+        f(&mut Any<'json, '_>) -> Result<_, ParseAnyMultiDocumentError>;
+        accumulator, index, mut value =>
+            f(&mut value),
+            f(accumulator, &mut value),
+            f(index, &mut value),
+            f(accumulator, index, &mut value),
+            value;
+        ParseAnyMultiDocumentError::MultiDocument;
+        index: "The index counts the documents passed to `f`, starting at 0."
+    }
+}
+
+impl<'a, 'json, H> fmt::Debug for OnProgress<'a, 'json, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnProgress")
+            .field("document", &self.document)
+            .field("every", &self.every)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,300 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::str;
+use std::io::{self, Read};
+
+use super::{MultiDocument, ParseAnyMultiDocumentError};
+use crate::{document::Document, validate::is_value_end};
+
+/// The default number of bytes to read at a time.
+const DEFAULT_BUFFER_SIZE: usize = 8 * 1024;
+
+/// A JSON multi-document read from an [`io::Read`] source, one value at a time.
+///
+/// Values are separated by whitespace, as in [`MultiDocument::new`], so this also reads newline-delimited JSON.
+/// Only the value being parsed is kept in memory: each value is read into an internal buffer, checked, and then yielded as a [`Document`] that borrows the buffer.
+/// The buffer is compacted before the next value is read.
+///
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+///
+/// use zjson::multi_document::ReadMultiDocument;
+///
+/// let input = Cursor::new("{\"a\": 1}\n[2, 3]\n");
+/// let mut documents = ReadMultiDocument::new(input);
+///
+/// let mut raw = Vec::new();
+/// while let Some(document) = documents.next()? {
+///     raw.push(document.remaining().to_owned());
+/// }
+///
+/// assert_eq!(raw, [r#"{"a": 1}"#, "[2, 3]"]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct ReadMultiDocument<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    /// The start of the data in `buffer` that has not been yielded.
+    start: usize,
+    buffer_size: usize,
+    eof: bool,
+    consumed: usize,
+}
+
+impl<R: Read> ReadMultiDocument<R> {
+    #[must_use]
+    #[inline]
+    /// Create a new multi-document that reads from `reader`, 8 KiB at a time.
+    pub const fn new(reader: R) -> Self {
+        Self::with_buffer_size(reader, DEFAULT_BUFFER_SIZE)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new multi-document that reads from `reader`, at least `buffer_size` bytes at a time.
+    ///
+    /// The buffer grows past `buffer_size` to fit values that are larger.
+    ///
+    /// # Panics
+    /// Panics if `buffer_size` is 0.
+    pub const fn with_buffer_size(reader: R, buffer_size: usize) -> Self {
+        assert!(buffer_size != 0, "the buffer size must not be 0");
+
+        Self {
+            reader,
+            buffer: Vec::new(),
+            start: 0,
+            buffer_size,
+            eof: false,
+            consumed: 0,
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    /// Read the next value, returning it as a [`Document`] containing only the value.
+    ///
+    /// The value has already been checked, so parsing the document only fails if it is parsed into an unexpected type.
+    /// Once the reader is exhausted, this will return [`None`].
+    ///
+    /// # Errors
+    /// - If reading fails, the error from the reader is returned.
+    /// - If the input is not valid UTF-8, an error of kind [`io::ErrorKind::InvalidData`] is returned.
+    /// - If parsing fails, an error of kind [`io::ErrorKind::InvalidData`] containing a [`ParseAnyMultiDocumentError`] is returned.
+    ///   The index of the value that failed is returned by [`Self::index`].
+    pub fn next(&mut self) -> io::Result<Option<Document<'_>>> {
+        // Compact the buffer, as the last value is no longer borrowed
+        self.buffer.drain(..self.start);
+        self.start = 0;
+
+        loop {
+            let text = self.text()?;
+            let mut document = MultiDocument::new(text);
+
+            let result = document
+                .peek()
+                .map_err(ParseAnyMultiDocumentError::from)
+                .and_then(|kind| {
+                    let start = document.offset();
+                    if let Some(mut value) = document.next()? {
+                        value.finish()?;
+                    }
+                    Ok(kind.map(|_| (start, document.offset())))
+                });
+
+            let incomplete = match result {
+                // A value at the end of the input may continue, like a number
+                Ok(Some((_, end))) => end == text.len(),
+                Ok(None) => true,
+                Err(ParseAnyMultiDocumentError::Any(error)) => is_value_end(error),
+                Err(ParseAnyMultiDocumentError::MultiDocument(_)) => false,
+            };
+
+            if incomplete && !self.eof {
+                self.fill()?;
+                continue;
+            }
+
+            let Some((start, end)) = result.map_err(|error| {
+                self.consumed += 1;
+                io::Error::from(error)
+            })?
+            else {
+                return Ok(None);
+            };
+
+            self.start = end;
+            self.consumed += 1;
+
+            let value = str::from_utf8(&self.buffer[start..end])
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            return Ok(Some(Document::new(value)));
+        }
+    }
+
+    /// Returns the valid UTF-8 at the start of the buffer.
+    fn text(&self) -> io::Result<&str> {
+        match str::from_utf8(&self.buffer) {
+            Ok(text) => Ok(text),
+            // A character can be split between reads
+            Err(error) if error.error_len().is_none() && !self.eof => {
+                Ok(str::from_utf8(&self.buffer[..error.valid_up_to()])
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?)
+            }
+            Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        }
+    }
+
+    /// Read more data into the buffer.
+    fn fill(&mut self) -> io::Result<()> {
+        // Read more at a time for large values, so that they are not parsed too many times
+        let size = self.buffer_size.max(self.buffer.len());
+        let len = self.buffer.len();
+        self.buffer.resize(len + size, 0);
+
+        let result = loop {
+            match self.reader.read(&mut self.buffer[len..]) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                result => break result,
+            }
+        };
+
+        let read = *result.as_ref().unwrap_or(&0);
+        self.buffer.truncate(len + read);
+        self.eof = result.is_ok() && read == 0;
+
+        result.map(drop)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of values that have been read, including one that failed to parse.
+    ///
+    /// After an error, the index of the value that failed is one less.
+    pub const fn index(&self) -> usize {
+        self.consumed
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the reader, dropping any data that has been read but not yielded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> core::fmt::Debug for ReadMultiDocument<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReadMultiDocument")
+            .field("index", &self.consumed)
+            .field("buffered", &(self.buffer.len() - self.start))
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+    use std::io::{self, Cursor};
+
+    use super::ReadMultiDocument;
+    use crate::{
+        any::ParseAnyError,
+        array::ParseArrayError,
+        multi_document::{ParseAnyMultiDocumentError, ParseMultiDocumentError},
+    };
+
+    const JSON: &str = "{\"a\": \"é😀\"}\n12345 true\n[1, [2, 3], \"x\"]\"y\"\r\n-0.5e10";
+
+    /// Read all of the values in `json`, `buffer_size` bytes at a time.
+    fn read_all(json: &str, buffer_size: usize) -> io::Result<Vec<String>> {
+        let mut documents = ReadMultiDocument::with_buffer_size(Cursor::new(json), buffer_size);
+        let mut values = Vec::new();
+
+        while let Some(mut document) = documents.next()? {
+            values.push(document.remaining().into());
+            document.finish()?;
+        }
+
+        Ok(values)
+    }
+
+    #[test]
+    fn refill() {
+        let expected = [
+            "{\"a\": \"é😀\"}",
+            "12345",
+            "true",
+            "[1, [2, 3], \"x\"]",
+            "\"y\"",
+            "-0.5e10",
+        ];
+
+        for buffer_size in [1, 2, 3, 5, 64] {
+            let values = read_all(JSON, buffer_size).expect("failed to read values");
+            assert_eq!(values, expected, "{buffer_size}");
+        }
+
+        assert!(read_all(" \n ", 1)
+            .expect("failed to read values")
+            .is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let error = |json, buffer_size| {
+            let mut documents = ReadMultiDocument::with_buffer_size(Cursor::new(json), buffer_size);
+            loop {
+                match documents.next() {
+                    Ok(Some(_)) => {}
+                    Ok(None) => panic!("expected an error in {json:?}"),
+                    Err(error) => {
+                        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+                        return (
+                            error.downcast::<ParseAnyMultiDocumentError>().ok(),
+                            documents.index(),
+                        );
+                    }
+                }
+            }
+        };
+
+        for buffer_size in [1, 4, 64] {
+            assert_eq!(
+                error("1 [2, x] 3", buffer_size),
+                (
+                    Some(
+                        ParseAnyError::Array(ParseArrayError::InvalidElement {
+                            c: 'x',
+                            or_end: false,
+                            index: 1
+                        })
+                        .into()
+                    ),
+                    2
+                )
+            );
+            assert_eq!(
+                error("[1] ?", buffer_size),
+                (Some(ParseMultiDocumentError::InvalidElement('?').into()), 2)
+            );
+            assert_eq!(
+                error("[1] [2, ", buffer_size),
+                (
+                    Some(ParseAnyError::Array(ParseArrayError::UnexpectedEnd { index: 1 }).into()),
+                    2
+                )
+            );
+        }
+
+        // Invalid UTF-8 is not a parsing error
+        let mut documents = ReadMultiDocument::with_buffer_size(Cursor::new(b"[\"\xff\"]"), 2);
+        let error = documents.next().expect_err("expected invalid UTF-8");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.downcast::<ParseAnyMultiDocumentError>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt;
 
+use crate::debug::DisplayChar;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The error returned when parsing a [`Number`](super::Number) fails.
 pub enum ParseNumberError {
@@ -48,19 +50,22 @@ impl fmt::Display for ParseNumberError {
             Self::ExpectedMinusOrDigit(c) => {
                 write!(
                     f,
-                    "Invalid character ({c}) in JSON number (expected minus sign or digit)!"
+                    "Invalid character ({c}) in JSON number (expected minus sign or digit)!",
+                    c = DisplayChar(*c)
                 )
             }
             Self::ExpectedDigit(c) => {
                 write!(
                     f,
-                    "Invalid character ({c}) in JSON number (expected digit)!"
+                    "Invalid character ({c}) in JSON number (expected digit)!",
+                    c = DisplayChar(*c)
                 )
             }
             Self::ExpectedSignOrDigit(c) => {
                 write!(
                     f,
-                    "Invalid character ({c}) in JSON number (expected sign or digit)!"
+                    "Invalid character ({c}) in JSON number (expected sign or digit)!",
+                    c = DisplayChar(*c)
                 )
             }
         }
@@ -68,4 +73,25 @@ impl fmt::Display for ParseNumberError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseNumberError {}
+impl core::error::Error for ParseNumberError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseNumberError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseNumberError::ExpectedMinusOrDigit('\0').to_string(),
+            "Invalid character (U+0000) in JSON number (expected minus sign or digit)!"
+        );
+        assert_eq!(
+            ParseNumberError::ExpectedDigit('\u{85}').to_string(),
+            "Invalid character (U+0085) in JSON number (expected digit)!"
+        );
+        assert_eq!(
+            ParseNumberError::ExpectedSignOrDigit('x').to_string(),
+            "Invalid character (x) in JSON number (expected sign or digit)!"
+        );
+    }
+}
@@ -1,28 +1,39 @@
-use crate::{debug::debug_impl, status::Status, Parent};
+use crate::{containers::span, debug::debug_impl, status::Status, Parent, ParseOptions};
 
 mod error;
 mod machine;
 mod parsed;
 pub use error::ParseNumberError;
 use machine::Machine;
-pub use parsed::ParsedNumber;
+pub use parsed::{FromNumber, Integer, ParsedNumber};
 
 /// A JSON number.
 pub struct Number<'json, 'p> {
     parent: &'p mut dyn Parent<'json>,
     remaining: &'json str,
+    end: Option<&'json str>,
+    options: ParseOptions,
 }
 
 impl<'json, 'p> Number<'json, 'p> {
-    pub(crate) fn new(parent: &'p mut dyn Parent<'json>, remaining: &'json str) -> Self {
-        Self { parent, remaining }
+    pub(crate) fn new(
+        parent: &'p mut dyn Parent<'json>,
+        remaining: &'json str,
+        options: ParseOptions,
+    ) -> Self {
+        Self {
+            parent,
+            remaining,
+            end: None,
+            options,
+        }
     }
 
     /// Try to parse the number.
     ///
     /// # Errors
     /// If parsing fails, this will return a [`ParseNumberError`].
-    pub fn get(&mut self) -> Result<ParsedNumber, ParseNumberError> {
+    pub fn get(&mut self) -> Result<ParsedNumber<'json>, ParseNumberError> {
         let mut machine = Machine::Start { signed: false };
         let mut end = self.remaining.len();
 
@@ -43,6 +54,7 @@ impl<'json, 'p> Number<'json, 'p> {
 
         let remaining = &self.remaining[end..];
         self.parent.set_remaining(remaining);
+        self.end = Some(remaining);
 
         let number_string = &self.remaining[..end];
         Ok(ParsedNumber::new(number_string))
@@ -58,6 +70,32 @@ impl<'json, 'p> Number<'json, 'p> {
     pub fn finish(&mut self) -> Result<(), ParseNumberError> {
         self.get().map(drop)
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the number has been successfully parsed, so the parent can continue.
+    pub const fn is_finished(&self) -> bool {
+        self.end.is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this number is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) fn span(&self) -> Option<&'json str> {
+        self.end.map(|end| span(self.remaining, end))
+    }
 }
 
 debug_impl!("Number", Number<'json, 'p>);
@@ -45,6 +45,7 @@ impl<'json> ParsedNumber<'json> {
     as_impl!(as_i64, i64);
     as_impl!(as_i128, i128);
 
+    #[cfg(feature = "float")]
     #[must_use]
     /// Get the number as a [`prim@f32`].
     pub fn as_f32(self) -> f32 {
@@ -53,6 +54,7 @@ impl<'json> ParsedNumber<'json> {
             .expect("failed to parse a number as an f32")
     }
 
+    #[cfg(feature = "float")]
     #[must_use]
     /// Get the number as a [`prim@f64`].
     pub fn as_f64(self) -> f64 {
@@ -110,6 +112,7 @@ eq_ord_impl!(i32, Self::as_i32);
 eq_ord_impl!(i64, Self::as_i64);
 eq_ord_impl!(i128, Self::as_i128);
 
+#[cfg(feature = "float")]
 impl PartialEq<f32> for ParsedNumber<'_> {
     #[inline]
     fn eq(&self, other: &f32) -> bool {
@@ -117,6 +120,7 @@ impl PartialEq<f32> for ParsedNumber<'_> {
     }
 }
 
+#[cfg(feature = "float")]
 impl PartialEq<f64> for ParsedNumber<'_> {
     #[inline]
     fn eq(&self, other: &f64) -> bool {
@@ -124,6 +128,62 @@ impl PartialEq<f64> for ParsedNumber<'_> {
     }
 }
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A numeric primitive that a [`ParsedNumber`] can be converted into.
+///
+/// This trait is sealed, it is implemented for all integer and floating point primitives.
+pub trait FromNumber: sealed::Sealed + Sized {
+    /// The name of the type, used in errors.
+    const NAME: &'static str;
+
+    /// Try to represent `number` as this type.
+    ///
+    /// This returns [`None`] if the number is out of range, or if it is not an integer and this is an integer type.
+    fn from_number(number: ParsedNumber<'_>) -> Option<Self>;
+}
+
+/// An integer primitive that a [`ParsedNumber`] can be converted into.
+///
+/// This trait is sealed, it is implemented for all integer primitives.
+pub trait Integer: FromNumber {}
+
+macro_rules! from_number_impl {
+    ( $t:ty, $as:expr $(, $integer:ident )? ) => {
+        impl sealed::Sealed for $t {}
+
+        impl FromNumber for $t {
+            const NAME: &'static str = stringify!($t);
+
+            #[inline]
+            fn from_number(number: ParsedNumber<'_>) -> Option<Self> {
+                $as(number)
+            }
+        }
+
+        $( impl $integer for $t {} )?
+    };
+}
+
+from_number_impl!(u8, ParsedNumber::as_u8, Integer);
+from_number_impl!(u16, ParsedNumber::as_u16, Integer);
+from_number_impl!(u32, ParsedNumber::as_u32, Integer);
+from_number_impl!(u64, ParsedNumber::as_u64, Integer);
+from_number_impl!(u128, ParsedNumber::as_u128, Integer);
+
+from_number_impl!(i8, ParsedNumber::as_i8, Integer);
+from_number_impl!(i16, ParsedNumber::as_i16, Integer);
+from_number_impl!(i32, ParsedNumber::as_i32, Integer);
+from_number_impl!(i64, ParsedNumber::as_i64, Integer);
+from_number_impl!(i128, ParsedNumber::as_i128, Integer);
+
+#[cfg(feature = "float")]
+from_number_impl!(f32, |number: ParsedNumber| Some(number.as_f32()));
+#[cfg(feature = "float")]
+from_number_impl!(f64, |number: ParsedNumber| Some(number.as_f64()));
+
 #[cfg(test)]
 mod test {
     use super::ParsedNumber;
@@ -152,7 +212,9 @@ mod test {
         test_eq!(0_i64);
         test_eq!(0_i128);
 
+        #[cfg(feature = "float")]
         test_eq!(0_f32);
+        #[cfg(feature = "float")]
         test_eq!(0_f64);
     }
 
@@ -170,7 +232,9 @@ mod test {
         test_eq!(53_i64);
         test_eq!(53_i128);
 
+        #[cfg(feature = "float")]
         test_eq!(53_f32);
+        #[cfg(feature = "float")]
         test_eq!(53_f64);
     }
 
@@ -182,31 +246,45 @@ mod test {
         test_eq!(-53_i64);
         test_eq!(-53_i128);
 
+        #[cfg(feature = "float")]
         test_eq!(-53_f32);
+        #[cfg(feature = "float")]
         test_eq!(-53_f64);
     }
 
     #[test]
     fn partial_eq_impl_decimal() {
+        #[cfg(feature = "float")]
         test_eq!(53.19_f32);
+        #[cfg(feature = "float")]
         test_eq!(53.19_f64);
 
+        #[cfg(feature = "float")]
         test_eq!(-53.19_f32);
+        #[cfg(feature = "float")]
         test_eq!(-53.19_f64);
     }
 
     #[test]
     fn partial_eq_impl_exponent() {
+        #[cfg(feature = "float")]
         test_eq!(53.19e5_f32);
+        #[cfg(feature = "float")]
         test_eq!(53.19e5_f64);
 
+        #[cfg(feature = "float")]
         test_eq!(-53.19e5_f32);
+        #[cfg(feature = "float")]
         test_eq!(-53.19e5_f64);
 
+        #[cfg(feature = "float")]
         test_eq!(53.19e-5_f32);
+        #[cfg(feature = "float")]
         test_eq!(53.19e-5_f64);
 
+        #[cfg(feature = "float")]
         test_eq!(-53.19e-5_f32);
+        #[cfg(feature = "float")]
         test_eq!(-53.19e-5_f64);
     }
 }
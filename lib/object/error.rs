@@ -1,6 +1,6 @@
 use core::fmt;
 
-use crate::string;
+use crate::{any::ParseAnyError, debug::DisplayChar, string};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The error returned when parsing an [`Object`](super::Object) fails.
@@ -22,7 +22,7 @@ pub enum ParseObjectError {
     InvalidElement(char),
     /// A character that was not a comma or a terminator (`}`) was found directly after a value.
     ExpectedCommaOrEnd(char),
-    /// The object was terminated directly after a comma.
+    /// The object was terminated directly after a comma, and trailing commas are not allowed by the [`ParseOptions`](crate::ParseOptions).
     TrailingComma,
 }
 
@@ -32,26 +32,31 @@ impl fmt::Display for ParseObjectError {
             Self::UnexpectedEnd => write!(f, "Unexpected end of JSON object!"),
             Self::ExpectedName { c, or_end: true } => write!(
                 f,
-                "Invalid character ({c}) in JSON object (expected a string name or an end, '}}')!"
+                "Invalid character ({c}) in JSON object (expected a string name or an end, '}}')!",
+                c = DisplayChar(*c)
             ),
             Self::ExpectedName { c, or_end: false } => write!(
                 f,
-                "Invalid character ({c}) in JSON object (expected a string name)!"
+                "Invalid character ({c}) in JSON object (expected a string name)!",
+                c = DisplayChar(*c)
             ),
             Self::InvalidName(err) => err.fmt(f),
             Self::ExpectedColon(c) => {
                 write!(
                     f,
-                    "Invalid character ({c}) in JSON object (expected a colon, ':')!"
+                    "Invalid character ({c}) in JSON object (expected a colon, ':')!",
+                    c = DisplayChar(*c)
                 )
             }
             Self::InvalidElement(c) => write!(
                 f,
-                "Invalid character ({c}) in JSON object (expected a value)!"
+                "Invalid character ({c}) in JSON object (expected a value)!",
+                c = DisplayChar(*c)
             ),
             Self::ExpectedCommaOrEnd(c) => write!(
                 f,
-                "Invalid character ({c}) in JSON object (expected a comma or an end, '}}')!"
+                "Invalid character ({c}) in JSON object (expected a comma or an end, '}}')!",
+                c = DisplayChar(*c)
             ),
             Self::TrailingComma => write!(f, "Trailing comma in JSON object!"),
         }
@@ -59,9 +64,9 @@ impl fmt::Display for ParseObjectError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseObjectError {
+impl core::error::Error for ParseObjectError {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         if let Self::InvalidName(err) = self {
             Some(err)
         } else {
@@ -69,3 +74,74 @@ impl std::error::Error for ParseObjectError {
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The error returned by [`Object::expect_only`](super::Object::expect_only).
+pub enum ExpectOnlyError<'json> {
+    /// A key that was not in the allowed set was found.
+    UnknownKey(string::ParsedString<'json>),
+    /// Parsing the object or a value failed.
+    Any(ParseAnyError),
+}
+
+impl fmt::Display for ExpectOnlyError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownKey(key) => {
+                write!(f, "Unknown key ({:?}) in JSON object!", key.unescaped())
+            }
+            Self::Any(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for ExpectOnlyError<'_> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        if let Self::Any(err) = self {
+            Some(err)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<ParseAnyError> for ExpectOnlyError<'_> {
+    #[inline]
+    fn from(value: ParseAnyError) -> Self {
+        Self::Any(value)
+    }
+}
+
+impl From<ParseObjectError> for ExpectOnlyError<'_> {
+    #[inline]
+    fn from(value: ParseObjectError) -> Self {
+        Self::Any(value.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParseObjectError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseObjectError::ExpectedName {
+                c: '\0',
+                or_end: true
+            }
+            .to_string(),
+            "Invalid character (U+0000) in JSON object (expected a string name or an end, '}')!"
+        );
+        assert_eq!(
+            ParseObjectError::ExpectedColon('\u{7f}').to_string(),
+            "Invalid character (U+007F) in JSON object (expected a colon, ':')!"
+        );
+        assert_eq!(
+            ParseObjectError::InvalidElement('j').to_string(),
+            "Invalid character (j) in JSON object (expected a value)!"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     containers::{ParsePrompt, ParseStatus},
-    string,
+    string, ParseOptions,
 };
 
 use super::ParseObjectError;
@@ -22,7 +22,7 @@ pub enum Machine<'json> {
 }
 
 impl<'json> Machine<'json> {
-    pub fn apply(self, c: char) -> Result<Self, ParseObjectError> {
+    pub fn apply(self, c: char, options: ParseOptions) -> Result<Self, ParseObjectError> {
         match self {
             Self::In { postcomma } => match c {
                 w if w.is_whitespace() => Ok(self),
@@ -30,7 +30,7 @@ impl<'json> Machine<'json> {
                 '"' => Ok(Self::Name(None)),
 
                 '}' => {
-                    if postcomma {
+                    if postcomma && !options.trailing_commas_allowed() {
                         Err(ParseObjectError::TrailingComma)
                     } else {
                         Ok(Self::End)
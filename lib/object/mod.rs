@@ -1,19 +1,41 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use crate::{
-    any::{Any, ParseAnyError},
-    containers::{fff_impl, ParseStatus},
+    any::{Any, ParseAnyError, ValueKind},
+    containers::{fff_impl, next_char, span, ParsePrompt, ParseStatus},
     debug::debug_impl,
     string::{self, ParsedString, String},
-    Parent,
+    Parent, ParseOptions,
 };
 
 mod error;
 mod machine;
-pub use error::ParseObjectError;
-use machine::Machine;
+pub use error::{ExpectOnlyError, ParseObjectError};
+pub(crate) use machine::Machine;
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The outcome of [`Object::read_string_field`].
+pub struct FieldOutcome {
+    /// If the key was found.
+    pub found: bool,
+    /// If the value contained escape sequences.
+    pub had_escapes: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A saved parsing position in an [`Object`], created by [`Object::checkpoint`].
+pub struct ObjectCheckpoint<'json> {
+    remaining: &'json str,
+    machine: Machine<'json>,
+}
 
 /// A JSON object.
 pub struct Object<'json, 'p> {
     parent: &'p mut dyn Parent<'json>,
+    options: ParseOptions,
+    start: &'json str,
     remaining: &'json str,
     machine: Machine<'json>,
 }
@@ -35,10 +57,17 @@ impl<'json, 'p> Parent<'json> for Object<'json, 'p> {
 }
 
 impl<'json, 'p> Object<'json, 'p> {
-    pub(crate) fn new(parent: &'p mut dyn Parent<'json>, remaining: &'json str) -> Self {
+    pub(crate) fn new(
+        parent: &'p mut dyn Parent<'json>,
+        start: &'json str,
+        options: ParseOptions,
+    ) -> Self {
         Self {
             parent,
-            remaining,
+            options,
+            start,
+            // Skip the opening brace
+            remaining: &start[1..],
             machine: Machine::In { postcomma: false },
         }
     }
@@ -54,8 +83,40 @@ impl<'json, 'p> Object<'json, 'p> {
     pub fn next(
         &mut self,
     ) -> Result<Option<(string::ParsedString<'json>, Any<'json, '_>)>, ParseObjectError> {
+        let Some((name, prompt)) = self.advance()? else {
+            self.parent.set_remaining(self.remaining);
+            return Ok(None);
+        };
+
+        self.machine = Machine::Element {
+            name,
+            element: prompt.into(),
+        };
+
+        let (remaining, options) = (self.remaining, self.options);
+        Ok(Some((name, prompt.create(self, remaining, options))))
+    }
+
+    /// Returns the kind of the next value in the object, without yielding it.
+    ///
+    /// The key is parsed, so [`Self::next`] continues from the value.
+    /// Once the object is exhausted, this will return [`None`].
+    ///
+    /// # Errors
+    /// If parsing the object fails, this will return a [`ParseObjectError`], the same as [`Self::next`] would.
+    #[inline]
+    pub fn peek(&mut self) -> Result<Option<ValueKind>, ParseObjectError> {
+        Ok(self.advance()?.map(|(_, prompt)| prompt.kind()))
+    }
+
+    /// Parse up to the start of the next value, returning its name and how it should be parsed, without starting it.
+    ///
+    /// Returns [`None`] if the object has been terminated.
+    fn advance(
+        &mut self,
+    ) -> Result<Option<(string::ParsedString<'json>, ParsePrompt)>, ParseObjectError> {
         loop {
-            let remaining = self.remaining;
+            let (remaining, options) = (self.remaining, self.options);
 
             match self.machine {
                 Machine::In { .. }
@@ -67,7 +128,7 @@ impl<'json, 'p> Object<'json, 'p> {
                 } => {}
 
                 Machine::Name(None) => {
-                    let mut name = String::<'json, '_>::new(self, remaining);
+                    let mut name = String::<'json, '_>::new(self, remaining, options);
                     let name = name.get().map_err(ParseObjectError::InvalidName)?;
                     self.machine = Machine::Name(Some(name));
                 }
@@ -75,32 +136,29 @@ impl<'json, 'p> Object<'json, 'p> {
                 Machine::Element {
                     name,
                     element: ParseStatus::Prompted(prompt),
-                } => {
-                    return Ok(Some((name, prompt.create(self, remaining))));
-                }
+                } => return Ok(Some((name, prompt))),
 
-                Machine::End => {
-                    self.parent.set_remaining(remaining);
-                    return Ok(None);
-                }
+                Machine::End => return Ok(None),
             }
 
-            let c = self
-                .remaining
-                .chars()
-                .next()
-                .ok_or(ParseObjectError::UnexpectedEnd)?;
-            self.machine = self.machine.apply(c)?;
-
-            // If currently parsing a number or literal, don't remove `c` from `self.remaining`
-            if let Machine::Element {
-                element: ParseStatus::Prompted(prompt),
-                ..
-            } = self.machine
-            {
-                if prompt.keep_first() {
+            // Whitespace does not change the state, so it is skipped
+            let (remaining, c) = next_char(self.remaining);
+            self.remaining = remaining;
+            let c = c.ok_or(ParseObjectError::UnexpectedEnd)?;
+
+            // Names and values are created from their first character, so don't remove `c` from `self.remaining`
+            match self.machine.apply(c, self.options)? {
+                Machine::Element {
+                    name,
+                    element: ParseStatus::Prompted(prompt),
+                } => return Ok(Some((name, prompt))),
+
+                machine @ Machine::Name(None) => {
+                    self.machine = machine;
                     continue;
                 }
+
+                machine => self.machine = machine,
             }
 
             self.remaining = &self.remaining[c.len_utf8()..];
@@ -119,15 +177,187 @@ impl<'json, 'p> Object<'json, 'p> {
         Ok(())
     }
 
+    #[must_use]
+    #[inline]
+    /// Save the current parsing position, so that it can be returned to with [`Self::rollback`].
+    pub const fn checkpoint(&self) -> ObjectCheckpoint<'json> {
+        ObjectCheckpoint {
+            remaining: self.remaining,
+            machine: self.machine,
+        }
+    }
+
+    #[inline]
+    /// Return to a parsing position saved with [`Self::checkpoint`], so that pairs can be parsed again.
+    ///
+    /// `checkpoint` must have been created by this object.
+    ///
+    /// Only this object is rolled back.
+    /// Once the object is exhausted, its parent continues from after it, so rolling back an exhausted object does not rewind the parent; it is the caller's responsibility to only continue parsing the parent once this object has been exhausted again.
+    pub const fn rollback(&mut self, checkpoint: ObjectCheckpoint<'json>) {
+        self.remaining = checkpoint.remaining;
+        self.machine = checkpoint.machine;
+    }
+
+    /// Runs `f` for each key, value pair in the object, erroring if a key is not in `allowed`.
+    ///
+    /// Keys are compared with escape sequences evaluated.
+    /// `f` is passed the index of the key in `allowed`.
+    ///
+    /// [`Any::finish`] is automatically called on all values, so it is not needed in `f`.
+    ///
+    /// # Errors
+    /// - If a key is not in `allowed`, this will return [`ExpectOnlyError::UnknownKey`].
+    /// - If parsing fails in this object or if `f` returns an error, this will return [`ExpectOnlyError::Any`].
+    pub fn expect_only<F>(
+        &mut self,
+        allowed: &[&str],
+        mut f: F,
+    ) -> Result<(), ExpectOnlyError<'json>>
+    where
+        F: FnMut(usize, &mut Any<'json, '_>) -> Result<(), ParseAnyError>,
+    {
+        while let Some((key, mut value)) = self.next()? {
+            let Some(index) = allowed.iter().position(|allowed| key == *allowed) else {
+                return Err(ExpectOnlyError::UnknownKey(key));
+            };
+
+            f(index, &mut value)?;
+            value.finish()?;
+        }
+
+        Ok(())
+    }
+
+    /// Finds the first value with a name that matches `key`, ignoring ASCII case.
+    ///
+    /// Names are compared with escape sequences evaluated, so `"\u0041"` matches `"a"`.
+    /// Only ASCII letters are case-folded; Unicode case folding is not supported, so `"Ü"` does not match `"ü"`.
+    ///
+    /// This only searches forwards; pairs that have already been parsed are not searched.
+    /// Pairs before the matching one are finished.
+    /// If the key is not found, the object will be finished and [`None`] will be returned.
+    ///
+    /// # Errors
+    /// If parsing fails in this object or a child, this will return a [`ParseAnyError`].
+    pub fn get_ignore_ascii_case(
+        &mut self,
+        key: &str,
+    ) -> Result<Option<Any<'json, '_>>, ParseAnyError> {
+        loop {
+            let Some((name, mut value)) = self.next()? else {
+                return Ok(None);
+            };
+
+            let matches = name
+                .chars()
+                .map(|c| c.to_ascii_lowercase())
+                .eq(key.chars().map(|c| c.to_ascii_lowercase()));
+
+            if matches {
+                break;
+            }
+
+            value.finish()?;
+        }
+
+        // The value has not been started, so the same pair is yielded again
+        Ok(self.next()?.map(|(_, value)| value))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Finds the first value with the name `key` and reads it into `buf`, with escape sequences evaluated.
+    ///
+    /// `buf` is cleared first, so it can be reused for each object, avoiding reallocation.
+    /// This only searches forwards; pairs that have already been parsed are not searched.
+    /// Pairs before the matching one are finished.
+    /// If `finish_rest` is [`true`], the pairs after it are also finished, otherwise, they are left to be parsed.
+    ///
+    /// If the key is not found, the object will be finished and the returned [`FieldOutcome::found`] will be [`false`].
+    ///
+    /// # Errors
+    /// - If parsing fails in this object or a child, this will return a [`ParseAnyError`].
+    /// - If the value is not a string, this will return [`ParseAnyError::TypeMismatch`].
+    pub fn read_string_field(
+        &mut self,
+        key: &str,
+        buf: &mut alloc::string::String,
+        finish_rest: bool,
+    ) -> Result<FieldOutcome, ParseAnyError> {
+        buf.clear();
+
+        let mut outcome = FieldOutcome::default();
+
+        while let Some((name, mut value)) = self.next()? {
+            if name != key {
+                value.finish()?;
+                continue;
+            }
+
+            let parsed = value.read_string()?;
+
+            let raw = parsed.unescaped();
+            outcome = FieldOutcome {
+                found: true,
+                had_escapes: parsed.has_escapes(),
+            };
+
+            if outcome.had_escapes {
+                buf.reserve(raw.len());
+                buf.extend(parsed.chars());
+            } else {
+                buf.push_str(raw);
+            }
+
+            break;
+        }
+
+        if finish_rest && outcome.found {
+            self.finish()?;
+        }
+
+        Ok(outcome)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the object has been fully parsed, so the parent can continue.
+    pub const fn is_exhausted(&self) -> bool {
+        matches!(self.machine, Machine::End)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this object is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) fn span(&self) -> Option<&'json str> {
+        self.is_exhausted()
+            .then(|| span(self.start, self.remaining))
+    }
+
     fff_impl! {
         type: "object"
         value: "key, value pair"
         f(ParsedString<'json>, &mut Any<'json, '_>) -> Result<_, ParseAnyError>;
-        accumulator, (key, mut value) =>
+        accumulator, index, (key, mut value) =>
             f(key, &mut value),
             f(accumulator, key, &mut value),
+            f(index, key, &mut value),
+            f(accumulator, index, key, &mut value),
             value;
-        ParseAnyError::Object
+        ParseAnyError::Object;
+        index: "The index counts the pairs passed to `f`, starting at 0."
     }
 }
 
@@ -135,13 +365,16 @@ debug_impl!("Object", Object<'json, 'p>);
 
 #[cfg(test)]
 mod test {
-    use crate::test_parent::TestParent;
+    use crate::{any::ParseAnyError, test_parent::TestParent};
 
-    use super::ParseObjectError;
+    #[cfg(feature = "alloc")]
+    use super::FieldOutcome;
+    use super::{ExpectOnlyError, ParseObjectError};
+    use crate::any::ValueKind;
 
     #[test]
     fn empty() {
-        let mut parent = TestParent::new("}");
+        let mut parent = TestParent::new("{}");
         let mut object = parent.object();
 
         let value = object.next().expect("failed to parse object");
@@ -154,7 +387,7 @@ mod test {
     fn string() {
         let expected_key = "key1";
         let expected_value = "value1";
-        let json = format!("\"{expected_key}\": \"{expected_value}\"}}");
+        let json = format!("{{\"{expected_key}\": \"{expected_value}\"}}");
 
         let mut parent = TestParent::new(&json);
         let mut object = parent.object();
@@ -179,10 +412,99 @@ mod test {
         assert!(parent.remaining.is_empty());
     }
 
+    #[test]
+    fn peek() {
+        let mut parent = TestParent::new(r#"{"a": [1], "b" :true } "#);
+        let mut object = parent.object();
+
+        assert_eq!(object.peek(), Ok(Some(ValueKind::Array)));
+        let (key, mut value) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("expected a pair");
+        assert_eq!(key, "a");
+        value.finish().expect("failed to parse value");
+
+        assert_eq!(object.peek(), Ok(Some(ValueKind::Literal)));
+        assert_eq!(object.peek(), Ok(Some(ValueKind::Literal)));
+        let (key, value) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("expected a pair");
+        assert_eq!(key, "b");
+        assert_eq!(value.kind(), ValueKind::Literal);
+
+        object.finish().expect("failed to parse object");
+        assert_eq!(object.peek(), Ok(None));
+        assert_eq!(parent.remaining, " ");
+
+        let mut parent = TestParent::new(r#"{"a" 1}"#);
+        assert_eq!(
+            parent.object().peek(),
+            Err(ParseObjectError::ExpectedColon('1'))
+        );
+    }
+
+    #[test]
+    fn whitespace() {
+        let mut parent = TestParent::new("{ \u{3000}\"a\"\t:\n 1\u{a0}, \"b\" : 2 } ");
+        let mut object = parent.object();
+        assert_eq!(object.finish(), Ok(()));
+        assert_eq!(parent.remaining, " ");
+
+        for (json, error) in [
+            (
+                "{  \u{3000} é}",
+                ParseObjectError::ExpectedName {
+                    c: 'é',
+                    or_end: true,
+                },
+            ),
+            (
+                "{\"a\"  \u{ff1a} 1}",
+                ParseObjectError::ExpectedColon('\u{ff1a}'),
+            ),
+            ("{\"a\": 1 \n ", ParseObjectError::UnexpectedEnd),
+        ] {
+            let mut parent = TestParent::new(json);
+            assert_eq!(
+                parent.object().finish(),
+                Err(ParseAnyError::Object(error)),
+                "{json:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rollback() {
+        let mut parent = TestParent::new(r#"{"a": "b", "c": 1} "#);
+        let mut object = parent.object();
+
+        let checkpoint = object.checkpoint();
+        let (key, mut value) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("expected a pair");
+        assert_eq!(key, "a");
+        assert_eq!(value.read_string().expect("failed to parse string"), "b");
+
+        // Parse the same pair again
+        object.rollback(checkpoint);
+        let (key, mut value) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("expected a pair");
+        assert_eq!(key, "a");
+        assert_eq!(value.read_string().expect("failed to parse string"), "b");
+
+        object.finish().expect("failed to parse object");
+        assert_eq!(parent.remaining, " ");
+    }
+
     #[test]
     fn invalid() {
         let invalid = 'j';
-        let json = invalid.to_string();
+        let json = format!("{{{invalid}");
 
         let mut parent = TestParent::new(&json);
         let mut object = parent.object();
@@ -208,7 +530,7 @@ mod test {
         let expected_value = "value1";
         let invalid = 'j';
 
-        let json = format!("\"{expected_key}\": \"{expected_value}\", {invalid}");
+        let json = format!("{{\"{expected_key}\": \"{expected_value}\", {invalid}");
 
         let mut parent = TestParent::new(&json);
         let mut object = parent.object();
@@ -241,4 +563,265 @@ mod test {
 
         assert_eq!(parent.remaining, json);
     }
+
+    #[test]
+    fn expect_only() {
+        let json = r#"{"a": 1, "b": [2], "c": {"d": 3}}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let mut seen = [false; 3];
+        object
+            .expect_only(&["c", "b", "a"], |i, _| {
+                seen[i] = true;
+                Ok(())
+            })
+            .expect("failed to parse object");
+
+        assert_eq!(seen, [true, true, true]);
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn expect_only_unknown_first() {
+        let json = r#"{"x": 1, "a": 2}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let error = object
+            .expect_only(&["a"], |_, _| Ok(()))
+            .expect_err("failed to return error for unknown key");
+
+        let ExpectOnlyError::UnknownKey(key) = error else {
+            panic!("expected an unknown key error, got {error:?}");
+        };
+        assert_eq!(key, "x");
+    }
+
+    #[test]
+    fn expect_only_unknown_last() {
+        let json = r#"{"a": 1, "b": 2, "x\ty": 3}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let mut count = 0;
+        let error = object
+            .expect_only(&["a", "b"], |_, _| {
+                count += 1;
+                Ok(())
+            })
+            .expect_err("failed to return error for unknown key");
+
+        let ExpectOnlyError::UnknownKey(key) = error else {
+            panic!("expected an unknown key error, got {error:?}");
+        };
+        assert_eq!(key, "x\ty");
+        assert_eq!(count, 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn read_string_field() {
+        let records = [
+            r#"{"id": 1, "message": "plain", "extra": [1]}"#,
+            r#"{"message": "tab\there ü 😃", "id": 2}"#,
+            r#"{"id": 3}"#,
+        ];
+        let expected = [
+            (
+                FieldOutcome {
+                    found: true,
+                    had_escapes: false,
+                },
+                "plain",
+            ),
+            (
+                FieldOutcome {
+                    found: true,
+                    had_escapes: true,
+                },
+                "tab\there ü 😃",
+            ),
+            (
+                FieldOutcome {
+                    found: false,
+                    had_escapes: false,
+                },
+                "",
+            ),
+        ];
+
+        let mut buf = String::new();
+        for (json, (expected_outcome, expected_value)) in records.into_iter().zip(expected) {
+            let mut parent = TestParent::new(json);
+            let mut object = parent.object();
+
+            let outcome = object
+                .read_string_field("message", &mut buf, true)
+                .expect("failed to read field");
+
+            assert_eq!(outcome, expected_outcome);
+            assert_eq!(buf, expected_value);
+            assert!(parent.remaining.is_empty());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn read_string_field_leave_rest() {
+        let json = r#"{"message": "a", "id": 2}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let mut buf = String::from("previous");
+        object
+            .read_string_field("message", &mut buf, false)
+            .expect("failed to read field");
+        assert_eq!(buf, "a");
+
+        let (key, _) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("failed to get value from object");
+        assert_eq!(key, "id");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn read_string_field_not_string() {
+        let json = r#"{"message": 1}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let error = object
+            .read_string_field("message", &mut String::new(), true)
+            .expect_err("failed to return error for non-string value");
+
+        assert_eq!(
+            error,
+            ParseAnyError::TypeMismatch {
+                expected: ValueKind::String,
+                found: ValueKind::Number
+            }
+        );
+    }
+
+    #[test]
+    fn get_ignore_ascii_case() {
+        let json = r#"{"UserId": 1, "Age": 2, "ÜBER": 3, "über": 4}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let mut value = object
+            .get_ignore_ascii_case("userid")
+            .expect("failed to parse object")
+            .expect("failed to find key");
+        let number = value
+            .mut_number()
+            .expect("failed to get number from object")
+            .get()
+            .expect("failed to parse number");
+        assert_eq!(number, 1_u8);
+
+        let mut value = object
+            .get_ignore_ascii_case("AGE")
+            .expect("failed to parse object")
+            .expect("failed to find key");
+        let number = value
+            .mut_number()
+            .expect("failed to get number from object")
+            .get()
+            .expect("failed to parse number");
+        assert_eq!(number, 2_u8);
+
+        let mut value = object
+            .get_ignore_ascii_case("über")
+            .expect("failed to parse object")
+            .expect("failed to find key");
+        let number = value
+            .mut_number()
+            .expect("failed to get number from object")
+            .get()
+            .expect("failed to parse number");
+        assert_eq!(number, 4_u8);
+
+        let value = object
+            .get_ignore_ascii_case("userid")
+            .expect("failed to parse object");
+        assert!(value.is_none());
+
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn get_ignore_ascii_case_escape() {
+        let json = r#"{"\u0041": true}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+
+        let value = object
+            .get_ignore_ascii_case("a")
+            .expect("failed to parse object");
+        assert!(value.is_some());
+    }
+
+    #[test]
+    fn any_all() {
+        let json = r#"{"a": 1, "_b": 2, "c": 3}"#;
+
+        let mut parent = TestParent::new(json);
+        let mut object = parent.object();
+        let any = object
+            .any::<_, ParseAnyError>(|key, _| Ok(key.unescaped().starts_with('_')))
+            .expect("failed to parse object");
+        assert!(any);
+
+        let (key, _) = object
+            .next()
+            .expect("failed to parse object")
+            .expect("failed to get value from object");
+        assert_eq!(key, "c");
+
+        let mut parent = TestParent::new(json);
+        let all = parent
+            .object()
+            .all::<_, ParseAnyError>(|_, value| Ok(value.read_number()? < 10_u8))
+            .expect("failed to parse object");
+        assert!(all);
+        assert!(parent.remaining.is_empty());
+    }
+
+    #[test]
+    fn fold_indexed() {
+        let json = r#"{"a": 1, "b": 2, "c": 3}"#;
+
+        let mut parent = TestParent::new(json);
+        let weighted = parent
+            .object()
+            .fold_indexed::<_, _, ParseAnyError>(0, |sum, index, _, value| {
+                let number = value.read_number()?.as_u8().expect("expected an integer") as usize;
+                Ok(sum + index * number)
+            })
+            .expect("failed to parse object");
+        assert_eq!(weighted, 2 + 2 * 3);
+        assert!(parent.remaining.is_empty());
+
+        let mut parent = TestParent::new(json);
+        let mut keys = [""; 3];
+        parent
+            .object()
+            .for_each_indexed::<_, ParseAnyError>(|index, key, _| {
+                keys[index] = key.unescaped();
+                Ok(())
+            })
+            .expect("failed to parse object");
+        assert_eq!(keys, ["a", "b", "c"]);
+    }
 }
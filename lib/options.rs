@@ -0,0 +1,264 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Options that change what JSON is accepted.
+///
+/// They are given to [`Document::with_options`](crate::document::Document::with_options) or [`MultiDocument::with_options`](crate::multi_document::MultiDocument::with_options), which pass them down to every value in the document.
+/// By default, only standard JSON is accepted.
+///
+/// # Examples
+/// ```
+/// use zjson::{document::Document, ParseOptions};
+///
+/// static OPTIONS: ParseOptions = ParseOptions::new().allow_trailing_commas(true);
+///
+/// let mut document = Document::with_options("[1, 2, ]", OPTIONS);
+/// document.finish()?;
+/// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+/// ```
+pub struct ParseOptions {
+    trailing_commas: bool,
+    lone_surrogates: bool,
+    json5_escapes: bool,
+}
+
+impl ParseOptions {
+    #[must_use]
+    #[inline]
+    /// Create the default options, which only accept standard JSON.
+    pub const fn new() -> Self {
+        Self {
+            trailing_commas: false,
+            lone_surrogates: false,
+            json5_escapes: false,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set whether a comma is allowed after the last element of an array or object.
+    pub const fn allow_trailing_commas(mut self, allow: bool) -> Self {
+        self.trailing_commas = allow;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if a comma is allowed after the last element of an array or object.
+    pub const fn trailing_commas_allowed(&self) -> bool {
+        self.trailing_commas
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set whether unpaired surrogate escapes (like `\ud800` without a low surrogate after it) are allowed in strings.
+    ///
+    /// Data from systems that use WTF-16 can contain them.
+    /// When they are allowed, they decode to U+FFFD (`\u{FFFD}`), as [`ParsedString::chars`](crate::string::ParsedString::chars) does for any invalid escape sequence.
+    pub const fn allow_lone_surrogates(mut self, allow: bool) -> Self {
+        self.lone_surrogates = allow;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if unpaired surrogate escapes are allowed in strings.
+    pub const fn lone_surrogates_allowed(&self) -> bool {
+        self.lone_surrogates
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set whether the extra escape sequences from JSON5 are allowed in strings.
+    ///
+    /// These are:
+    /// - `\'` for an apostrophe and `\v` for a vertical tab.
+    /// - `\0` for NUL, when it is not followed by a digit.
+    /// - `\x` followed by two hex digits, like `\x41` for `A`.
+    /// - A backslash followed by a line terminator (a line continuation), which is removed from the string.
+    ///
+    /// [`ParsedString::chars`](crate::string::ParsedString::chars) decodes them whether or not they are allowed, so they compare and display as expected.
+    pub const fn allow_json5_escapes(mut self, allow: bool) -> Self {
+        self.json5_escapes = allow;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the extra escape sequences from JSON5 are allowed in strings.
+    pub const fn json5_escapes_allowed(&self) -> bool {
+        self.json5_escapes
+    }
+}
+
+impl ParseOptions {
+    #[must_use]
+    /// Apply `overrides` on top of these options, such as to layer per-endpoint options on a base preset.
+    ///
+    /// Options that are not set in `overrides` are kept as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{ParseOptions, ParseOptionsOverrides};
+    ///
+    /// let base = ParseOptions::new().allow_trailing_commas(true);
+    /// let options = base.merge(ParseOptionsOverrides::new().allow_json5_escapes(true));
+    ///
+    /// assert!(options.trailing_commas_allowed());
+    /// assert!(options.json5_escapes_allowed());
+    /// assert!(!options.lone_surrogates_allowed());
+    /// ```
+    pub const fn merge(self, overrides: ParseOptionsOverrides) -> Self {
+        const fn or(value: Option<bool>, default: bool) -> bool {
+            match value {
+                Some(value) => value,
+                None => default,
+            }
+        }
+
+        Self {
+            trailing_commas: or(overrides.trailing_commas, self.trailing_commas),
+            lone_surrogates: or(overrides.lone_surrogates, self.lone_surrogates),
+            json5_escapes: or(overrides.json5_escapes, self.json5_escapes),
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ParseOptions {
+    /// Writes every option and whether it is allowed, like `trailing commas: allowed, lone surrogates: rejected, JSON5 escapes: rejected`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const fn allowed(allow: bool) -> &'static str {
+            if allow {
+                "allowed"
+            } else {
+                "rejected"
+            }
+        }
+
+        write!(
+            f,
+            "trailing commas: {}, lone surrogates: {}, JSON5 escapes: {}",
+            allowed(self.trailing_commas),
+            allowed(self.lone_surrogates),
+            allowed(self.json5_escapes),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Changes to make to [`ParseOptions`], applied with [`ParseOptions::merge`].
+///
+/// Each option is only changed if it has been set.
+pub struct ParseOptionsOverrides {
+    trailing_commas: Option<bool>,
+    lone_surrogates: Option<bool>,
+    json5_escapes: Option<bool>,
+}
+
+impl ParseOptionsOverrides {
+    #[must_use]
+    #[inline]
+    /// Create overrides that do not change any options.
+    pub const fn new() -> Self {
+        Self {
+            trailing_commas: None,
+            lone_surrogates: None,
+            json5_escapes: None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Override [`ParseOptions::allow_trailing_commas`].
+    pub const fn allow_trailing_commas(mut self, allow: bool) -> Self {
+        self.trailing_commas = Some(allow);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the override for [`ParseOptions::allow_trailing_commas`], if it is set.
+    pub const fn trailing_commas_allowed(&self) -> Option<bool> {
+        self.trailing_commas
+    }
+
+    #[must_use]
+    #[inline]
+    /// Override [`ParseOptions::allow_lone_surrogates`].
+    pub const fn allow_lone_surrogates(mut self, allow: bool) -> Self {
+        self.lone_surrogates = Some(allow);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the override for [`ParseOptions::allow_lone_surrogates`], if it is set.
+    pub const fn lone_surrogates_allowed(&self) -> Option<bool> {
+        self.lone_surrogates
+    }
+
+    #[must_use]
+    #[inline]
+    /// Override [`ParseOptions::allow_json5_escapes`].
+    pub const fn allow_json5_escapes(mut self, allow: bool) -> Self {
+        self.json5_escapes = Some(allow);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the override for [`ParseOptions::allow_json5_escapes`], if it is set.
+    pub const fn json5_escapes_allowed(&self) -> Option<bool> {
+        self.json5_escapes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParseOptions, ParseOptionsOverrides};
+
+    #[test]
+    fn merge() {
+        let base = ParseOptions::new()
+            .allow_trailing_commas(true)
+            .allow_lone_surrogates(true);
+
+        assert_eq!(base.merge(ParseOptionsOverrides::new()), base);
+        assert_eq!(
+            base.merge(
+                ParseOptionsOverrides::new()
+                    .allow_lone_surrogates(false)
+                    .allow_json5_escapes(true)
+            ),
+            ParseOptions::new()
+                .allow_trailing_commas(true)
+                .allow_json5_escapes(true)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        assert_eq!(
+            ParseOptions::new().to_string(),
+            "trailing commas: rejected, lone surrogates: rejected, JSON5 escapes: rejected"
+        );
+        assert_eq!(
+            ParseOptions::new()
+                .allow_trailing_commas(true)
+                .allow_json5_escapes(true)
+                .to_string(),
+            "trailing commas: allowed, lone surrogates: rejected, JSON5 escapes: allowed"
+        );
+    }
+}
@@ -0,0 +1,365 @@
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    any::{Any, ParseAnyError},
+    document::{Document, ParseAnyDocumentError},
+    string::ParsedString,
+};
+
+/// The marker written where content was left out.
+pub const ELLIPSIS: char = '…';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Limits for [`preview`].
+pub struct PreviewBudget {
+    /// The maximum number of values to include, counting containers and their children.
+    pub max_values: usize,
+    /// The maximum number of bytes of output, not counting the closing brackets and error description.
+    pub max_bytes: usize,
+    /// The maximum number of bytes to include from any single string or key.
+    pub max_string_bytes: usize,
+}
+
+impl PreviewBudget {
+    #[must_use]
+    #[inline]
+    /// Create the default budget: 64 values, 512 bytes and 16 bytes per string.
+    pub const fn new() -> Self {
+        Self {
+            max_values: 64,
+            max_bytes: 512,
+            max_string_bytes: 16,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set the maximum number of values to include.
+    pub const fn max_values(mut self, max_values: usize) -> Self {
+        self.max_values = max_values;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set the maximum number of bytes of output.
+    pub const fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set the maximum number of bytes to include from any single string or key.
+    pub const fn max_string_bytes(mut self, max_string_bytes: usize) -> Self {
+        self.max_string_bytes = max_string_bytes;
+        self
+    }
+}
+
+impl Default for PreviewBudget {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A bounded rendering of a JSON document, created by [`preview`].
+pub struct PreviewString {
+    text: String,
+    truncated: bool,
+    error: Option<ParseAnyDocumentError>,
+}
+
+impl PreviewString {
+    #[must_use]
+    #[inline]
+    /// Returns the rendering, including the error description, if there is one.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if any content was left out to stay within the budget.
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the error that stopped the preview, if the document was invalid.
+    pub const fn error(&self) -> Option<ParseAnyDocumentError> {
+        self.error
+    }
+}
+
+impl fmt::Display for PreviewString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<PreviewString> for String {
+    #[inline]
+    fn from(value: PreviewString) -> Self {
+        value.text
+    }
+}
+
+/// Render a bounded preview of `json`, for logging.
+///
+/// The preview is compact JSON, with [`ELLIPSIS`] where content was left out to stay within `budget`.
+/// No more than [`PreviewBudget::max_string_bytes`] bytes are included from any string or key, so a preview can be logged without leaking whole values.
+/// Strings are included as they are written in the JSON, with escape sequences.
+///
+/// This never fails: if the document is invalid, it is previewed up to the error, and the error description is appended.
+///
+/// # Examples
+/// ```
+/// use zjson::preview::{preview, PreviewBudget};
+///
+/// let budget = PreviewBudget::new().max_values(4).max_string_bytes(4);
+/// let preview = preview(r#"{"name": "Jane Doe", "tags": [1, 2, 3]}"#, budget);
+///
+/// assert_eq!(preview.as_str(), r#"{"name": "Jane…", "tags": [1, …]}"#);
+/// ```
+#[must_use]
+pub fn preview(json: &str, budget: PreviewBudget) -> PreviewString {
+    let mut writer = Writer {
+        text: String::new(),
+        closers: Vec::new(),
+        budget,
+        values: 0,
+        truncated: false,
+    };
+
+    let error = writer.document(&mut Document::new(json)).err();
+
+    if writer.truncated || error.is_some() {
+        writer.text.push(ELLIPSIS);
+    }
+
+    while let Some(closer) = writer.closers.pop() {
+        writer.text.push(closer);
+    }
+
+    if let Some(error) = error {
+        writer.text.push_str(" (");
+        // Writing to a string does not fail
+        let _ = fmt::Write::write_fmt(&mut writer.text, format_args!("{error}"));
+        writer.text.push(')');
+    }
+
+    PreviewString {
+        text: writer.text,
+        truncated: writer.truncated,
+        error,
+    }
+}
+
+struct Writer {
+    text: String,
+    closers: Vec<char>,
+    budget: PreviewBudget,
+    values: usize,
+    truncated: bool,
+}
+
+impl Writer {
+    fn document(&mut self, document: &mut Document) -> Result<(), ParseAnyDocumentError> {
+        if let Some(mut value) = document.next()? {
+            if !self.value(&mut value)? {
+                return Ok(());
+            }
+        }
+
+        document.finish()
+    }
+
+    /// Write `value`, returning [`false`] if the budget ran out.
+    fn value(&mut self, value: &mut Any) -> Result<bool, ParseAnyError> {
+        if self.values == self.budget.max_values {
+            self.truncated = true;
+            return Ok(false);
+        }
+        self.values += 1;
+
+        match value {
+            Any::String(string) => {
+                let parsed = string.get()?;
+                Ok(self.string(parsed))
+            }
+
+            Any::Number(number) => Ok(self.write(number.get()?.as_str())),
+
+            Any::Literal(literal) => Ok(self.write(match literal.get()?.as_bool() {
+                Some(true) => "true",
+                Some(false) => "false",
+                None => "null",
+            })),
+
+            Any::Object(object) => {
+                if !self.write("{") {
+                    return Ok(false);
+                }
+                self.closers.push('}');
+
+                let mut first = true;
+                while let Some((key, mut value)) = object.next()? {
+                    if !(first || self.write(", "))
+                        || !self.string(key)
+                        || !self.write(": ")
+                        || !self.value(&mut value)?
+                    {
+                        return Ok(false);
+                    }
+                    first = false;
+                }
+
+                self.closers.pop();
+                self.text.push('}');
+                Ok(true)
+            }
+
+            Any::Array(array) => {
+                if !self.write("[") {
+                    return Ok(false);
+                }
+                self.closers.push(']');
+
+                let mut first = true;
+                while let Some(mut value) = array.next()? {
+                    if !(first || self.write(", ")) || !self.value(&mut value)? {
+                        return Ok(false);
+                    }
+                    first = false;
+                }
+
+                self.closers.pop();
+                self.text.push(']');
+                Ok(true)
+            }
+        }
+    }
+
+    /// Write a string with its quotes, cutting it to the string budget.
+    fn string(&mut self, string: ParsedString) -> bool {
+        let raw = string.unescaped();
+
+        // Cut between characters, so escape sequences (including surrogate
+        // pairs) are not cut in half
+        let end = string
+            .char_indices()
+            .map(|(range, _)| range.end)
+            .take_while(|&end| end <= self.budget.max_string_bytes)
+            .last()
+            .unwrap_or(0);
+        let cut = &raw[..end];
+
+        if !self.write("\"") || !self.write(cut) {
+            return false;
+        }
+
+        if cut.len() < raw.len() {
+            self.text.push(ELLIPSIS);
+        }
+        self.text.push('"');
+
+        true
+    }
+
+    /// Write `s` if it fits in the byte budget, returning [`false`] if not.
+    fn write(&mut self, s: &str) -> bool {
+        if self.text.len() + s.len() > self.budget.max_bytes {
+            self.truncated = true;
+            return false;
+        }
+
+        self.text.push_str(s);
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{any::ParseAnyError, array::ParseArrayError, document::ParseAnyDocumentError};
+
+    use super::{preview, PreviewBudget};
+
+    #[test]
+    fn complete() {
+        let json = r#" { "a" : [1, true, null], "b": {"c": "d"} } "#;
+        let preview = preview(json, PreviewBudget::new());
+
+        assert_eq!(
+            preview.as_str(),
+            r#"{"a": [1, true, null], "b": {"c": "d"}}"#
+        );
+        assert!(!preview.is_truncated());
+        assert_eq!(preview.error(), None);
+    }
+
+    #[test]
+    fn truncated_strings() {
+        let json = r#"{"password": "hunter2hunter2", "note": "éé"}"#;
+        let preview = preview(json, PreviewBudget::new().max_string_bytes(3));
+
+        assert_eq!(preview.as_str(), r#"{"pas…": "hun…", "not…": "é…"}"#);
+
+        let json = r#"["ab\u00e9", "a\nb"]"#;
+        let preview = super::preview(json, PreviewBudget::new().max_string_bytes(4));
+
+        // Escape sequences are not cut in half
+        assert_eq!(preview.as_str(), r#"["ab…", "a\nb"]"#);
+
+        // Nor are escaped backslashes
+        let json = r#"["ab\\cdef", "a\\\\b"]"#;
+        let preview = super::preview(json, PreviewBudget::new().max_string_bytes(4));
+        assert_eq!(preview.as_str(), r#"["ab\\…", "a\\…"]"#);
+
+        // Nor are surrogate pairs
+        let json = r#"["a\ud83d\ude03b", "\ud83d\ude03"]"#;
+        let preview = super::preview(json, PreviewBudget::new().max_string_bytes(8));
+        assert_eq!(preview.as_str(), r#"["a…", "…"]"#);
+    }
+
+    #[test]
+    fn truncated_arrays() {
+        let json = "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]";
+
+        let preview = preview(json, PreviewBudget::new().max_values(7));
+        assert_eq!(preview.as_str(), "[[1, 2, 3], [4, …]]");
+        assert!(preview.is_truncated());
+
+        let preview = super::preview(json, PreviewBudget::new().max_bytes(14));
+        assert_eq!(preview.as_str(), "[[1, 2, 3], [4…]]");
+        assert!(preview.is_truncated());
+    }
+
+    #[test]
+    fn invalid() {
+        let json = r#"{"a": [1, 2, j]}"#;
+        let preview = preview(json, PreviewBudget::new());
+
+        let error =
+            ParseAnyDocumentError::Any(ParseAnyError::Array(ParseArrayError::InvalidElement {
+                c: 'j',
+                or_end: false,
+                index: 2,
+            }));
+        assert_eq!(preview.error(), Some(error));
+        assert_eq!(
+            preview.to_string(),
+            format!(r#"{{"a": [1, 2…]}} ({error})"#)
+        );
+
+        let preview = super::preview("[1] 2", PreviewBudget::new());
+        assert!(preview.as_str().starts_with("[1]… ("));
+    }
+}
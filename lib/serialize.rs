@@ -0,0 +1,352 @@
+use core::fmt;
+
+use serde::{
+    de::{self, IgnoredAny, MapAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    literal::ParsedLiteral, number::ParsedNumber, string::ParsedString, ParseOptions,
+    ParseOptionsOverrides,
+};
+
+impl<'json> Serialize for ParsedString<'json> {
+    /// Serializes the escaped string (with escape sequences processed).
+    ///
+    /// A string without escape sequences is serialized from the JSON directly; otherwise it is escaped as it is serialized.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.has_escapes() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_str(self.unescaped())
+        }
+    }
+}
+
+impl<'json> Serialize for ParsedNumber<'json> {
+    /// Serializes the number as the first of [`u64`], [`i64`], [`u128`], [`i128`] and [`f64`] that can represent it.
+    ///
+    /// Without the `float` feature, a number that is not an integer returns an error instead of being serialized as an [`f64`].
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(n) = self.as_u64() {
+            serializer.serialize_u64(n)
+        } else if let Some(n) = self.as_i64() {
+            serializer.serialize_i64(n)
+        } else if let Some(n) = self.as_u128() {
+            serializer.serialize_u128(n)
+        } else if let Some(n) = self.as_i128() {
+            serializer.serialize_i128(n)
+        } else {
+            #[cfg(feature = "float")]
+            return serializer.serialize_f64(self.as_f64());
+            #[cfg(not(feature = "float"))]
+            return Err(serde::ser::Error::custom(
+                "cannot serialize a non-integer number without the float feature",
+            ));
+        }
+    }
+}
+
+impl Serialize for ParsedLiteral {
+    /// Serializes `true` and `false` as [`bool`]s, and `null` as a unit.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_bool() {
+            Some(b) => serializer.serialize_bool(b),
+            None => serializer.serialize_unit(),
+        }
+    }
+}
+
+/// The fields of [`ParseOptions`] and [`ParseOptionsOverrides`], in the order that they are serialized.
+const OPTION_FIELDS: &[&str] = &["trailing_commas", "lone_surrogates", "json5_escapes"];
+
+impl Serialize for ParseOptions {
+    /// Serializes the options as a struct with a [`bool`] for each option, like `{"trailing_commas":false,"lone_surrogates":false,"json5_escapes":false}`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ParseOptions", OPTION_FIELDS.len())?;
+        state.serialize_field("trailing_commas", &self.trailing_commas_allowed())?;
+        state.serialize_field("lone_surrogates", &self.lone_surrogates_allowed())?;
+        state.serialize_field("json5_escapes", &self.json5_escapes_allowed())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseOptions {
+    /// Deserializes options from a map of option names to [`bool`]s, as they are serialized.
+    ///
+    /// Options that are missing are not allowed, as in [`ParseOptions::new`].
+    /// Unknown fields are ignored, so that configuration written for newer versions can still be read.
+    ///
+    /// # Errors
+    /// An error is returned, naming the option, if an option is given more than once (even with the same value) or is not a [`bool`].
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ParseOptionsOverrides::deserialize(deserializer)
+            .map(|overrides| Self::new().merge(overrides))
+    }
+}
+
+impl Serialize for ParseOptionsOverrides {
+    /// Serializes the overrides as a struct with a [`bool`] for each option that is set, like `{"trailing_commas":true}`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = [
+            self.trailing_commas_allowed(),
+            self.lone_surrogates_allowed(),
+            self.json5_escapes_allowed(),
+        ];
+
+        let mut state = serializer
+            .serialize_struct("ParseOptionsOverrides", fields.iter().flatten().count())?;
+        for (name, value) in OPTION_FIELDS.iter().zip(fields) {
+            match value {
+                Some(allow) => state.serialize_field(name, &allow)?,
+                None => state.skip_field(name)?,
+            }
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseOptionsOverrides {
+    /// Deserializes overrides from a map of option names to [`bool`]s, as they are serialized.
+    ///
+    /// Options that are missing are not set.
+    /// Unknown fields are ignored, so that configuration written for newer versions can still be read.
+    ///
+    /// # Errors
+    /// An error is returned, naming the option, if an option is given more than once (even with the same value) or is not a [`bool`].
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("ParseOptionsOverrides", OPTION_FIELDS, OverridesVisitor)
+    }
+}
+
+/// The index of a field in [`OPTION_FIELDS`], or [`None`] for an unknown field.
+struct OptionField(Option<usize>);
+
+impl<'de> Deserialize<'de> for OptionField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = OptionField;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an option name")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(OptionField(
+                    OPTION_FIELDS.iter().position(|&field| field == v),
+                ))
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct OverridesVisitor;
+
+impl<'de> Visitor<'de> for OverridesVisitor {
+    type Value = ParseOptionsOverrides;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of parse options")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = [None; OPTION_FIELDS.len()];
+
+        while let Some(OptionField(field)) = map.next_key()? {
+            let Some(i) = field else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+
+            if fields[i].is_some() {
+                return Err(de::Error::duplicate_field(OPTION_FIELDS[i]));
+            }
+            fields[i] = Some(map.next_value::<bool>()?);
+        }
+
+        let [trailing_commas, lone_surrogates, json5_escapes] = fields;
+        let mut overrides = ParseOptionsOverrides::new();
+        if let Some(allow) = trailing_commas {
+            overrides = overrides.allow_trailing_commas(allow);
+        }
+        if let Some(allow) = lone_surrogates {
+            overrides = overrides.allow_lone_surrogates(allow);
+        }
+        if let Some(allow) = json5_escapes {
+            overrides = overrides.allow_json5_escapes(allow);
+        }
+
+        Ok(overrides)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{any::Any, document::Document, ParseOptions, ParseOptionsOverrides};
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn round_trip() {
+        let json =
+            r#"["plain", "a\"bé😃\n", 1, -2, 18446744073709551616, 1.5e3, true, false, null]"#;
+        let expected = r#"["plain","a\"bé😃\n",1,-2,18446744073709551616,1500.0,true,false,null]"#;
+
+        let mut document = Document::new(json);
+        let Ok(Some(Any::Array(mut array))) = document.next() else {
+            panic!("expected an array");
+        };
+
+        let mut values = Vec::new();
+        while let Some(value) = array.next().expect("failed to parse array") {
+            values.push(
+                match value {
+                    Any::String(mut string) => {
+                        serde_json::to_string(&string.get().expect("failed to parse string"))
+                    }
+                    Any::Number(mut number) => {
+                        serde_json::to_string(&number.get().expect("failed to parse number"))
+                    }
+                    Any::Literal(mut literal) => {
+                        serde_json::to_string(&literal.get().expect("failed to parse literal"))
+                    }
+                    _ => panic!("unexpected value"),
+                }
+                .expect("failed to serialize value"),
+            );
+        }
+
+        let output = format!("[{}]", values.join(","));
+        assert_eq!(output, expected);
+
+        // The output parses back to the same values
+        let mut document = Document::new(&output);
+        let Ok(Some(Any::Array(mut array))) = document.next() else {
+            panic!("expected an array");
+        };
+        let Ok(Some(Any::String(mut string))) = array.nth(1) else {
+            panic!("expected a string");
+        };
+        assert_eq!(string.get().map(|s| s == "a\"bé😃\n"), Ok(true));
+    }
+
+    #[cfg(not(feature = "float"))]
+    #[test]
+    fn no_float() {
+        let mut document = Document::new("1.5");
+        let Ok(Some(Any::Number(mut number))) = document.next() else {
+            panic!("expected a number");
+        };
+
+        let number = number.get().expect("failed to parse number");
+        assert!(serde_json::to_string(&number).is_err());
+    }
+
+    #[test]
+    fn options_round_trip() {
+        for i in 0..8 {
+            let options = ParseOptions::new()
+                .allow_trailing_commas(i & 1 != 0)
+                .allow_lone_surrogates(i & 2 != 0)
+                .allow_json5_escapes(i & 4 != 0);
+
+            let json = serde_json::to_string(&options).expect("failed to serialize options");
+            assert_eq!(serde_json::from_str(&json).ok(), Some(options), "{json}");
+        }
+
+        assert_eq!(
+            serde_json::to_string(&ParseOptions::new().allow_lone_surrogates(true)).ok(),
+            Some(
+                r#"{"trailing_commas":false,"lone_surrogates":true,"json5_escapes":false}"#
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn options_missing_and_unknown_fields() {
+        let options: ParseOptions = serde_json::from_str(
+            r#"{"json5_escapes": true, "max_depth": 0, "comments": {"line": [true]}}"#,
+        )
+        .expect("failed to deserialize options");
+        assert_eq!(options, ParseOptions::new().allow_json5_escapes(true));
+
+        let options: ParseOptions =
+            serde_json::from_str("{}").expect("failed to deserialize options");
+        assert_eq!(options, ParseOptions::new());
+    }
+
+    #[test]
+    fn options_validation() {
+        for (json, message) in [
+            (
+                r#"{"trailing_commas": true, "trailing_commas": false}"#,
+                "duplicate field `trailing_commas`",
+            ),
+            (
+                r#"{"lone_surrogates": true, "lone_surrogates": true}"#,
+                "duplicate field `lone_surrogates`",
+            ),
+            (
+                r#"{"json5_escapes": "yes"}"#,
+                "invalid type: string \"yes\", expected a boolean",
+            ),
+            (
+                r#"{"json5_escapes": null}"#,
+                "invalid type: null, expected a boolean",
+            ),
+            (
+                "[true]",
+                "invalid type: sequence, expected a map of parse options",
+            ),
+            (
+                "true",
+                "invalid type: boolean `true`, expected a map of parse options",
+            ),
+        ] {
+            let error = serde_json::from_str::<ParseOptions>(json)
+                .expect_err(json)
+                .to_string();
+            assert!(error.starts_with(message), "{json}: {error}");
+
+            let error = serde_json::from_str::<ParseOptionsOverrides>(json)
+                .expect_err(json)
+                .to_string();
+            assert!(error.starts_with(message), "{json}: {error}");
+        }
+    }
+
+    #[test]
+    fn overrides_round_trip() {
+        for overrides in [
+            ParseOptionsOverrides::new(),
+            ParseOptionsOverrides::new().allow_trailing_commas(false),
+            ParseOptionsOverrides::new()
+                .allow_lone_surrogates(true)
+                .allow_json5_escapes(false),
+        ] {
+            let json = serde_json::to_string(&overrides).expect("failed to serialize overrides");
+            assert_eq!(serde_json::from_str(&json).ok(), Some(overrides), "{json}");
+        }
+
+        // Unset options are not written, so they stay unset
+        let overrides = ParseOptionsOverrides::new().allow_json5_escapes(true);
+        let json = serde_json::to_string(&overrides).expect("failed to serialize overrides");
+        assert_eq!(json, r#"{"json5_escapes":true}"#);
+
+        let base: ParseOptions = serde_json::from_str(r#"{"trailing_commas": true}"#)
+            .expect("failed to deserialize options");
+        let overrides: ParseOptionsOverrides =
+            serde_json::from_str(&json).expect("failed to deserialize overrides");
+        assert_eq!(
+            base.merge(overrides),
+            ParseOptions::new()
+                .allow_trailing_commas(true)
+                .allow_json5_escapes(true)
+        );
+    }
+}
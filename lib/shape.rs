@@ -0,0 +1,386 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    any::Any,
+    document::{Document, ParseAnyDocumentError},
+};
+
+/// The default value of [`ShapeHashOptions::max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Which elements of an array contribute to its shape.
+pub enum ArrayElements {
+    /// Every element contributes its shape.
+    #[default]
+    All,
+    /// Only the first element contributes its shape.
+    First,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// How the length of an array contributes to its shape.
+pub enum ArrayLength {
+    /// The length does not contribute (other than through the elements).
+    #[default]
+    Ignore,
+    /// The exact length contributes.
+    Exact,
+    /// The length contributes as one of the buckets: 0, 1, 2-3, 4-7, 8-15, ... (powers of two).
+    Bucketed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Options for [`shape_hash`].
+pub struct ShapeHashOptions {
+    /// Which elements of an array contribute to its shape.
+    pub array_elements: ArrayElements,
+    /// How the length of an array contributes to its shape.
+    pub array_length: ArrayLength,
+    /// The maximum number of objects and arrays that can be nested in each other.
+    pub max_depth: usize,
+}
+
+impl Default for ShapeHashOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShapeHashOptions {
+    #[must_use]
+    #[inline]
+    /// Create the default options: all array elements contribute, array lengths are ignored and the maximum depth is [`DEFAULT_MAX_DEPTH`].
+    pub const fn new() -> Self {
+        Self {
+            array_elements: ArrayElements::All,
+            array_length: ArrayLength::Ignore,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set which elements of an array contribute to its shape.
+    pub const fn array_elements(mut self, array_elements: ArrayElements) -> Self {
+        self.array_elements = array_elements;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set how the length of an array contributes to its shape.
+    pub const fn array_length(mut self, array_length: ArrayLength) -> Self {
+        self.array_length = array_length;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Set the maximum number of objects and arrays that can be nested in each other.
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The error returned when [`shape_hash`] fails.
+pub enum ShapeHashError {
+    /// Parsing the document failed.
+    Parse(ParseAnyDocumentError),
+    /// Objects and arrays were nested deeper than [`ShapeHashOptions::max_depth`].
+    TooDeep,
+}
+
+impl fmt::Display for ShapeHashError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => err.fmt(f),
+            Self::TooDeep => write!(f, "JSON document is nested too deeply to hash its shape!"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for ShapeHashError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            Self::TooDeep => None,
+        }
+    }
+}
+
+impl<E: Into<ParseAnyDocumentError>> From<E> for ShapeHashError {
+    #[inline]
+    fn from(value: E) -> Self {
+        Self::Parse(value.into())
+    }
+}
+
+const STRING: u8 = 0;
+const NUMBER: u8 = 1;
+const OBJECT: u8 = 2;
+const ARRAY: u8 = 3;
+const LITERAL: u8 = 4;
+const END: u8 = 0xff;
+
+/// Hash the shape of the value in `document` into `state`.
+///
+/// The shape includes the names of keys (with escape sequences evaluated), the kinds of values and how they are nested, but not the contents of strings, numbers or literals.
+/// This can be used to cheaply identify which kind of document has been received before fully parsing it.
+///
+/// The whole document is parsed, so errors anywhere in it are returned.
+/// Lengths are hashed as [`u64`]s, so with a portable hasher, the hash is the same on 32-bit and 64-bit targets.
+///
+/// Nested objects and arrays are hashed recursively, so the nesting is limited to [`ShapeHashOptions::max_depth`] levels to bound the stack used.
+/// Use [`validate`](crate::validate()) to check documents that are nested deeper than that.
+///
+/// # Errors
+/// - If parsing fails in the document or a value, the error is returned as a [`ShapeHashError::Parse`].
+/// - If objects and arrays are nested deeper than [`ShapeHashOptions::max_depth`], this will return [`ShapeHashError::TooDeep`].
+pub fn shape_hash<H: Hasher>(
+    document: &mut Document,
+    state: &mut H,
+    options: ShapeHashOptions,
+) -> Result<(), ShapeHashError> {
+    if let Some(mut value) = document.next()? {
+        hash_value(&mut value, state, options, 0)?;
+    }
+
+    Ok(document.finish()?)
+}
+
+/// Hash the shape of `value`, which is nested in `depth` objects and arrays.
+fn hash_value<H: Hasher>(
+    value: &mut Any,
+    state: &mut H,
+    options: ShapeHashOptions,
+    depth: usize,
+) -> Result<(), ShapeHashError> {
+    if matches!(value, Any::Object(_) | Any::Array(_)) && depth >= options.max_depth {
+        return Err(ShapeHashError::TooDeep);
+    }
+
+    match value {
+        Any::String(string) => {
+            string.finish()?;
+            state.write_u8(STRING);
+        }
+
+        Any::Number(number) => {
+            number.finish()?;
+            state.write_u8(NUMBER);
+        }
+
+        Any::Literal(literal) => {
+            literal.finish()?;
+            state.write_u8(LITERAL);
+        }
+
+        Any::Object(object) => {
+            state.write_u8(OBJECT);
+
+            while let Some((key, mut value)) = object.next()? {
+                key.hash(state);
+                hash_value(&mut value, state, options, depth + 1)?;
+                value.finish()?;
+            }
+
+            state.write_u8(END);
+        }
+
+        Any::Array(array) => {
+            state.write_u8(ARRAY);
+
+            let mut length = 0_usize;
+            while let Some(mut value) = array.next()? {
+                if length == 0 || options.array_elements == ArrayElements::All {
+                    hash_value(&mut value, state, options, depth + 1)?;
+                }
+
+                value.finish()?;
+                length += 1;
+            }
+
+            match options.array_length {
+                ArrayLength::Ignore => {}
+                // Use a fixed width, so that the hash does not depend on the target
+                ArrayLength::Exact => state.write_u64(length as u64),
+                ArrayLength::Bucketed => state.write_u32(usize::BITS - length.leading_zeros()),
+            }
+
+            state.write_u8(END);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use core::hash::Hasher;
+
+    use crate::document::Document;
+
+    use super::{
+        shape_hash, ArrayElements, ArrayLength, ShapeHashError, ShapeHashOptions, ARRAY,
+        DEFAULT_MAX_DEPTH, END, NUMBER,
+    };
+
+    /// A 64-bit FNV-1a hasher, so the tests do not depend on `std`'s hasher.
+    struct Fnv(u64);
+
+    impl Default for Fnv {
+        fn default() -> Self {
+            Self(0xcbf2_9ce4_8422_2325)
+        }
+    }
+
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    fn hash(json: &str, options: ShapeHashOptions) -> u64 {
+        let mut hasher = Fnv::default();
+        shape_hash(&mut Document::new(json), &mut hasher, options)
+            .expect("failed to parse document");
+        hasher.finish()
+    }
+
+    /// A hasher that records everything written to it.
+    #[derive(Default)]
+    struct Recorder(Vec<u8>);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    #[test]
+    fn exact_length_width() {
+        let mut recorder = Recorder::default();
+        let options = ShapeHashOptions::new().array_length(ArrayLength::Exact);
+        shape_hash(&mut Document::new("[1, 2]"), &mut recorder, options)
+            .expect("failed to parse document");
+
+        let mut expected = vec![ARRAY, NUMBER, NUMBER];
+        expected.extend_from_slice(&2_u64.to_ne_bytes());
+        expected.push(END);
+        assert_eq!(recorder.0, expected);
+    }
+
+    #[test]
+    fn same_shape() {
+        let options = ShapeHashOptions::new();
+
+        assert_eq!(
+            hash(r#"{"a": 1, "b": ["x", true], "c": {"d": null}}"#, options),
+            hash(
+                r#"{"a": -2e5, "b": ["y", false], "c": {"d": true}}"#,
+                options
+            )
+        );
+    }
+
+    #[test]
+    fn different_shape() {
+        let options = ShapeHashOptions::new();
+        let base = hash(r#"{"a": 1, "b": [2, 3]}"#, options);
+
+        // added key
+        assert_ne!(base, hash(r#"{"a": 1, "b": [2, 3], "c": 4}"#, options));
+        // renamed key
+        assert_ne!(base, hash(r#"{"a": 1, "c": [2, 3]}"#, options));
+        // changed nesting
+        assert_ne!(base, hash(r#"{"a": 1, "b": [[2], 3]}"#, options));
+        // changed kind
+        assert_ne!(base, hash(r#"{"a": "1", "b": [2, 3]}"#, options));
+    }
+
+    #[test]
+    fn array_options() {
+        let first = ShapeHashOptions::new().array_elements(ArrayElements::First);
+        assert_eq!(hash("[1, 2, 3]", first), hash(r#"[1, "2", {}]"#, first));
+        assert_ne!(hash("[1, 2, 3]", first), hash("[[1], 2, 3]", first));
+
+        let all = ShapeHashOptions::new();
+        assert_ne!(hash("[1, 2, 3]", all), hash(r#"[1, "2", {}]"#, all));
+
+        let exact = ShapeHashOptions::new()
+            .array_elements(ArrayElements::First)
+            .array_length(ArrayLength::Exact);
+        assert_ne!(hash("[1, 2, 3]", exact), hash("[1, 2]", exact));
+
+        let bucketed = ShapeHashOptions::new()
+            .array_elements(ArrayElements::First)
+            .array_length(ArrayLength::Bucketed);
+        assert_eq!(hash("[1, 2, 3]", bucketed), hash("[1, 2]", bucketed));
+        assert_ne!(hash("[1, 2, 3]", bucketed), hash("[1, 2, 3, 4]", bucketed));
+        assert_ne!(hash("[]", bucketed), hash("[1]", bucketed));
+    }
+
+    #[test]
+    fn max_depth() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        let options = ShapeHashOptions::new();
+
+        let mut hasher = Fnv::default();
+        assert_eq!(
+            shape_hash(
+                &mut Document::new(&nested(DEFAULT_MAX_DEPTH)),
+                &mut hasher,
+                options
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            shape_hash(
+                &mut Document::new(&nested(DEFAULT_MAX_DEPTH + 1)),
+                &mut hasher,
+                options
+            ),
+            Err(ShapeHashError::TooDeep)
+        );
+
+        // Deeply nested input is rejected before it can overflow the stack
+        assert_eq!(
+            shape_hash(&mut Document::new(&nested(1_000_000)), &mut hasher, options),
+            Err(ShapeHashError::TooDeep)
+        );
+
+        let options = options.max_depth(1);
+        assert_eq!(
+            shape_hash(&mut Document::new("[1, 2]"), &mut hasher, options),
+            Ok(())
+        );
+        assert_eq!(
+            shape_hash(&mut Document::new(r#"{"a": []}"#), &mut hasher, options),
+            Err(ShapeHashError::TooDeep)
+        );
+        assert_eq!(
+            shape_hash(&mut Document::new("1"), &mut hasher, options.max_depth(0)),
+            Ok(())
+        );
+    }
+}
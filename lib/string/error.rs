@@ -1,23 +1,42 @@
 use core::fmt;
 
+use crate::debug::DisplayChar;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 /// The error returned when parsing a [`String`](super::String) fails.
+///
+/// The errors in escape sequences have the `offset` of the character that caused the error, in bytes from the start of the string's contents (after the opening quotation mark).
 pub enum ParseStringError {
     /// The JSON string ended before the string was terminated.
     UnexpectedEnd,
-    /// An invalid character was escaped.
-    InvalidEscape(char),
+    /// An invalid character was escaped, or a JSON5 `\x` escape had a non-hex character.
+    InvalidEscape {
+        /// The escaped or non-hex character.
+        c: char,
+        /// Where the character is in the string.
+        offset: usize,
+    },
     /// A non-hex character was used in a unicode escape.
-    InvalidUnicodeEscape(char),
+    InvalidUnicodeEscape {
+        /// The non-hex character.
+        c: char,
+        /// Where the character is in the string.
+        offset: usize,
+    },
     /// A low surrogate was found that was not prefixed by a high surrogate.
     MissingHighSurrogate {
         /// The low surrogate found.
         low: u16,
+        /// Where the last digit of the low surrogate is in the string.
+        offset: usize,
     },
     /// A high surrogate was found that was not followed by a low surrogate.
     MissingLowSurrogate {
         /// The high surrogate found.
         high: u16,
+        /// Where the character after the high surrogate is in the string.
+        offset: usize,
     },
     /// A unicode escape sequence was found after a high surrogate but it was not a valid low surrogate.
     InvalidLowSurrogate {
@@ -25,7 +44,40 @@ pub enum ParseStringError {
         high: u16,
         /// The unicode escape sequence.
         low: u16,
+        /// Where the last digit of the unicode escape sequence is in the string.
+        offset: usize,
     },
+    /// A quotation mark was found that was not escaped, in a string given to [`ParsedString::try_new`](super::ParsedString::try_new).
+    UnescapedQuote,
+}
+
+impl ParseStringError {
+    #[must_use]
+    #[inline]
+    /// Returns where the error is in the string, for errors in escape sequences.
+    pub const fn offset(self) -> Option<usize> {
+        match self {
+            Self::InvalidEscape { offset, .. }
+            | Self::InvalidUnicodeEscape { offset, .. }
+            | Self::MissingHighSurrogate { offset, .. }
+            | Self::MissingLowSurrogate { offset, .. }
+            | Self::InvalidLowSurrogate { offset, .. } => Some(offset),
+            Self::UnexpectedEnd | Self::UnescapedQuote => None,
+        }
+    }
+
+    /// Set the offset of an error in an escape sequence, which is found by the caller of the string machine.
+    pub(crate) const fn at(mut self, at: usize) -> Self {
+        match &mut self {
+            Self::InvalidEscape { offset, .. }
+            | Self::InvalidUnicodeEscape { offset, .. }
+            | Self::MissingHighSurrogate { offset, .. }
+            | Self::MissingLowSurrogate { offset, .. }
+            | Self::InvalidLowSurrogate { offset, .. } => *offset = at,
+            Self::UnexpectedEnd | Self::UnescapedQuote => {}
+        }
+        self
+    }
 }
 
 impl fmt::Display for ParseStringError {
@@ -34,31 +86,67 @@ impl fmt::Display for ParseStringError {
             Self::UnexpectedEnd => {
                 write!(f, "Unexpected end of JSON string (missing \")!")
             }
-            Self::InvalidEscape(c) => write!(f, "Invalid escape character ({c}) in JSON string!"),
-            Self::InvalidUnicodeEscape(c) => {
+            Self::InvalidEscape { c, offset } => write!(
+                f,
+                "Invalid escape character ({c}) at byte {offset} of JSON string!",
+                c = DisplayChar(*c)
+            ),
+            Self::InvalidUnicodeEscape { c, offset } => {
                 write!(
                     f,
-                    "Invalid character ({c}) in unicode escape in JSON string!"
+                    "Invalid character ({c}) in unicode escape at byte {offset} of JSON string!",
+                    c = DisplayChar(*c)
                 )
             }
-            Self::MissingHighSurrogate { low } => {
+            Self::MissingHighSurrogate { low, offset } => {
                 write!(
                     f,
-                    "Found a low surrogate (\\u{low:0>4x}) not prefixed with a high surrogate!"
+                    "Found a low surrogate (\\u{low:0>4x}) not prefixed with a high surrogate at byte {offset} of JSON string!"
                 )
             }
-            Self::MissingLowSurrogate { high } => {
+            Self::MissingLowSurrogate { high, offset } => {
                 write!(
                     f,
-                    "Found a high surrogate (\\u{high:0>4x}) not followed by a low surrogate!"
+                    "Found a high surrogate (\\u{high:0>4x}) not followed by a low surrogate at byte {offset} of JSON string!"
                 )
             }
-            Self::InvalidLowSurrogate { high, low } => {
-                write!(f, "Invalid low surrogate (\\u{low:0>4x}) after a high surrogate (\\u{high:0>4x}!)")
+            Self::InvalidLowSurrogate { high, low, offset } => {
+                write!(f, "Invalid low surrogate (\\u{low:0>4x}) after a high surrogate (\\u{high:0>4x}) at byte {offset} of JSON string!")
             }
+            Self::UnescapedQuote => write!(f, "Unescaped quotation mark (\") in JSON string!"),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseStringError {}
+impl core::error::Error for ParseStringError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseStringError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseStringError::InvalidEscape { c: '\n', offset: 3 }.to_string(),
+            "Invalid escape character (U+000A) at byte 3 of JSON string!"
+        );
+        assert_eq!(
+            ParseStringError::InvalidUnicodeEscape { c: '\0', offset: 0 }.to_string(),
+            "Invalid character (U+0000) in unicode escape at byte 0 of JSON string!"
+        );
+        assert_eq!(
+            ParseStringError::InvalidEscape { c: 'x', offset: 1 }.to_string(),
+            "Invalid escape character (x) at byte 1 of JSON string!"
+        );
+        assert_eq!(
+            ParseStringError::InvalidLowSurrogate {
+                high: 0xd83d,
+                low: 0x41,
+                offset: 11
+            }
+            .to_string(),
+            "Invalid low surrogate (\\u0041) after a high surrogate (\\ud83d) at byte 11 of JSON string!"
+        );
+    }
+}
@@ -1,13 +1,65 @@
-use crate::{status::Status, string::ParseStringError};
+use crate::{status::Status, string::ParseStringError, ParseOptions};
 
+/// The string machine, which checks a string one character at a time.
+///
+/// Errors have an offset of 0, as the machine does not know where it is in the string; callers set it with [`ParseStringError::at`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Machine {
     In,
     Escape(EscapeMachine),
+    /// After a JSON5 `\0` escape, which must not be followed by a digit.
+    Nul,
+    /// After a JSON5 line continuation ending in a carriage return, which may be followed by a line feed.
+    CarriageReturn,
 }
 
 impl Machine {
+    /// Apply `c`, only accepting standard JSON.
+    #[inline]
     pub fn apply(self, c: char) -> Result<Option<Self>, ParseStringError> {
+        self.step(c, false)
+    }
+
+    /// Apply `c`, accepting what `options` allow.
+    pub fn apply_with(
+        self,
+        c: char,
+        options: ParseOptions,
+    ) -> Result<Option<Self>, ParseStringError> {
+        let json5 = options.json5_escapes_allowed();
+        let result = self.step(c, json5);
+        if !options.lone_surrogates_allowed() {
+            return result;
+        }
+
+        // Unpaired surrogate escapes are skipped instead of returning an error
+        match result {
+            // The low surrogate escape has been read, so continue after it
+            Err(ParseStringError::MissingHighSurrogate { .. }) => Ok(Some(Self::In)),
+            // Continue as if the high surrogate escape was not there
+            Err(ParseStringError::MissingLowSurrogate { .. }) => match self {
+                Self::Escape(EscapeMachine::Surrogate {
+                    low: LowMachine::AwaitingU,
+                    ..
+                }) => Self::Escape(EscapeMachine::Awaiting).step(c, json5),
+                _ => Self::In.step(c, json5),
+            },
+            // The second escape is not a low surrogate, so it stands alone
+            Err(ParseStringError::InvalidLowSurrogate { low, .. }) => {
+                Ok(Some(if (0xd800..0xdc00).contains(&low) {
+                    Self::Escape(EscapeMachine::Surrogate {
+                        high: low,
+                        low: LowMachine::Awaiting,
+                    })
+                } else {
+                    Self::In
+                }))
+            }
+            result => result,
+        }
+    }
+
+    fn step(self, c: char, json5: bool) -> Result<Option<Self>, ParseStringError> {
         match self {
             Self::In => Ok(match c {
                 '\\' => Some(Self::Escape(EscapeMachine::Awaiting)),
@@ -15,7 +67,23 @@ impl Machine {
                 _ => Some(Self::In),
             }),
 
-            Self::Escape(machine) => Ok(match machine.apply(c)? {
+            // Octal escapes are not allowed
+            Self::Nul if c.is_ascii_digit() => {
+                Err(ParseStringError::InvalidEscape { c: '0', offset: 0 })
+            }
+            Self::CarriageReturn if c == '\n' => Ok(Some(Self::In)),
+            Self::Nul | Self::CarriageReturn => Self::In.step(c, json5),
+
+            Self::Escape(EscapeMachine::Awaiting) if json5 && is_line_terminator(c) => {
+                Ok(Some(if c == '\r' {
+                    Self::CarriageReturn
+                } else {
+                    Self::In
+                }))
+            }
+            Self::Escape(EscapeMachine::Awaiting) if json5 && c == '0' => Ok(Some(Self::Nul)),
+
+            Self::Escape(machine) => Ok(match machine.apply(c, json5)? {
                 Status::Parsing(machine) => Some(Self::Escape(machine)),
                 Status::Done(_) => Some(Self::In),
             }),
@@ -23,16 +91,35 @@ impl Machine {
     }
 }
 
+/// Returns [`true`] if `c` ends a line in JSON5, so a backslash before it is a line continuation.
+pub const fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EscapeMachine {
     Awaiting,
-    Unicode { n: u16, len: u8 },
-    Surrogate { high: u16, low: LowMachine },
+    Unicode {
+        n: u16,
+        len: u8,
+    },
+    Surrogate {
+        high: u16,
+        low: LowMachine,
+    },
+    /// A JSON5 `\x` escape, with two hex digits.
+    Hex {
+        n: u8,
+        len: u8,
+    },
 }
 
 impl EscapeMachine {
-    pub fn apply(self, c: char) -> Result<Status<Self, char>, ParseStringError> {
+    /// Apply `c`, also accepting JSON5 escapes if `json5` is [`true`].
+    ///
+    /// Line continuations are not handled here, as they do not produce a character.
+    pub fn apply(self, c: char, json5: bool) -> Result<Status<Self, char>, ParseStringError> {
         match self {
             Self::Awaiting => match c {
                 '"' | '\\' | '/' => Ok(Status::Done(c)),
@@ -44,9 +131,32 @@ impl EscapeMachine {
 
                 'u' => Ok(Status::Parsing(Self::Unicode { n: 0, len: 0 })),
 
-                _ => Err(ParseStringError::InvalidEscape(c)),
+                '\'' if json5 => Ok(Status::Done(c)),
+                'v' if json5 => Ok(Status::Done('\x0b')),
+                '0' if json5 => Ok(Status::Done('\0')),
+                'x' if json5 => Ok(Status::Parsing(Self::Hex { n: 0, len: 0 })),
+
+                _ => Err(ParseStringError::InvalidEscape { c, offset: 0 }),
             },
 
+            Self::Hex { mut n, len } => {
+                n <<= 4;
+                // This won't truncate because the maximum result is 15
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    n |= c
+                        .to_digit(16)
+                        .ok_or(ParseStringError::InvalidEscape { c, offset: 0 })?
+                        as u8;
+                }
+
+                if len == 1 {
+                    Ok(Status::Done(char::from(n)))
+                } else {
+                    Ok(Status::Parsing(Self::Hex { n, len: len + 1 }))
+                }
+            }
+
             Self::Unicode { mut n, len } => {
                 n <<= 4;
                 // This won't truncate because the maximum result is 15
@@ -54,7 +164,7 @@ impl EscapeMachine {
                 {
                     n |= c
                         .to_digit(16)
-                        .ok_or(ParseStringError::InvalidUnicodeEscape(c))?
+                        .ok_or(ParseStringError::InvalidUnicodeEscape { c, offset: 0 })?
                         as u16;
                 }
 
@@ -65,7 +175,7 @@ impl EscapeMachine {
 
                     // For u16s, the above only fails for surrogates
                     if n >= 0xdc00 {
-                        return Err(ParseStringError::MissingHighSurrogate { low: n });
+                        return Err(ParseStringError::MissingHighSurrogate { low: n, offset: 0 });
                     }
 
                     Ok(Status::Parsing(Self::Surrogate {
@@ -106,13 +216,17 @@ impl LowMachine {
                 {
                     low |= c
                         .to_digit(16)
-                        .ok_or(ParseStringError::InvalidUnicodeEscape(c))?
+                        .ok_or(ParseStringError::InvalidUnicodeEscape { c, offset: 0 })?
                         as u16;
                 }
 
                 if len == 3 {
                     if !(0xdc00..0xe000).contains(&low) {
-                        return Err(ParseStringError::InvalidLowSurrogate { high, low });
+                        return Err(ParseStringError::InvalidLowSurrogate {
+                            high,
+                            low,
+                            offset: 0,
+                        });
                     }
 
                     let char_code =
@@ -128,14 +242,14 @@ impl LowMachine {
                 }
             }
 
-            _ => Err(ParseStringError::MissingLowSurrogate { high }),
+            _ => Err(ParseStringError::MissingLowSurrogate { high, offset: 0 }),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{EscapeMachine, Machine};
+    use super::{EscapeMachine, Machine, ParseStringError, Status};
 
     #[test]
     fn escaped_quotes() {
@@ -183,4 +297,24 @@ mod test {
         let result = machine.apply('"').expect("failed to apply '\"' to machine");
         assert!(result.is_none());
     }
+
+    #[test]
+    fn invalid_hex_escape() {
+        let mut machine = EscapeMachine::Awaiting;
+
+        for c in ['x', '4'] {
+            let Status::Parsing(next) = machine
+                .apply(c, true)
+                .expect("failed to apply character to machine")
+            else {
+                panic!("expected machine to continue");
+            };
+            machine = next;
+        }
+
+        assert_eq!(
+            machine.apply('g', true),
+            Err(ParseStringError::InvalidEscape { c: 'g', offset: 0 })
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{debug::debug_impl, Parent};
+use crate::{containers::span, debug::debug_impl, Parent, ParseOptions};
 
 mod error;
 mod machine;
@@ -10,12 +10,28 @@ pub use parsed::ParsedString;
 /// A JSON string.
 pub struct String<'json, 'p> {
     parent: &'p mut dyn Parent<'json>,
+    start: &'json str,
     remaining: &'json str,
+    end: Option<&'json str>,
+    error_offset: Option<usize>,
+    options: ParseOptions,
 }
 
 impl<'json, 'p> String<'json, 'p> {
-    pub(crate) fn new(parent: &'p mut dyn Parent<'json>, remaining: &'json str) -> Self {
-        Self { parent, remaining }
+    pub(crate) fn new(
+        parent: &'p mut dyn Parent<'json>,
+        start: &'json str,
+        options: ParseOptions,
+    ) -> Self {
+        Self {
+            parent,
+            start,
+            // Skip the opening quote
+            remaining: &start[1..],
+            end: None,
+            error_offset: None,
+            options,
+        }
     }
 
     /// Try to parse the string.
@@ -23,24 +39,103 @@ impl<'json, 'p> String<'json, 'p> {
     ///
     /// # Errors
     /// If parsing the string fails, this will return a [`ParseStringError`].
+    /// Where it failed is returned by [`Self::error_offset`].
     pub fn get(&mut self) -> Result<ParsedString<'json>, ParseStringError> {
         let mut machine = Machine::In;
+        let contents = self.remaining;
+        let mut i = 0;
+
+        loop {
+            // Outside of escapes, only quotation marks and backslashes change the state
+            if machine == Machine::In {
+                let Some(offset) = find_quote_or_backslash(&contents.as_bytes()[i..]) else {
+                    break;
+                };
+                i += offset;
+            }
+
+            // Quotation marks and backslashes are ASCII, so `i` is on a character boundary
+            let Some(c) = contents[i..].chars().next() else {
+                break;
+            };
 
-        for (i, c) in self.remaining.char_indices() {
-            if let Some(next) = machine.apply(c)? {
+            let next = machine.apply_with(c, self.options).map_err(|error| {
+                self.error_offset = Some(i);
+                error.at(i)
+            })?;
+
+            if let Some(next) = next {
                 machine = next;
+                i += c.len_utf8();
                 continue;
             }
 
-            let next_i = i + c.len_utf8();
-            self.parent.set_remaining(&self.remaining[next_i..]);
+            let remaining = &self.remaining[i + 1..];
+            self.parent.set_remaining(remaining);
+            self.end = Some(remaining);
 
             return Ok(ParsedString::new(&self.remaining[0..i]));
         }
 
+        self.error_offset = Some(contents.len());
         Err(ParseStringError::UnexpectedEnd)
     }
 
+    /// Try to parse the string, returning it as it is in the JSON, including the quotation marks.
+    ///
+    /// Escape sequences are left exactly as they are, so this can be copied into other JSON.
+    ///
+    /// # Errors
+    /// If parsing the string fails, this will return a [`ParseStringError`], as in [`Self::get`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::Any, document::Document};
+    ///
+    /// let mut document = Document::new(r#"["a\u0062\n"]"#);
+    /// let Some(Any::Array(mut array)) = document.next()? else {
+    ///     panic!("expected an array");
+    /// };
+    /// let Some(Any::String(mut string)) = array.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    ///
+    /// assert_eq!(string.raw()?, r#""a\u0062\n""#);
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn raw(&mut self) -> Result<&'json str, ParseStringError> {
+        let contents = self.get()?.unescaped();
+        // The contents and both quotation marks
+        Ok(&self.start[..contents.len() + 2])
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the byte offset where parsing the string failed, if it has failed.
+    ///
+    /// The offset is from the start of the string's contents, after the opening quotation mark, and is the start of the character that caused the error.
+    /// For [`ParseStringError::UnexpectedEnd`], it is the length of the rest of the JSON.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::Any, document::Document, string::ParseStringError};
+    ///
+    /// let mut document = Document::new(r#""a long string with a bad \escape""#);
+    /// let Some(Any::String(mut string)) = document.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    ///
+    /// assert_eq!(
+    ///     string.get(),
+    ///     Err(ParseStringError::InvalidEscape { c: 'e', offset: 26 })
+    /// );
+    /// assert_eq!(string.error_offset(), Some(26));
+    /// # Ok::<(), zjson::document::ParseDocumentError>(())
+    /// ```
+    pub const fn error_offset(&self) -> Option<usize> {
+        self.error_offset
+    }
+
     #[inline]
     /// Finish parsing the string so that the parent can continue.
     ///
@@ -51,19 +146,78 @@ impl<'json, 'p> String<'json, 'p> {
     pub fn finish(&mut self) -> Result<(), ParseStringError> {
         self.get().map(drop)
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the string has been successfully parsed, so the parent can continue.
+    pub const fn is_finished(&self) -> bool {
+        self.end.is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the options that this string is parsed with.
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn remaining(&self) -> &'json str {
+        self.remaining
+    }
+
+    #[must_use]
+    #[inline]
+    pub(crate) fn span(&self) -> Option<&'json str> {
+        self.end.map(|end| span(self.start, end))
+    }
 }
 
 debug_impl!("String", String<'json, 'p>);
 
+/// Returns the index of the first `"` or `\` in `bytes`.
+///
+/// Long runs without either are checked a word at a time.
+fn find_quote_or_backslash(bytes: &[u8]) -> Option<usize> {
+    const WORD: usize = core::mem::size_of::<usize>();
+    const LOW: usize = usize::MAX / 0xff;
+    const HIGH: usize = LOW << 7;
+
+    /// Returns [`true`] if any byte of `word` is 0.
+    const fn has_zero(word: usize) -> bool {
+        word.wrapping_sub(LOW) & !word & HIGH != 0
+    }
+
+    let mut start = 0;
+    while let Some(chunk) = bytes.get(start..start + WORD) {
+        let mut word = [0; WORD];
+        word.copy_from_slice(chunk);
+        let word = usize::from_ne_bytes(word);
+
+        if has_zero(word ^ (LOW * usize::from(b'"'))) || has_zero(word ^ (LOW * usize::from(b'\\')))
+        {
+            break;
+        }
+        start += WORD;
+    }
+
+    // Find the match in the word that has one, or in the bytes left over
+    bytes[start..]
+        .iter()
+        .position(|&b| b == b'"' || b == b'\\')
+        .map(|i| start + i)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::test_parent::TestParent;
+    use crate::{any::Any, document::Document, test_parent::TestParent, ParseOptions};
 
     use super::ParseStringError;
 
     #[test]
     fn empty() {
-        let mut parent = TestParent::new("\"");
+        let mut parent = TestParent::new("\"\"");
         let mut string = parent.string();
 
         let value = string.get().expect("failed to parse string");
@@ -75,7 +229,7 @@ mod test {
     #[test]
     fn string() {
         let expected_value = "value1";
-        let json = format!("{expected_value}\"");
+        let json = format!("\"{expected_value}\"");
 
         let mut parent = TestParent::new(&json);
         let mut string = parent.string();
@@ -90,7 +244,7 @@ mod test {
     #[test]
     fn escape() {
         let expected = " \" \\ / \x08 \x0c \n \r \t ";
-        let json = r#" \" \\ \/ \b \f \n \r \t ""#;
+        let json = r#"" \" \\ \/ \b \f \n \r \t ""#;
 
         let mut parent = TestParent::new(json);
         let mut string = parent.string();
@@ -105,7 +259,7 @@ mod test {
     #[test]
     fn unicode_escape() {
         let expected = "ü";
-        let json = r#"\u00fc""#;
+        let json = r#""\u00fc""#;
 
         let mut parent = TestParent::new(json);
         let mut string = parent.string();
@@ -117,9 +271,216 @@ mod test {
         assert!(parent.remaining.is_empty());
     }
 
+    #[test]
+    fn lone_surrogates() {
+        let cases = [
+            (r#""\ud800""#, "\u{fffd}"),
+            (r#""a\udc00b""#, "a\u{fffd}b"),
+            (r#""\ud83dx""#, "\u{fffd}x"),
+            (r#""\ud83d\n""#, "\u{fffd}\n"),
+            (r#""\ud83dA""#, "\u{fffd}A"),
+            (r#""\ud83d😃""#, "\u{fffd}😃"),
+            (r#""😃""#, "😃"),
+        ];
+        let options = ParseOptions::new().allow_lone_surrogates(true);
+
+        for (json, expected) in cases {
+            let mut document = Document::with_options(json, options);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string in {json:?}");
+            };
+            assert_eq!(
+                string.get().map(|value| value == expected),
+                Ok(true),
+                "{json:?}"
+            );
+            document.finish().expect("failed to finish document");
+        }
+
+        // They are not allowed by default
+        let mut document = Document::new(r#""\ud800""#);
+        let Ok(Some(Any::String(mut string))) = document.next() else {
+            panic!("expected a string");
+        };
+        assert_eq!(
+            string.get(),
+            Err(ParseStringError::MissingLowSurrogate {
+                high: 0xd800,
+                offset: 6
+            })
+        );
+    }
+
+    #[test]
+    fn json5_escapes() {
+        let cases = [
+            ("\"\\x41\\x7e\\xE9\"", "A~\u{e9}"),
+            ("\"\\'\\v\"", "'\x0b"),
+            ("\"a\\0b\"", "a\0b"),
+            ("\"\\0\"", "\0"),
+            ("\"a\\\nb\\\r\nc\\\rd\\\u{2028}e\\\u{2029}f\"", "abcdef"),
+            ("\"\\\r\\\n\"", ""),
+            ("\"\\u0041\\n\"", "A\n"),
+        ];
+        let options = ParseOptions::new().allow_json5_escapes(true);
+
+        for (json, expected) in cases {
+            let mut document = Document::with_options(json, options);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string in {json:?}");
+            };
+            assert_eq!(
+                string.get().map(|value| value == expected),
+                Ok(true),
+                "{json:?}"
+            );
+            document.finish().expect("failed to finish document");
+
+            // They are not allowed by default
+            if json.contains("\\u") {
+                continue;
+            }
+            let mut document = Document::new(json);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string in {json:?}");
+            };
+            assert!(
+                matches!(string.get(), Err(ParseStringError::InvalidEscape { .. })),
+                "{json:?}"
+            );
+        }
+
+        let errors = [
+            (
+                "\"\\01\"",
+                ParseStringError::InvalidEscape { c: '0', offset: 2 },
+                2,
+            ),
+            (
+                "\"\\x4g\"",
+                ParseStringError::InvalidEscape { c: 'g', offset: 3 },
+                3,
+            ),
+            (
+                "\"\\x4\"",
+                ParseStringError::InvalidEscape { c: '"', offset: 3 },
+                3,
+            ),
+            (
+                "\"\\q\"",
+                ParseStringError::InvalidEscape { c: 'q', offset: 1 },
+                1,
+            ),
+        ];
+
+        for (json, error, offset) in errors {
+            let mut document = Document::with_options(json, options);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string in {json:?}");
+            };
+            assert_eq!(string.get(), Err(error), "{json:?}");
+            assert_eq!(string.error_offset(), Some(offset), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn error_offset() {
+        let cases = [
+            (r#""\q""#, 1),
+            (r#""é\u12x4""#, 6),
+            (r#""\ud83d\u0041""#, 11),
+            (r#""\ud83dx""#, 6),
+            (r#""\ude03""#, 5),
+            ("\"abc", 3),
+        ];
+
+        for (json, offset) in cases {
+            let mut parent = TestParent::new(json);
+            let mut string = parent.string();
+            assert_eq!(string.error_offset(), None, "{json:?}");
+
+            assert!(string.get().is_err(), "{json:?}");
+            assert_eq!(string.error_offset(), Some(offset), "{json:?}");
+        }
+
+        let mut parent = TestParent::new(r#""ok""#);
+        let mut string = parent.string();
+        assert!(string.get().is_ok());
+        assert_eq!(string.error_offset(), None);
+    }
+
+    #[test]
+    fn raw() {
+        let json = r#""a\"bé😃" , 1"#;
+
+        let mut parent = TestParent::new(json);
+        let mut string = parent.string();
+
+        assert_eq!(string.raw(), Ok(r#""a\"bé😃""#));
+        // It can be called again, after the string has been parsed
+        assert_eq!(string.raw(), Ok(r#""a\"bé😃""#));
+        assert_eq!(parent.remaining, " , 1");
+
+        let mut parent = TestParent::new(r#""\x""#);
+        let mut string = parent.string();
+        assert_eq!(
+            string.raw(),
+            Err(ParseStringError::InvalidEscape { c: 'x', offset: 1 })
+        );
+    }
+
+    #[test]
+    fn find_quote_or_backslash() {
+        for len in 0..40 {
+            let padding = "é".repeat(len / 2) + &"a".repeat(len % 2);
+            assert_eq!(super::find_quote_or_backslash(padding.as_bytes()), None);
+
+            for special in ['"', '\\'] {
+                let bytes = format!("{padding}{special}b\"");
+                assert_eq!(
+                    super::find_quote_or_backslash(bytes.as_bytes()),
+                    Some(padding.len()),
+                    "{bytes:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn long() {
+        for len in 0..40 {
+            let padding = "é😃a".repeat(len);
+
+            let json = format!(r#""{padding}\né{padding}" , 1"#);
+            let mut parent = TestParent::new(&json);
+            let mut string = parent.string();
+            let value = string.get().expect("failed to parse string");
+            assert_eq!(value, format!("{padding}\né{padding}"));
+            assert_eq!(parent.remaining, " , 1");
+
+            let json = format!(r#""{padding}\u00x9""#);
+            let mut parent = TestParent::new(&json);
+            let mut string = parent.string();
+            assert_eq!(
+                string.get(),
+                Err(ParseStringError::InvalidUnicodeEscape {
+                    c: 'x',
+                    offset: padding.len() + 4
+                })
+            );
+            assert_eq!(string.error_offset(), Some(padding.len() + 4));
+
+            let json = format!("\"{padding}");
+            let mut parent = TestParent::new(&json);
+            let mut string = parent.string();
+            assert_eq!(string.get(), Err(ParseStringError::UnexpectedEnd));
+            assert_eq!(string.error_offset(), Some(padding.len()));
+        }
+    }
+
     #[test]
     fn terminated() {
-        let json = "j";
+        let json = "\"j";
 
         let mut parent = TestParent::new(json);
         let mut string = parent.string();
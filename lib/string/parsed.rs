@@ -1,10 +1,23 @@
-use core::{fmt, hash, iter::FusedIterator, str};
+use core::{
+    cmp,
+    fmt::{self, Write},
+    hash,
+    iter::FusedIterator,
+    ops::Range,
+    str,
+};
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
 use crate::status::Status;
 
-use super::machine::EscapeMachine;
+use super::{
+    machine::{is_line_terminator, EscapeMachine, Machine},
+    ParseStringError,
+};
+
+/// The size of the buffer on the stack that [`ParsedString::parse`] escapes strings into.
+const PARSE_BUFFER_SIZE: usize = 128;
 
 #[derive(Clone, Copy)]
 /// A parsed JSON string.
@@ -15,10 +28,49 @@ pub struct ParsedString<'json> {
 impl<'json> ParsedString<'json> {
     #[must_use]
     #[inline]
-    pub(super) const fn new(json: &'json str) -> Self {
+    pub(crate) const fn new(json: &'json str) -> Self {
         Self { json }
     }
 
+    /// Create a parsed string from the contents of a JSON string (without the quotation marks), checking that it is valid.
+    ///
+    /// Escape sequences are checked as when parsing a [`String`](super::String), so the string can be used in the same way as one from the parser.
+    ///
+    /// # Errors
+    /// If `raw` contains an invalid or incomplete escape sequence, or an unescaped quotation mark, this will return a [`ParseStringError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::string::{ParseStringError, ParsedString};
+    ///
+    /// let parsed = ParsedString::try_new(r"a\u00e9\n")?;
+    /// assert_eq!(parsed, "aé\n");
+    ///
+    /// assert_eq!(ParsedString::try_new(r"a\"), Err(ParseStringError::UnexpectedEnd));
+    /// assert_eq!(
+    ///     ParsedString::try_new(r"a\x"),
+    ///     Err(ParseStringError::InvalidEscape { c: 'x', offset: 2 })
+    /// );
+    /// assert_eq!(ParsedString::try_new(r#"a"b"#), Err(ParseStringError::UnescapedQuote));
+    /// # Ok::<(), ParseStringError>(())
+    /// ```
+    pub fn try_new(raw: &'json str) -> Result<Self, ParseStringError> {
+        let mut machine = Machine::In;
+
+        for (i, c) in raw.char_indices() {
+            machine = machine
+                .apply(c)
+                .map_err(|error| error.at(i))?
+                .ok_or(ParseStringError::UnescapedQuote)?;
+        }
+
+        // The closing quotation mark must end the string, as it would in JSON
+        match machine.apply('"').map_err(|error| error.at(raw.len()))? {
+            None => Ok(Self::new(raw)),
+            Some(_) => Err(ParseStringError::UnexpectedEnd),
+        }
+    }
+
     #[must_use]
     #[inline]
     /// Returns [`true`] if the string has zero characters.
@@ -30,13 +82,118 @@ impl<'json> ParsedString<'json> {
     #[inline]
     /// Returns the unescaped string.
     /// This will be the same as the JSON string without the quotation marks.
+    ///
+    /// Escape sequences (like `\n`) have not been processed yet, as they are written in the JSON; [`Self::escaped`] and [`Self::escaped_cow`] process them.
     pub const fn unescaped(self) -> &'json str {
         self.json
     }
 
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the string contains escape sequences.
+    ///
+    /// If it does not, the escaped and unescaped strings are the same, so [`Self::unescaped`] can be used without processing escapes.
+    pub const fn has_escapes(self) -> bool {
+        let bytes = self.json.as_bytes();
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'\\' {
+                return true;
+            }
+            i += 1;
+        }
+
+        false
+    }
+
+    #[must_use]
+    /// Returns the number of characters in the escaped string.
+    ///
+    /// Each escape sequence counts as one character, including a surrogate pair, as it is one character once escaped, except for JSON5 line continuations, which count as none.
+    /// This is the same as `self.chars().count()`, but faster if the string has no escape sequences.
+    pub fn char_count(self) -> usize {
+        if self.has_escapes() {
+            self.chars().count()
+        } else {
+            // Count the bytes that are not UTF-8 continuation bytes
+            self.json
+                .bytes()
+                .filter(|&b| b & 0b1100_0000 != 0b1000_0000)
+                .count()
+        }
+    }
+
+    #[must_use]
+    /// Returns the length of the escaped string in bytes, when it is encoded as UTF-8.
+    ///
+    /// This is the same as `self.escaped().len()`, so it can be used to size a buffer for [`Self::unescape_into`] or [`Self::escaped`].
+    /// The escape sequences are measured without decoding the characters, except for invalid escape sequences, JSON5 `\x` escapes and JSON5 line continuations, which are measured as they are decoded by [`Self::chars`].
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::string::ParsedString;
+    ///
+    /// let parsed = ParsedString::try_new(r"a\né😃")?;
+    /// assert_eq!(parsed.unescaped_len(), "a\né😃".len());
+    /// # Ok::<(), zjson::string::ParseStringError>(())
+    /// ```
+    pub fn unescaped_len(self) -> usize {
+        let bytes = self.json.as_bytes();
+        let mut len = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] != b'\\' {
+                len += 1;
+                i += 1;
+                continue;
+            }
+
+            i += match bytes.get(i + 1) {
+                Some(
+                    b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' | b'\'' | b'v' | b'0',
+                ) => {
+                    len += 1;
+                    2
+                }
+                Some(b'u') => match unicode_escape(&bytes[i..]) {
+                    Some(0xd800..0xdc00) => match unicode_escape(&bytes[i + 6..]) {
+                        Some(0xdc00..0xe000) => {
+                            len += 4;
+                            12
+                        }
+                        _ => return self.chars().map(char::len_utf8).sum(),
+                    },
+                    Some(0x0000..0x0080) => {
+                        len += 1;
+                        6
+                    }
+                    Some(0x0080..0x0800) => {
+                        len += 2;
+                        6
+                    }
+                    Some(0x0800..0xd800 | 0xe000..) => {
+                        len += 3;
+                        6
+                    }
+                    _ => return self.chars().map(char::len_utf8).sum(),
+                },
+                _ => return self.chars().map(char::len_utf8).sum(),
+            };
+        }
+
+        len
+    }
+
     #[must_use]
     #[inline]
     /// Returns an iterator over the characters in the escaped string.
+    ///
+    /// Strings from the parser are always valid, but if an invalid escape sequence is found, it is replaced with U+FFFD (`\u{FFFD}`) and decoding continues from the character that made it invalid.
+    /// The iterator can be reversed, but iterating backwards over invalid escape sequences may not give the same characters.
+    ///
+    /// The JSON5 escape sequences (see [`ParseOptions::allow_json5_escapes`](crate::ParseOptions::allow_json5_escapes)) are always decoded, and line continuations produce no characters.
     pub fn chars(self) -> Chars<'json> {
         Chars {
             json: self.json.chars(),
@@ -47,9 +204,387 @@ impl<'json> ParsedString<'json> {
     #[must_use]
     #[inline]
     /// Collects the escaped string into a [`String`](alloc::string::String).
+    ///
+    /// Escape sequences are processed, so this is the string that the JSON represents.
+    /// This always allocates; [`Self::escaped_cow`] only allocates if there are escape sequences.
     pub fn escaped(self) -> alloc::string::String {
         self.chars().collect()
     }
+
+    /// Parses the escaped string (with escape sequences processed) into another type, with [`str::parse`].
+    ///
+    /// If the string has no escape sequences, the unescaped string is parsed directly.
+    /// Otherwise, it is escaped into a buffer on the stack, which fits strings up to 128 bytes (once escaped).
+    /// Longer strings are escaped into a temporary [`String`](alloc::string::String) with the `alloc` feature, which allocates.
+    ///
+    /// Without the `alloc` feature, longer strings with escape sequences cannot be escaped, so the unescaped string is parsed instead, with the escape sequences left in.
+    /// This usually fails, but types that accept any string (or backslashes) will get the unescaped string, so use [`Self::chars`] for strings that could be longer.
+    ///
+    /// # Errors
+    /// If parsing fails, the error from [`FromStr`](str::FromStr) is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::net::Ipv4Addr;
+    ///
+    /// use zjson::{any::Any, document::Document};
+    ///
+    /// let mut document = Document::new(r#"["127.0.0.1", "\u0031\u0032"]"#);
+    /// let Some(Any::Array(mut array)) = document.next()? else {
+    ///     panic!("expected an array");
+    /// };
+    ///
+    /// let Some(Any::String(mut address)) = array.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    /// assert_eq!(address.get()?.parse(), Ok(Ipv4Addr::LOCALHOST));
+    ///
+    /// let Some(Any::String(mut number)) = array.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    /// assert_eq!(number.get()?.parse(), Ok(12_u32));
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn parse<T: str::FromStr>(self) -> Result<T, T::Err> {
+        if !self.has_escapes() {
+            return self.json.parse();
+        }
+
+        #[cfg(feature = "alloc")]
+        return self
+            .parse_on_stack()
+            .unwrap_or_else(|| self.escaped().parse());
+        #[cfg(not(feature = "alloc"))]
+        return self.parse_on_stack().unwrap_or_else(|| self.json.parse());
+    }
+
+    /// Escapes the string into a buffer on the stack and parses it, or returns [`None`] if it is longer than [`PARSE_BUFFER_SIZE`].
+    fn parse_on_stack<T: str::FromStr>(self) -> Option<Result<T, T::Err>> {
+        let mut buffer = [0; PARSE_BUFFER_SIZE];
+        let mut len = 0;
+        for c in self.chars() {
+            let bytes = buffer.get_mut(len..len + c.len_utf8())?;
+            len += c.encode_utf8(bytes).len();
+        }
+
+        // The buffer only contains whole characters, so this always succeeds
+        str::from_utf8(&buffer[..len]).ok().map(str::parse)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns a value that displays the string as a JSON string, with quotation marks.
+    ///
+    /// Parsing the output gives a string equal to this one.
+    /// The unescaped string is written as it is if it is valid in JSON, so escape sequences are kept.
+    /// Otherwise, `"`, `\` and control characters are escaped, and other characters are written as they are.
+    /// Unpaired surrogate escapes, which [`ParseOptions::allow_lone_surrogates`](crate::ParseOptions::allow_lone_surrogates) lets through, are written as U+FFFD, as they are decoded.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::string::ParsedString;
+    ///
+    /// let parsed = ParsedString::try_new(r"a\u00e9\n")?;
+    /// assert_eq!(parsed.display_json().to_string(), r#""a\u00e9\n""#);
+    /// # Ok::<(), zjson::string::ParseStringError>(())
+    /// ```
+    pub const fn display_json(self) -> DisplayJson<'json> {
+        DisplayJson(self)
+    }
+
+    #[must_use]
+    /// Returns [`true`] if the escaped string starts with `prefix`.
+    ///
+    /// Escape sequences are processed first, so `\u0061b` starts with `a`.
+    pub fn starts_with(self, prefix: &str) -> bool {
+        if self.has_escapes() {
+            starts_with_chars(self.chars(), prefix)
+        } else {
+            self.json.starts_with(prefix)
+        }
+    }
+
+    #[must_use]
+    /// Returns [`true`] if the escaped string ends with `suffix`.
+    ///
+    /// Escape sequences are processed first, so `a\u0062` ends with `b`.
+    pub fn ends_with(self, suffix: &str) -> bool {
+        if !self.has_escapes() {
+            return self.json.ends_with(suffix);
+        }
+
+        let mut chars = self.chars();
+        suffix.chars().rev().all(|c| chars.next_back() == Some(c))
+    }
+
+    #[must_use]
+    /// Returns the rest of the string after `prefix`, if the escaped string starts with `prefix`.
+    ///
+    /// The result borrows from the same JSON as this string.
+    /// Each escape sequence is one character (a surrogate pair is one escape sequence), so the end of the prefix is always between escape sequences, never in the middle of one.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::Any, document::Document};
+    ///
+    /// let mut document = Document::new(r#""user\u002ename""#);
+    /// let Some(Any::String(mut string)) = document.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    ///
+    /// let name = string.get()?.strip_prefix("user.").expect("expected a user key");
+    /// assert_eq!(name, "name");
+    /// assert_eq!(name.unescaped(), "name");
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn strip_prefix(self, prefix: &str) -> Option<Self> {
+        if !self.has_escapes() {
+            return self.json.strip_prefix(prefix).map(Self::new);
+        }
+
+        let mut chars = self.chars();
+        prefix
+            .chars()
+            .all(|c| chars.next() == Some(c))
+            .then(|| Self::new(chars.json.as_str()))
+    }
+
+    #[must_use]
+    /// Returns the start of the string before `suffix`, if the escaped string ends with `suffix`.
+    ///
+    /// As in [`Self::strip_prefix`], the result borrows from the same JSON as this string, and the start of the suffix is always between escape sequences.
+    pub fn strip_suffix(self, suffix: &str) -> Option<Self> {
+        if !self.has_escapes() {
+            return self.json.strip_suffix(suffix).map(Self::new);
+        }
+
+        let mut chars = self.chars();
+        suffix
+            .chars()
+            .rev()
+            .all(|c| chars.next_back() == Some(c))
+            .then(|| Self::new(chars.json.as_str()))
+    }
+
+    #[must_use]
+    /// Returns [`true`] if the escaped string contains `needle`.
+    ///
+    /// Escape sequences are processed first, so `a\u0062c` contains `bc`.
+    pub fn contains(self, needle: &str) -> bool {
+        if !self.has_escapes() {
+            return self.json.contains(needle);
+        }
+
+        let mut chars = self.chars();
+        loop {
+            if starts_with_chars(chars.clone(), needle) {
+                return true;
+            }
+
+            if chars.next().is_none() {
+                return false;
+            }
+        }
+    }
+
+    #[must_use]
+    /// Returns [`true`] if the escaped string is equal to `other`, ignoring ASCII case.
+    ///
+    /// Like [`str::eq_ignore_ascii_case`], only ASCII letters are folded, not other Unicode characters.
+    /// Escape sequences are processed first, so `\u0041` matches `a`.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::Any, document::Document};
+    ///
+    /// let mut document = Document::new(r#""Content-\u0054ype""#);
+    /// let Some(Any::String(mut string)) = document.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    ///
+    /// assert!(string.get()?.eq_ignore_ascii_case("content-type"));
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn eq_ignore_ascii_case(self, other: &str) -> bool {
+        if self.has_escapes() {
+            self.chars()
+                .map(|c| c.to_ascii_lowercase())
+                .eq(other.chars().map(|c| c.to_ascii_lowercase()))
+        } else {
+            self.json.eq_ignore_ascii_case(other)
+        }
+    }
+
+    #[must_use]
+    /// Returns [`true`] if the escaped strings are equal, ignoring ASCII case.
+    ///
+    /// This is [`Self::eq_ignore_ascii_case`] for two parsed strings.
+    pub fn eq_ignore_ascii_case_parsed(self, other: ParsedString<'_>) -> bool {
+        if other.has_escapes() {
+            self.chars()
+                .map(|c| c.to_ascii_lowercase())
+                .eq(other.chars().map(|c| c.to_ascii_lowercase()))
+        } else {
+            self.eq_ignore_ascii_case(other.json)
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over the characters in the escaped string, with the byte range in the unescaped string that each one came from.
+    ///
+    /// An escaped character can come from 2 bytes (like `\n`), 6 bytes (like `\u00e9`) or 12 bytes (a surrogate pair).
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::string::ParsedString;
+    ///
+    /// let parsed = ParsedString::try_new(r"a\n\u00e9")?;
+    /// let mut indices = parsed.char_indices();
+    ///
+    /// assert_eq!(indices.next(), Some((0..1, 'a')));
+    /// assert_eq!(indices.next(), Some((1..3, '\n')));
+    /// assert_eq!(indices.next(), Some((3..9, 'é')));
+    /// assert_eq!(indices.next(), None);
+    /// # Ok::<(), zjson::string::ParseStringError>(())
+    /// ```
+    pub fn char_indices(self) -> CharIndices<'json> {
+        CharIndices {
+            chars: self.chars(),
+            front: 0,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over the UTF-8 bytes of the escaped string.
+    ///
+    /// Escape sequences are processed as in [`Self::chars`].
+    pub fn bytes(self) -> Bytes<'json> {
+        Bytes {
+            chars: self.chars(),
+            buffer: [0; 4],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Writes the escaped string (with escape sequences processed) to `out`, one character at a time.
+    ///
+    /// This is what the [`Display`](fmt::Display) implementation writes, without going through a [`fmt::Formatter`].
+    ///
+    /// # Errors
+    /// If writing to `out` fails, the error is returned.
+    pub fn write_unescaped<W: fmt::Write>(self, out: &mut W) -> fmt::Result {
+        self.chars().try_for_each(|c| out.write_char(c))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    /// Replaces the contents of `out` with the escaped string (with escape sequences processed), reusing its capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use zjson::{any::Any, document::Document};
+    ///
+    /// let mut buffer = String::new();
+    ///
+    /// let mut document = Document::new(r#"["a\tb", "c"]"#);
+    /// let Some(Any::Array(mut array)) = document.next()? else {
+    ///     panic!("expected an array");
+    /// };
+    ///
+    /// while let Some(Any::String(mut string)) = array.next()? {
+    ///     string.get()?.unescape_into(&mut buffer);
+    ///     assert!(buffer == "a\tb" || buffer == "c");
+    /// }
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn unescape_into(self, out: &mut alloc::string::String) {
+        out.clear();
+        // Escape sequences are longer than the characters they are for
+        out.reserve(self.json.len());
+        out.extend(self.chars());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Collects the escaped string into a [`String`](alloc::string::String).
+    ///
+    /// This is the same as [`Self::escaped`], and as converting with [`From`].
+    pub fn to_owned_string(self) -> alloc::string::String {
+        self.escaped()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Returns the escaped string, the string that the JSON represents, borrowing it from the JSON if possible.
+    ///
+    /// If the string has no escape sequences, the escaped and unescaped strings are the same, so this returns [`Cow::Borrowed`](alloc::borrow::Cow::Borrowed) with [`Self::unescaped`], without allocating.
+    /// Otherwise, this returns [`Cow::Owned`](alloc::borrow::Cow::Owned) with [`Self::escaped`].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use zjson::{any::Any, document::Document};
+    ///
+    /// let mut document = Document::new(r#"["plain", "line\nbreak"]"#);
+    /// let Some(Any::Array(mut array)) = document.next()? else {
+    ///     panic!("expected an array");
+    /// };
+    ///
+    /// let Some(Any::String(mut plain)) = array.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    /// assert!(matches!(plain.get()?.escaped_cow(), Cow::Borrowed("plain")));
+    ///
+    /// let Some(Any::String(mut escaped)) = array.next()? else {
+    ///     panic!("expected a string");
+    /// };
+    /// assert_eq!(escaped.get()?.escaped_cow(), Cow::<str>::Owned("line\nbreak".into()));
+    /// # Ok::<(), zjson::document::ParseAnyDocumentError>(())
+    /// ```
+    pub fn escaped_cow(self) -> alloc::borrow::Cow<'json, str> {
+        if self.has_escapes() {
+            alloc::borrow::Cow::Owned(self.escaped())
+        } else {
+            alloc::borrow::Cow::Borrowed(self.json)
+        }
+    }
+}
+
+impl<'json> ParsedString<'json> {
+    /// Compares the escaped strings lexicographically by character, like [`str`].
+    fn cmp_chars(self, other: ParsedString<'_>) -> cmp::Ordering {
+        if self.has_escapes() || other.has_escapes() {
+            self.chars().cmp(other.chars())
+        } else {
+            // UTF-8 byte order is the same as character order
+            self.json.cmp(other.json)
+        }
+    }
+}
+
+/// Returns the code unit of the `\u` escape sequence at the start of `bytes`, if it is valid.
+fn unicode_escape(bytes: &[u8]) -> Option<u16> {
+    let [b'\\', b'u', digits @ ..] = bytes.get(..6)? else {
+        return None;
+    };
+
+    digits.iter().try_fold(0, |n, &digit| {
+        let digit = char::from(digit).to_digit(16)?;
+        // This won't truncate because the digit is at most 15
+        #[allow(clippy::cast_possible_truncation)]
+        Some(n << 4 | digit as u16)
+    })
+}
+
+/// Returns [`true`] if `chars` starts with the characters of `prefix`.
+fn starts_with_chars(mut chars: Chars<'_>, prefix: &str) -> bool {
+    prefix.chars().all(|c| chars.next() == Some(c))
 }
 
 impl<'json> fmt::Debug for ParsedString<'json> {
@@ -60,18 +595,18 @@ impl<'json> fmt::Debug for ParsedString<'json> {
 
 impl<'json> fmt::Display for ParsedString<'json> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for c in self.chars() {
-            write!(f, "{c}")?;
-        }
-
-        Ok(())
+        self.write_unescaped(f)
     }
 }
 
 impl<'json> PartialEq<str> for ParsedString<'json> {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.chars().eq(other.chars())
+        if self.has_escapes() {
+            self.chars().eq(other.chars())
+        } else {
+            self.json == other
+        }
     }
 }
 
@@ -82,17 +617,97 @@ impl<'json> PartialEq<&str> for ParsedString<'json> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'json> PartialEq<alloc::string::String> for ParsedString<'json> {
+    #[inline]
+    fn eq(&self, other: &alloc::string::String) -> bool {
+        self.eq(other.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'json> PartialEq<ParsedString<'json>> for alloc::string::String {
+    #[inline]
+    fn eq(&self, other: &ParsedString<'json>) -> bool {
+        other.eq(self.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'json> PartialEq<alloc::borrow::Cow<'_, str>> for ParsedString<'json> {
+    #[inline]
+    fn eq(&self, other: &alloc::borrow::Cow<'_, str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'json> PartialEq<ParsedString<'json>> for alloc::borrow::Cow<'_, str> {
+    #[inline]
+    fn eq(&self, other: &ParsedString<'json>) -> bool {
+        other.eq(&**self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'json> From<ParsedString<'json>> for alloc::string::String {
+    /// Collects the escaped string, as in [`ParsedString::escaped`].
+    #[inline]
+    fn from(value: ParsedString<'json>) -> Self {
+        value.escaped()
+    }
+}
+
 impl<'json> PartialEq<ParsedString<'_>> for ParsedString<'json> {
     #[inline]
     fn eq(&self, other: &ParsedString<'_>) -> bool {
-        self.chars().eq(other.chars())
+        if other.has_escapes() {
+            self.chars().eq(other.chars())
+        } else {
+            self.eq(other.json)
+        }
     }
 }
 impl<'json> Eq for ParsedString<'json> {}
 
+impl<'json> PartialOrd<str> for ParsedString<'json> {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<cmp::Ordering> {
+        if self.has_escapes() {
+            Some(self.chars().cmp(other.chars()))
+        } else {
+            Some(self.json.cmp(other))
+        }
+    }
+}
+
+impl<'json> PartialOrd<&str> for ParsedString<'json> {
+    #[inline]
+    fn partial_cmp(&self, other: &&str) -> Option<cmp::Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
+impl<'json> PartialOrd<ParsedString<'_>> for ParsedString<'json> {
+    #[inline]
+    fn partial_cmp(&self, other: &ParsedString<'_>) -> Option<cmp::Ordering> {
+        Some(self.cmp_chars(*other))
+    }
+}
+
+impl<'json> Ord for ParsedString<'json> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.cmp_chars(*other)
+    }
+}
+
 impl<'json> hash::Hash for ParsedString<'json> {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // There is no fast path for strings without escapes, as hashing the raw
+        // bytes in one write would not match hashing the escaped path: hashers
+        // are not required to give the same result for split writes
         for c in self.chars() {
             c.hash(state);
         }
@@ -112,49 +727,326 @@ impl<'json> Iterator for Chars<'json> {
     type Item = char;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let c = self.json.next()?;
+        let mut c = self.json.next()?;
+
+        // JSON5 line continuations do not produce a character
+        while c == '\\' {
+            let rest = self.json.as_str();
+            let Some(terminator) = rest.chars().next().filter(|&c| is_line_terminator(c)) else {
+                break;
+            };
+
+            let rest = &rest[terminator.len_utf8()..];
+            let rest = match terminator {
+                '\r' => rest.strip_prefix('\n').unwrap_or(rest),
+                _ => rest,
+            };
+            self.json = rest.chars();
+            c = self.json.next()?;
+        }
 
         if c != '\\' {
             return Some(c);
         }
 
         let mut machine = EscapeMachine::Awaiting;
+        let mut low_start = None;
 
-        for c in &mut self.json {
-            match machine
-                .apply(c)
-                .expect("failed to parse an escape in a parsed string")
-            {
-                Status::Parsing(next) => machine = next,
-                Status::Done(result) => {
-                    return Some(result);
+        loop {
+            let rest = self.json.clone();
+            let Some(c) = self.json.next() else {
+                return Some(char::REPLACEMENT_CHARACTER);
+            };
+
+            match machine.apply(c, true) {
+                Ok(Status::Parsing(next)) => {
+                    if c == '\\' {
+                        low_start = Some(rest);
+                    }
+
+                    machine = next;
+                }
+                Ok(Status::Done(result)) => return Some(result),
+                Err(ParseStringError::MissingHighSurrogate { .. }) => {
+                    return Some(char::REPLACEMENT_CHARACTER);
+                }
+                Err(_) => {
+                    // Resynchronise on the escape after a high surrogate or
+                    // the character that made the escape invalid
+                    self.json = low_start.unwrap_or(rest);
+                    return Some(char::REPLACEMENT_CHARACTER);
                 }
             }
         }
-
-        panic!("ran out of characters whilst parsing an escape in a parsed string");
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let bytes = self.json.as_str().len();
+        let json = self.json.as_str();
+        let bytes = json.len();
+
+        // Line continuations do not produce characters, so any number of
+        // them could be next; they are a backslash then one of '\n', '\r',
+        // U+2028 and U+2029, which start with 0xe2 in UTF-8
+        let continuations = json
+            .as_bytes()
+            .windows(2)
+            .any(|pair| matches!(pair, [b'\\', b'\n' | b'\r' | 0xe2]));
 
-        let min = if bytes < 6 {
+        let min = if continuations {
+            0
+        } else if bytes < 6 {
             // max-len-character: 4 byte utf-8
-            (bytes + 3) / 4
+            bytes.div_ceil(4)
         } else if bytes < 12 {
             // max-len-character: 6 byte unicode escape
-            (bytes + 5) / 6
+            bytes.div_ceil(6)
         } else {
             // max-len-character: 12 byte surrogate pair
-            (bytes + 11) / 12
+            bytes.div_ceil(12)
         };
 
         (min, Some(bytes))
     }
 }
 
+impl<'json> DoubleEndedIterator for Chars<'json> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut json = self.json.as_str();
+        while let Some(rest) = strip_line_continuation(json) {
+            json = rest;
+        }
+        let last = json.chars().next_back()?;
+
+        // Escape sequences end in ASCII, so these are byte lengths: a
+        // surrogate pair, a unicode escape, a JSON5 hex escape, then a single
+        // character escape
+        let candidates = [12, 6, 4, last.len_utf8() + 1];
+        let escape = candidates.into_iter().find_map(|len| {
+            let start = json.len().checked_sub(len)?;
+            let bytes = json.as_bytes();
+            if bytes[start] != b'\\' {
+                return None;
+            }
+
+            // An odd run of backslashes before this one escapes it
+            let run = bytes[..start]
+                .iter()
+                .rev()
+                .take_while(|&&b| b == b'\\')
+                .count();
+            if run % 2 != 0 {
+                return None;
+            }
+
+            // The escape must decode to exactly one character
+            let mut chars = Chars {
+                json: json[start..].chars(),
+            };
+            let c = chars.next()?;
+            chars.json.as_str().is_empty().then_some((start, c))
+        });
+
+        let (start, c) = escape.unwrap_or_else(|| {
+            let start = json.len() - last.len_utf8();
+            let c = if last == '\\' {
+                char::REPLACEMENT_CHARACTER
+            } else {
+                last
+            };
+            (start, c)
+        });
+
+        self.json = json[..start].chars();
+        Some(c)
+    }
+}
+
 impl FusedIterator for Chars<'_> {}
 
+/// Returns [`true`] if `json` has escape sequences that are only allowed in JSON5.
+fn has_json5_escapes(json: &str) -> bool {
+    let bytes = json.as_bytes();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            i += 1;
+            continue;
+        }
+
+        // U+2028 and U+2029 start with 0xe2 in UTF-8
+        if let Some(b'\'' | b'v' | b'0' | b'x' | b'\n' | b'\r' | 0xe2) = bytes.get(i + 1) {
+            return true;
+        }
+        i += 2;
+    }
+
+    false
+}
+
+/// Returns [`true`] if `json` has a `\u` escape sequence for a surrogate that is not part of a pair.
+fn has_lone_surrogates(json: &str) -> bool {
+    let bytes = json.as_bytes();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            i += 1;
+            continue;
+        }
+
+        match unicode_escape(&bytes[i..]) {
+            Some(0xd800..=0xdbff) => {
+                if !matches!(unicode_escape(&bytes[i + 6..]), Some(0xdc00..=0xdfff)) {
+                    return true;
+                }
+                i += 12;
+            }
+            Some(0xdc00..=0xdfff) => return true,
+            Some(_) => i += 6,
+            None => i += 2,
+        }
+    }
+
+    false
+}
+
+/// Returns `json` without the JSON5 line continuation at its end, if it has one.
+fn strip_line_continuation(json: &str) -> Option<&str> {
+    let rest = json
+        .strip_suffix("\r\n")
+        .or_else(|| json.strip_suffix(is_line_terminator))?
+        .strip_suffix('\\')?;
+
+    // An odd run of backslashes before this one escapes it
+    let run = rest.bytes().rev().take_while(|&b| b == b'\\').count();
+    (run % 2 == 0).then_some(rest)
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Displays a [`ParsedString`] as a JSON string, created by [`ParsedString::display_json`].
+pub struct DisplayJson<'json>(ParsedString<'json>);
+
+impl<'json> fmt::Display for DisplayJson<'json> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = self.0.json;
+
+        // The parser allows raw control characters, JSON5 escapes and lone surrogates, but JSON does not
+        if !json.bytes().any(|b| b < 0x20) && !has_json5_escapes(json) && !has_lone_surrogates(json)
+        {
+            return write!(f, "\"{json}\"");
+        }
+
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\u{8}' => f.write_str("\\b")?,
+                '\u{c}' => f.write_str("\\f")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                '\0'..='\u{1f}' => write!(f, "\\u{:04x}", u32::from(c))?,
+                _ => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// An iterator over the characters of a [`ParsedString`] and where they came from, created by [`ParsedString::char_indices`].
+#[derive(Clone, Debug)]
+pub struct CharIndices<'json> {
+    chars: Chars<'json>,
+    /// The offset of the start of `chars` in the unescaped string.
+    front: usize,
+}
+
+impl<'json> Iterator for CharIndices<'json> {
+    type Item = (Range<usize>, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.chars.json.as_str().len();
+        let c = self.chars.next()?;
+
+        let start = self.front;
+        self.front += len - self.chars.json.as_str().len();
+        Some((start..self.front, c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<'json> DoubleEndedIterator for CharIndices<'json> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let end = self.front + self.chars.json.as_str().len();
+        let c = self.chars.next_back()?;
+
+        let start = self.front + self.chars.json.as_str().len();
+        Some((start..end, c))
+    }
+}
+
+impl FusedIterator for CharIndices<'_> {}
+
+/// An iterator over the UTF-8 bytes of a [`ParsedString`], created by [`ParsedString::bytes`].
+#[derive(Clone)]
+pub struct Bytes<'json> {
+    chars: Chars<'json>,
+    /// The encoded bytes of the current character, `buffer[start..end]` are still to be returned.
+    buffer: [u8; 4],
+    start: u8,
+    end: u8,
+}
+
+impl<'json> Iterator for Bytes<'json> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            let c = self.chars.next()?;
+            self.start = 0;
+            // A UTF-8 character is at most 4 bytes
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                self.end = c.encode_utf8(&mut self.buffer).len() as u8;
+            }
+        }
+
+        let b = self.buffer[usize::from(self.start)];
+        self.start += 1;
+        Some(b)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.end - self.start);
+        // Every character is one to four bytes
+        let (min, max) = self.chars.size_hint();
+        (
+            pending + min,
+            max.and_then(|max| max.checked_mul(4)?.checked_add(pending)),
+        )
+    }
+}
+
+impl FusedIterator for Bytes<'_> {}
+
+impl<'json> fmt::Debug for Bytes<'json> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bytes")
+            .field(
+                "pending",
+                &&self.buffer[usize::from(self.start)..usize::from(self.end)],
+            )
+            .field("chars", &self.chars)
+            .finish()
+    }
+}
+
 impl<'json> fmt::Debug for Chars<'json> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ParsedString")
@@ -165,7 +1057,9 @@ impl<'json> fmt::Debug for Chars<'json> {
 
 #[cfg(test)]
 mod test {
-    use super::ParsedString;
+    use core::{cmp, fmt};
+
+    use super::{ParseStringError, ParsedString};
 
     #[test]
     fn test_surrogate_pair() {
@@ -173,4 +1067,800 @@ mod test {
         let parsed = ParsedString::new(unescaped);
         assert_eq!(parsed, "😃");
     }
+
+    #[test]
+    fn reverse() {
+        let cases = [
+            "",
+            "plain é😀",
+            r"ab\n",
+            r"\\",
+            r"a\\\\",
+            r"\\n",
+            r"\\\n",
+            r"\\u0041",
+            r"x\u0041",
+            r"x\ud83d\ude03",
+            r"\ud83d\ude03\ud83d\ude03",
+            r#"\"\\\/\b\f\n\r\té中😃"#,
+            r"\u0041\u0042\u0043",
+            r"\ud83d\ude03\n\u00e9",
+        ];
+
+        for raw in cases {
+            let parsed = ParsedString::new(raw);
+
+            let mut forward = ['\0'; 16];
+            let mut len = 0;
+            for c in parsed.chars() {
+                forward[len] = c;
+                len += 1;
+            }
+
+            assert!(
+                parsed
+                    .chars()
+                    .rev()
+                    .eq(forward[..len].iter().rev().copied()),
+                "{raw:?}"
+            );
+
+            // Iterating from both ends meets in the middle
+            let mut chars = parsed.chars();
+            let (mut front, mut back) = (0, len);
+            while front < back {
+                if front % 2 == 0 {
+                    assert_eq!(chars.next(), Some(forward[front]), "{raw:?}");
+                    front += 1;
+                } else {
+                    back -= 1;
+                    assert_eq!(chars.next_back(), Some(forward[back]), "{raw:?}");
+                }
+            }
+            assert_eq!(chars.next(), None, "{raw:?}");
+            assert_eq!(chars.next_back(), None, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn try_new() {
+        let valid = [
+            "",
+            "plain é😀",
+            r#"\"\\\/\b\f\n\r\t"#,
+            r"\u0041\ud83d\ude03",
+            "'",
+        ];
+        for raw in valid {
+            assert_eq!(
+                ParsedString::try_new(raw),
+                Ok(ParsedString::new(raw)),
+                "{raw:?}"
+            );
+        }
+
+        let invalid = [
+            (r"\", ParseStringError::UnexpectedEnd),
+            (
+                r"a\u12",
+                ParseStringError::InvalidUnicodeEscape { c: '"', offset: 5 },
+            ),
+            (
+                r"\u12x4",
+                ParseStringError::InvalidUnicodeEscape { c: 'x', offset: 4 },
+            ),
+            (r"\q", ParseStringError::InvalidEscape { c: 'q', offset: 1 }),
+            (
+                r"\ud83d",
+                ParseStringError::MissingLowSurrogate {
+                    high: 0xd83d,
+                    offset: 6,
+                },
+            ),
+            (
+                r"\ud83dx",
+                ParseStringError::MissingLowSurrogate {
+                    high: 0xd83d,
+                    offset: 6,
+                },
+            ),
+            (
+                r"\ude03",
+                ParseStringError::MissingHighSurrogate {
+                    low: 0xde03,
+                    offset: 5,
+                },
+            ),
+            (
+                r"\ud83d\u0041",
+                ParseStringError::InvalidLowSurrogate {
+                    high: 0xd83d,
+                    low: 0x0041,
+                    offset: 11,
+                },
+            ),
+            ("\"", ParseStringError::UnescapedQuote),
+            (r#"\\""#, ParseStringError::UnescapedQuote),
+        ];
+        for (raw, error) in invalid {
+            assert_eq!(ParsedString::try_new(raw), Err(error), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn parse() {
+        use core::net::Ipv4Addr;
+
+        assert_eq!(ParsedString::new("1234").parse(), Ok(1234_u32));
+        assert_eq!(
+            ParsedString::new(r"\u0031\u00323\u0034").parse(),
+            Ok(1234_u32)
+        );
+        assert!(ParsedString::new(r"12\n").parse::<u32>().is_err());
+
+        assert_eq!(ParsedString::new("-1.5e3").parse(), Ok(-1500.0_f64));
+        assert_eq!(
+            ParsedString::new(r"-1.5\u0065\u0033").parse(),
+            Ok(-1500.0_f64)
+        );
+
+        assert_eq!(
+            ParsedString::new("10.0.0.1").parse(),
+            Ok(Ipv4Addr::new(10, 0, 0, 1))
+        );
+        assert_eq!(
+            ParsedString::new(r"10\u002e0\u002e0\u002e1").parse(),
+            Ok(Ipv4Addr::new(10, 0, 0, 1))
+        );
+        assert!(ParsedString::new(r"10\u002e0").parse::<Ipv4Addr>().is_err());
+
+        // Too long for the stack buffer
+        #[cfg(feature = "alloc")]
+        {
+            let raw = "\\u0030".repeat(200) + "1";
+            assert_eq!(ParsedString::new(&raw).parse(), Ok(1_u64));
+        }
+    }
+
+    #[test]
+    fn parse_buffer_limit() {
+        extern crate alloc;
+        use alloc::string::String;
+
+        use super::PARSE_BUFFER_SIZE;
+
+        // Each escape sequence is one byte once escaped
+        let raw = r"\u0041".repeat(PARSE_BUFFER_SIZE);
+        let fits = ParsedString::new(&raw);
+        assert_eq!(
+            fits.parse_on_stack::<String>(),
+            Some(Ok("A".repeat(PARSE_BUFFER_SIZE)))
+        );
+        assert_eq!(fits.parse(), Ok("A".repeat(PARSE_BUFFER_SIZE)));
+
+        let raw = r"\u0041".repeat(PARSE_BUFFER_SIZE + 1);
+        let long = ParsedString::new(&raw);
+        assert_eq!(long.parse_on_stack::<String>(), None);
+
+        // Without `alloc`, the unescaped string is parsed instead
+        #[cfg(feature = "alloc")]
+        assert_eq!(long.parse(), Ok("A".repeat(PARSE_BUFFER_SIZE + 1)));
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(long.parse(), Ok(raw.clone()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display_json() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        use crate::{any::Any, document::Document};
+
+        let cases = [
+            ("", r#""""#),
+            ("plain é😀", r#""plain é😀""#),
+            (
+                r#"\"\\\/\u0041\ud83d\ude03"#,
+                r#""\"\\\/\u0041\ud83d\ude03""#,
+            ),
+            ("a\tb", "\"a\\tb\""),
+            ("\u{0}\u{1f}\n\\\\", r#""\u0000\u001f\n\\""#),
+            ("\\u0022a\tb", r#""\"a\tb""#),
+            // JSON5 escapes are not valid JSON
+            (r"\x41\'\v\0", r#""A'\u000b\u0000""#),
+            ("a\\\u{2028}b", r#""ab""#),
+        ];
+
+        for (raw, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            let json = parsed.display_json().to_string();
+            assert_eq!(json, expected, "{raw:?}");
+
+            // The output parses back to an equal string
+            let mut document = Document::new(&json);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string from {raw:?}");
+            };
+            assert_eq!(string.get(), Ok(parsed), "{raw:?}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display_json_lone_surrogates() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        use crate::{any::Any, document::Document, ParseOptions};
+
+        let options = ParseOptions::new().allow_lone_surrogates(true);
+        let cases = [
+            (r#""a\ud800b""#, "\"a\u{fffd}b\""),
+            (r#""\udc00""#, "\"\u{fffd}\""),
+            (r#""\ud83d\ud83d\ude03""#, "\"\u{fffd}\u{1f603}\""),
+            // Paired surrogates are kept as they are
+            (r#""\ud83d\ude03\u0041""#, r#""\ud83d\ude03\u0041""#),
+        ];
+
+        for (json, expected) in cases {
+            let mut document = Document::with_options(json, options);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string in {json:?}");
+            };
+            let parsed = string.get().expect("failed to parse string");
+
+            let output = parsed.display_json().to_string();
+            assert_eq!(output, expected, "{json:?}");
+
+            // The output parses back to an equal string, without the option
+            let mut document = Document::new(&output);
+            let Ok(Some(Any::String(mut string))) = document.next() else {
+                panic!("expected a string from {json:?}");
+            };
+            assert_eq!(string.get(), Ok(parsed), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        let cases = [
+            (r"\q", "\u{fffd}q"),
+            (r"a\", "a\u{fffd}"),
+            (r"\u12", "\u{fffd}"),
+            (r"\u12x4", "\u{fffd}x4"),
+            (r"\ud83dx", "\u{fffd}x"),
+            (r"\ud83d\n", "\u{fffd}\n"),
+            (r"\ud83d\ud83d", "\u{fffd}\u{fffd}"),
+            (r"\ud83d😃", "\u{fffd}😃"),
+            (r"\ud83dA", "\u{fffd}A"),
+            (r"\ude03", "\u{fffd}"),
+        ];
+
+        for (raw, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert!(parsed.chars().eq(expected.chars()), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn eq() {
+        let cases = [
+            ("name", "name", true),
+            ("name", "names", false),
+            ("", "", true),
+            (r"\u006eame", "name", true),
+            (r"\u006eame", "game", false),
+            (r"a\\b", r"a\b", true),
+            (r"\ud83d\ude03", "😃", true),
+            ("😃", "😃", true),
+        ];
+
+        for (raw, other, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert_eq!(parsed == other, expected, "{raw:?}");
+
+            // Compare both ways, so each side is escaped and unescaped
+            let escaped = ParsedString::new(r"\u006eame");
+            assert_eq!(parsed == escaped, escaped == parsed, "{raw:?}");
+            assert_eq!(parsed == escaped, raw == "name" || raw == r"\u006eame");
+            assert!(parsed == ParsedString::new(raw), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn json5_escapes() {
+        let cases = [
+            ("\\x41\\x7e\\xE9", "A~\u{e9}"),
+            ("\\'\\v\\0", "'\x0b\0"),
+            ("a\\\nb\\\r\nc\\\rd\\\u{2028}e\\\u{2029}f", "abcdef"),
+            ("\\\n\\\r\n\\\u{2028}", ""),
+            ("a\\\\\nb", "a\\\nb"),
+            ("a\\\n", "a"),
+        ];
+
+        for (raw, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert!(parsed.chars().eq(expected.chars()), "{raw:?}");
+            assert!(parsed.chars().rev().eq(expected.chars().rev()), "{raw:?}");
+            assert_eq!(parsed, expected, "{raw:?}");
+            assert_eq!(parsed.unescaped_len(), expected.len(), "{raw:?}");
+            assert_eq!(parsed.char_count(), expected.chars().count(), "{raw:?}");
+
+            let (min, max) = parsed.chars().size_hint();
+            let count = expected.chars().count();
+            assert!(
+                min <= count && max.is_some_and(|max| count <= max),
+                "{raw:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn has_escapes() {
+        let cases = [
+            ("", false),
+            ("plain é😀", false),
+            (r"\nabc", true),
+            (r"ab\u00e9c", true),
+            (r"abc\\", true),
+            ("/", false),
+        ];
+
+        for (raw, expected) in cases {
+            assert_eq!(ParsedString::new(raw).has_escapes(), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn char_count() {
+        let cases = [
+            ("", ""),
+            ("plain", "plain"),
+            ("é😀ab中", "é😀ab中"),
+            (r"\u00e9\n\u4e2d", "é\n中"),
+            (r"a\ud83d\ude03b", "a😃b"),
+            (r"\ud83d\ude03é😀", "😃é😀"),
+        ];
+
+        for (raw, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert_eq!(parsed.char_count(), expected.chars().count(), "{raw:?}");
+            assert_eq!(parsed.char_count(), parsed.chars().count(), "{raw:?}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unescaped_len() {
+        let cases = [
+            "",
+            "plain",
+            "é😀ab中",
+            r#"\"\\\/\b\f\n\r\t"#,
+            r"\u0041\u00e9\u4e2d\ufffd",
+            r"\u007f\u0080\u07ff\u0800\uffff",
+            r"a\ud83d\ude03b",
+            r"\ud83d\ude03\uD83D\uDE00",
+            // Invalid escape sequences
+            r"\ud83d",
+            r"\ud83dx",
+            r"\ud83d\n",
+            r"\ude03",
+            r"\u12x4",
+            r"\q",
+            r"\",
+        ];
+
+        for raw in cases {
+            let parsed = ParsedString::new(raw);
+            assert_eq!(parsed.unescaped_len(), parsed.escaped().len(), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn write_unescaped() {
+        /// A writer with a fixed capacity.
+        struct Fixed<const N: usize> {
+            buffer: [u8; N],
+            len: usize,
+        }
+
+        impl<const N: usize> fmt::Write for Fixed<N> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.buffer
+                    .get_mut(self.len..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let parsed = ParsedString::new(r"a\u00e9\ud83d\ude03");
+
+        let mut out = Fixed::<7> {
+            buffer: [0; 7],
+            len: 0,
+        };
+        assert_eq!(parsed.write_unescaped(&mut out), Ok(()));
+        assert_eq!(&out.buffer[..out.len], "aé😃".as_bytes());
+
+        let mut out = Fixed::<6> {
+            buffer: [0; 6],
+            len: 0,
+        };
+        assert_eq!(parsed.write_unescaped(&mut out), Err(fmt::Error));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unescape_into() {
+        extern crate alloc;
+        use alloc::string::String;
+
+        let mut buffer = String::from("some old contents");
+        let capacity = buffer.capacity();
+
+        ParsedString::new(r"\\n\n").unescape_into(&mut buffer);
+        assert_eq!(buffer, "\\n\n");
+        assert_eq!(buffer.capacity(), capacity);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ord() {
+        extern crate alloc;
+        use alloc::collections::BTreeSet;
+
+        let strings = [
+            "",
+            "a",
+            "ab",
+            r"\u0061b",
+            "b",
+            r"\u0062",
+            "B",
+            "é",
+            r"\u00e9a",
+            "\u{ffff}",
+            r"\ud83d\ude03",
+            "😃",
+            r"\n",
+            "\t",
+        ];
+
+        for a in strings {
+            for b in strings {
+                let (a, b) = (ParsedString::new(a), ParsedString::new(b));
+                assert_eq!(a.cmp(&b), a.escaped().cmp(&b.escaped()), "{a:?} {b:?}");
+                assert_eq!(a.cmp(&b) == cmp::Ordering::Equal, a == b, "{a:?} {b:?}");
+            }
+        }
+
+        let set: BTreeSet<_> = strings.into_iter().map(ParsedString::new).collect();
+        let sorted: alloc::vec::Vec<_> = set.into_iter().map(ParsedString::escaped).collect();
+        assert_eq!(
+            sorted,
+            ["", "\t", "\n", "B", "a", "ab", "b", "é", "éa", "\u{ffff}", "😃"]
+        );
+    }
+
+    #[test]
+    fn partial_ord_str() {
+        let cases = [
+            ("a", "b", cmp::Ordering::Less),
+            ("é", "e", cmp::Ordering::Greater),
+            (r"\u0041", "a", cmp::Ordering::Less),
+            (r"\u0041", "A", cmp::Ordering::Equal),
+            // The escape is less than `^`, but the character is greater
+            (r"\u0061", "^", cmp::Ordering::Greater),
+            (r"\ud83d\ude03", "\u{ffff}", cmp::Ordering::Greater),
+            (r"ab\n", "ab", cmp::Ordering::Greater),
+        ];
+
+        for (raw, other, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert_eq!(
+                parsed.partial_cmp(other),
+                Some(expected),
+                "{raw:?} {other:?}"
+            );
+            assert_eq!(
+                parsed.partial_cmp(&other),
+                Some(expected),
+                "{raw:?} {other:?}"
+            );
+        }
+
+        assert!(ParsedString::new(r"\u0061") > "^");
+        assert!(ParsedString::new("l") < "m");
+    }
+
+    #[test]
+    fn char_indices() {
+        let raw = r#"aé\"\u0041\ud83d\ude03😃\\"#;
+        let expected = [
+            (0..1, 'a'),
+            (1..3, 'é'),
+            (3..5, '"'),
+            (5..11, 'A'),
+            (11..23, '😃'),
+            (23..27, '😃'),
+            (27..29, '\\'),
+        ];
+
+        let parsed = ParsedString::new(raw);
+        assert!(parsed.char_indices().eq(expected.iter().cloned()));
+        assert!(parsed
+            .char_indices()
+            .rev()
+            .eq(expected.iter().rev().cloned()));
+
+        for (range, c) in parsed.char_indices() {
+            assert!(ParsedString::new(&raw[range]).chars().eq([c]));
+        }
+
+        // From both ends
+        let mut indices = parsed.char_indices();
+        assert_eq!(indices.next(), Some(expected[0].clone()));
+        assert_eq!(indices.next_back(), Some(expected[6].clone()));
+        assert_eq!(indices.next_back(), Some(expected[5].clone()));
+        assert_eq!(indices.next(), Some(expected[1].clone()));
+        assert!(indices.eq(expected[2..5].iter().cloned()));
+    }
+
+    #[test]
+    fn bytes() {
+        let cases = [
+            ("", ""),
+            ("plain é😀", "plain é😀"),
+            (r"a\u00e9\n\ud83d\ude03\u4e2d", "aé\n😃中"),
+            (r"\q", "\u{fffd}q"),
+        ];
+
+        for (raw, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert!(parsed.bytes().eq(expected.bytes()), "{raw:?}");
+
+            let mut bytes = parsed.bytes();
+            let mut remaining = expected.len();
+            loop {
+                let (min, max) = bytes.size_hint();
+                assert!(min <= remaining, "{raw:?}");
+                assert!(max.is_some_and(|max| max >= remaining), "{raw:?}");
+
+                if bytes.next().is_none() {
+                    break;
+                }
+                remaining -= 1;
+            }
+            assert_eq!(bytes.next(), None);
+        }
+    }
+
+    #[test]
+    fn eq_ignore_ascii_case() {
+        let cases = [
+            ("Content-Type", "content-type", true),
+            ("Content-Type", "content_type", false),
+            (r"\u0041", "a", true),
+            (r"\u0041", "b", false),
+            (r"\u0061B", "Ab", true),
+            // Only ASCII is folded
+            ("É", "é", false),
+            (r"\u00c9", "é", false),
+            (r"\u00e9", "É", false),
+            ("", "", true),
+            ("a", "", false),
+        ];
+
+        for (raw, other, expected) in cases {
+            let parsed = ParsedString::new(raw);
+            assert_eq!(
+                parsed.eq_ignore_ascii_case(other),
+                expected,
+                "{raw:?} {other:?}"
+            );
+            assert_eq!(
+                parsed.eq_ignore_ascii_case_parsed(ParsedString::new(other)),
+                expected,
+                "{raw:?} {other:?}"
+            );
+            assert_eq!(
+                ParsedString::new(other).eq_ignore_ascii_case_parsed(parsed),
+                expected,
+                "{raw:?} {other:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn substrings() {
+        let strings = [
+            "abcd",
+            r"ab\u0063d",
+            r"\u0061bc\u0064",
+            r"a\ud83d\ude03é",
+            "a😃é",
+            r"\n",
+        ];
+        let needles = [
+            "", "a", "ab", "abc", "bcd", "cd", "d", "abcd", "abcde", "😃", "a😃", "😃é", "é", "\n",
+        ];
+
+        for raw in strings {
+            let parsed = ParsedString::new(raw);
+            // A haystack without escapes to check against
+            let haystack: [char; 8] = {
+                let mut haystack = ['\0'; 8];
+                parsed.chars().zip(&mut haystack).for_each(|(c, h)| *h = c);
+                haystack
+            };
+            let len = parsed.char_count();
+            let haystack = &haystack[..len];
+
+            for needle in needles {
+                let needle_chars: [char; 8] = {
+                    let mut chars = ['\0'; 8];
+                    needle.chars().zip(&mut chars).for_each(|(c, n)| *n = c);
+                    chars
+                };
+                let needle_chars = &needle_chars[..needle.chars().count()];
+
+                assert_eq!(
+                    parsed.starts_with(needle),
+                    haystack.starts_with(needle_chars),
+                    "{raw:?} {needle:?}"
+                );
+                assert_eq!(
+                    parsed.ends_with(needle),
+                    haystack.ends_with(needle_chars),
+                    "{raw:?} {needle:?}"
+                );
+                assert_eq!(
+                    parsed.contains(needle),
+                    needle_chars.is_empty()
+                        || haystack
+                            .windows(needle_chars.len())
+                            .any(|w| w == needle_chars),
+                    "{raw:?} {needle:?}"
+                );
+            }
+        }
+
+        // Needles that span an escape
+        let parsed = ParsedString::new(r"ab\u0063d");
+        assert!(parsed.starts_with("abc"));
+        assert!(parsed.ends_with("cd"));
+        assert!(parsed.contains("bcd"));
+        assert!(!parsed.contains("u0063"));
+    }
+
+    #[test]
+    fn strip() {
+        let parsed = ParsedString::new(r"user\u002ename");
+        assert_eq!(
+            parsed.strip_prefix("user.").map(ParsedString::unescaped),
+            Some("name")
+        );
+        assert_eq!(
+            parsed.strip_prefix("user").map(ParsedString::unescaped),
+            Some(r"\u002ename")
+        );
+        assert_eq!(
+            parsed.strip_prefix("").map(ParsedString::unescaped),
+            Some(parsed.unescaped())
+        );
+        assert_eq!(parsed.strip_prefix("user\\"), None);
+        assert_eq!(parsed.strip_prefix("user.namex"), None);
+        assert_eq!(
+            parsed.strip_suffix(".name").map(ParsedString::unescaped),
+            Some("user")
+        );
+        assert_eq!(
+            parsed.strip_suffix("name").map(ParsedString::unescaped),
+            Some(r"user\u002e")
+        );
+        assert_eq!(
+            parsed
+                .strip_suffix("user.name")
+                .map(ParsedString::unescaped),
+            Some("")
+        );
+        assert_eq!(parsed.strip_suffix("xuser.name"), None);
+
+        // A surrogate pair is one escape sequence
+        let parsed = ParsedString::new(r"a\ud83d\ude03b");
+        assert_eq!(
+            parsed.strip_prefix("a😃").map(ParsedString::unescaped),
+            Some("b")
+        );
+        assert_eq!(
+            parsed.strip_suffix("😃b").map(ParsedString::unescaped),
+            Some("a")
+        );
+        assert_eq!(parsed.strip_prefix("a\u{fffd}"), None);
+
+        let parsed = ParsedString::new("user.name");
+        assert_eq!(
+            parsed.strip_prefix("user.").map(ParsedString::unescaped),
+            Some("name")
+        );
+        assert_eq!(
+            parsed.strip_suffix(".name").map(ParsedString::unescaped),
+            Some("user")
+        );
+        assert_eq!(parsed.strip_suffix("x"), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_string() {
+        extern crate alloc;
+        use alloc::string::String;
+
+        let parsed = ParsedString::new(r"aé\n");
+        let owned = String::from("aé\n");
+
+        assert_eq!(String::from(parsed), owned);
+        assert_eq!(parsed.to_owned_string(), owned);
+        let converted: String = parsed.into();
+        assert_eq!(converted, owned);
+
+        assert_eq!(parsed, owned);
+        assert_eq!(owned, parsed);
+        assert_ne!(parsed, String::from(r"aé\n"));
+        assert_ne!(String::from("a"), parsed);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_eq() {
+        extern crate alloc;
+        use alloc::{borrow::Cow, string::String};
+
+        let parsed = ParsedString::new(r"aé\n");
+
+        let borrowed: Cow<str> = Cow::Borrowed("aé\n");
+        let owned: Cow<str> = Cow::Owned(String::from("aé\n"));
+        assert_eq!(parsed, borrowed);
+        assert_eq!(parsed, owned);
+        assert_eq!(borrowed, parsed);
+        assert_eq!(owned, parsed);
+
+        let different: Cow<str> = Cow::Borrowed(r"aé\n");
+        assert_ne!(parsed, different);
+        assert_ne!(different, parsed);
+
+        // The escaped string compares equal to itself
+        assert_eq!(parsed.escaped_cow(), parsed);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn escaped_cow() {
+        extern crate alloc;
+        use alloc::borrow::Cow;
+
+        let cases = [
+            ("", Some("")),
+            ("plain é😀", Some("plain é😀")),
+            (r"\n", None),
+            (r"a\u00e9b", None),
+        ];
+
+        for (raw, borrowed) in cases {
+            let parsed = ParsedString::new(raw);
+            let cow = parsed.escaped_cow();
+            assert_eq!(cow, parsed.escaped(), "{raw:?}");
+            match borrowed {
+                Some(expected) => {
+                    assert!(matches!(cow, Cow::Borrowed(s) if s == expected), "{raw:?}");
+                }
+                None => assert!(matches!(cow, Cow::Owned(_)), "{raw:?}"),
+            }
+        }
+    }
 }
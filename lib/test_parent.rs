@@ -1,5 +1,6 @@
 use crate::{
     array::Array, literal::Literal, number::Number, object::Object, string::String, Parent,
+    ParseOptions,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -21,6 +22,7 @@ impl<'json> Parent<'json> for TestParent<'json> {
     }
 }
 
+#[allow(dead_code)]
 impl<'json> TestParent<'json> {
     #[inline]
     #[must_use]
@@ -32,34 +34,34 @@ impl<'json> TestParent<'json> {
     #[must_use]
     pub fn string(&mut self) -> String<'json, '_> {
         let remaining = self.remaining;
-        String::new(self, remaining)
+        String::new(self, remaining, ParseOptions::new())
     }
 
     #[inline]
     #[must_use]
     pub fn number(&mut self) -> Number<'json, '_> {
         let remaining = self.remaining;
-        Number::new(self, remaining)
+        Number::new(self, remaining, ParseOptions::new())
     }
 
     #[inline]
     #[must_use]
     pub fn object(&mut self) -> Object<'json, '_> {
         let remaining = self.remaining;
-        Object::new(self, remaining)
+        Object::new(self, remaining, ParseOptions::new())
     }
 
     #[inline]
     #[must_use]
     pub fn array(&mut self) -> Array<'json, '_> {
         let remaining = self.remaining;
-        Array::new(self, remaining)
+        Array::new(self, remaining, ParseOptions::new())
     }
 
     #[inline]
     #[must_use]
     pub fn literal(&mut self) -> Literal<'json, '_> {
         let remaining = self.remaining;
-        Literal::new(self, remaining)
+        Literal::new(self, remaining, ParseOptions::new())
     }
 }
@@ -0,0 +1,294 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A kind of generated JSON document, for benchmarks and tests.
+///
+/// Documents are generated deterministically from a seed and a size, so they do not need to be stored.
+/// The statistical properties of each kind are documented on its variant.
+pub enum CorpusKind {
+    /// An array of `size` numbers.
+    ///
+    /// Half of the numbers are integers in `-1_000_000..1_000_000`;
+    /// the other half have up to 6 fractional digits, and a quarter of those also have an exponent in `-20..20`.
+    NumericArray,
+    /// An object with `size` keys of 4 to 24 characters, one in ten containing an escape sequence.
+    ///
+    /// The values are 40% numbers, 30% strings of 0 to 16 characters, 20% literals and 10% arrays of up to 4 numbers.
+    KeyHeavyObject,
+    /// Arrays and objects alternately nested `size` levels deep, around a number.
+    DeepNesting,
+    /// An array of `size` log records.
+    ///
+    /// Each record is an object with an integer timestamp, one of four levels and a message of 32 to 96 characters,
+    /// one in twenty containing escape sequences.
+    StringLogs,
+    /// `size` log records, like [`Self::StringLogs`], each as a separate document on its own line (newline-delimited JSON).
+    ///
+    /// Parse this with a [`MultiDocument`](crate::multi_document::MultiDocument).
+    Ndjson,
+}
+
+impl CorpusKind {
+    /// All of the kinds of documents.
+    pub const ALL: [Self; 5] = [
+        Self::NumericArray,
+        Self::KeyHeavyObject,
+        Self::DeepNesting,
+        Self::StringLogs,
+        Self::Ndjson,
+    ];
+
+    #[must_use]
+    #[inline]
+    /// Returns a file name for the document, including the extension.
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Self::NumericArray => "numeric_array.json",
+            Self::KeyHeavyObject => "key_heavy_object.json",
+            Self::DeepNesting => "deep_nesting.json",
+            Self::StringLogs => "string_logs.json",
+            Self::Ndjson => "logs.ndjson",
+        }
+    }
+
+    #[must_use]
+    /// Generate a document of this kind.
+    ///
+    /// The same `seed` and `size` always generate the same document.
+    pub fn generate(self, seed: u64, size: usize) -> String {
+        let mut json = String::new();
+        self.write(&mut json, seed, size)
+            .expect("failed to write to a string");
+        json
+    }
+
+    /// Write a document of this kind to `w`.
+    ///
+    /// The same `seed` and `size` always write the same document.
+    ///
+    /// # Errors
+    /// If writing to `w` fails, the error is returned.
+    pub fn write<W: Write>(self, w: &mut W, seed: u64, size: usize) -> fmt::Result {
+        let mut rng = Rng::new(seed);
+
+        match self {
+            Self::NumericArray => {
+                w.write_char('[')?;
+                for i in 0..size {
+                    if i != 0 {
+                        w.write_str(", ")?;
+                    }
+                    rng.number(w)?;
+                }
+                w.write_char(']')
+            }
+
+            Self::KeyHeavyObject => {
+                w.write_char('{')?;
+                for i in 0..size {
+                    if i != 0 {
+                        w.write_str(", ")?;
+                    }
+
+                    // Make the keys unique by prefixing the index
+                    write!(w, "\"{i}")?;
+                    rng.string_contents(w, 4, 24, 10)?;
+                    w.write_str("\": ")?;
+
+                    match rng.below(10) {
+                        0..=3 => rng.number(w)?,
+                        4..=6 => {
+                            w.write_char('"')?;
+                            rng.string_contents(w, 0, 16, 10)?;
+                            w.write_char('"')?;
+                        }
+                        7 | 8 => rng.literal(w)?,
+                        _ => {
+                            w.write_char('[')?;
+                            for j in 0..rng.below(5) {
+                                if j != 0 {
+                                    w.write_str(", ")?;
+                                }
+                                rng.number(w)?;
+                            }
+                            w.write_char(']')?;
+                        }
+                    }
+                }
+                w.write_char('}')
+            }
+
+            Self::DeepNesting => {
+                for i in 0..size {
+                    w.write_str(if i % 2 == 0 { "[" } else { "{\"a\": " })?;
+                }
+                rng.number(w)?;
+                for i in (0..size).rev() {
+                    w.write_char(if i % 2 == 0 { ']' } else { '}' })?;
+                }
+                Ok(())
+            }
+
+            Self::StringLogs => {
+                w.write_char('[')?;
+                for i in 0..size {
+                    w.write_str(if i == 0 { "\n  " } else { ",\n  " })?;
+                    rng.log_record(w, i)?;
+                }
+                w.write_str("\n]")
+            }
+
+            Self::Ndjson => {
+                for i in 0..size {
+                    rng.log_record(w, i)?;
+                    w.write_char('\n')?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A small, deterministic pseudo-random number generator (`SplitMix64`).
+struct Rng(u64);
+
+impl Rng {
+    const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    const fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`.
+    const fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Returns a number in `min..=max`.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn between(&mut self, min: usize, max: usize) -> usize {
+        min + self.below((max - min + 1) as u64) as usize
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn number<W: Write>(&mut self, w: &mut W) -> fmt::Result {
+        let integer = self.below(2_000_000) as i64 - 1_000_000;
+
+        if self.below(2) == 0 {
+            return write!(w, "{integer}");
+        }
+
+        let fraction = self.below(1_000_000);
+        write!(w, "{integer}.{fraction}")?;
+
+        if self.below(4) == 0 {
+            let exponent = self.below(40) as i64 - 20;
+            write!(w, "e{exponent}")?;
+        }
+
+        Ok(())
+    }
+
+    fn literal<W: Write>(&mut self, w: &mut W) -> fmt::Result {
+        w.write_str(match self.below(3) {
+            0 => "true",
+            1 => "false",
+            _ => "null",
+        })
+    }
+
+    /// Write the contents of a string of `min..=max` characters, without the quotes.
+    ///
+    /// One in `escape_one_in` strings contain escape sequences.
+    fn string_contents<W: Write>(
+        &mut self,
+        w: &mut W,
+        min: usize,
+        max: usize,
+        escape_one_in: u64,
+    ) -> fmt::Result {
+        const ESCAPES: [&str; 4] = ["\\n", "\\\"", "\\u00e9", "\\ud83d\\ude03"];
+
+        let len = self.between(min, max);
+        let escape = len != 0 && self.below(escape_one_in) == 0;
+
+        for i in 0..len {
+            if escape && i % 8 == 0 {
+                w.write_str(ESCAPES[i / 8 % ESCAPES.len()])?;
+                continue;
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            let c = match self.below(8) {
+                0 => ' ',
+                _ => (b'a' + self.below(26) as u8) as char,
+            };
+            w.write_char(c)?;
+        }
+
+        Ok(())
+    }
+
+    fn log_record<W: Write>(&mut self, w: &mut W, i: usize) -> fmt::Result {
+        const LEVELS: [&str; 4] = ["debug", "info", "warn", "error"];
+
+        #[allow(clippy::cast_possible_truncation)]
+        let level = LEVELS[self.below(4) as usize];
+        let timestamp = 1_700_000_000_000 + i as u64 * 1000 + self.below(1000);
+
+        write!(
+            w,
+            "{{\"ts\": {timestamp}, \"level\": \"{level}\", \"msg\": \""
+        )?;
+        self.string_contents(w, 32, 96, 20)?;
+        w.write_str("\"}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        document::Document,
+        multi_document::{MultiDocument, ParseAnyMultiDocumentError},
+    };
+
+    use super::CorpusKind;
+
+    #[test]
+    fn deterministic() {
+        for kind in CorpusKind::ALL {
+            assert_eq!(kind.generate(1, 50), kind.generate(1, 50), "{kind:?}");
+            assert_ne!(kind.generate(1, 50), kind.generate(2, 50), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn valid() {
+        for seed in 0..20 {
+            for kind in CorpusKind::ALL {
+                let json = kind.generate(seed, 100);
+
+                if kind == CorpusKind::Ndjson {
+                    let mut document = MultiDocument::new(&json);
+                    let count = document
+                        .fold::<_, _, ParseAnyMultiDocumentError>(0, |count, _| Ok(count + 1))
+                        .expect("failed to parse generated documents");
+                    assert_eq!(count, 100);
+                } else {
+                    Document::new(&json)
+                        .finish()
+                        .unwrap_or_else(|err| panic!("failed to parse {kind:?}: {err}\n{json}"));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    any::{Any, ParseAnyError},
+    document::Document,
+};
+
+#[cfg(feature = "alloc")]
+/// Deterministic generation of JSON documents for benchmarks and tests.
+pub mod corpus;
+
+#[doc(inline)]
+pub use crate::assert_consumed;
+
+#[track_caller]
+/// Parses `json` as a document, runs `f` on the root value and asserts that `f` fully consumed it.
+///
+/// This is intended for testing code that parses values, to make sure that it consumes exactly the value it was given.
+/// The [`assert_consumed!`](crate::assert_consumed) macro is a shorthand for this function.
+///
+/// # Panics
+/// Panics if parsing the document fails, if `f` returns an error, if `f` did not fully consume the value or if there are any non-whitespace characters after the value.
+pub fn assert_consumed<F>(json: &str, f: F)
+where
+    F: FnOnce(&mut Any<'_, '_>) -> Result<(), ParseAnyError>,
+{
+    let mut document = Document::new(json);
+
+    {
+        let mut value = match document.next() {
+            Ok(Some(value)) => value,
+            Ok(None) => panic!("got no values in document"),
+            Err(err) => panic!("failed to parse document: {err}"),
+        };
+
+        let start = value.remaining();
+
+        if let Err(err) = f(&mut value) {
+            panic!("failed to parse value: {err}");
+        }
+
+        if !value.is_finished() {
+            let remaining = value.remaining();
+            let consumed = &start[..start.len() - remaining.len()];
+
+            panic!(
+                "value was not fully consumed\n  consumed: {consumed:?}\n remaining: {remaining:?}"
+            );
+        }
+    }
+
+    if let Err(err) = document.finish() {
+        panic!("failed to parse document after value: {err}");
+    }
+}
+
+#[macro_export]
+/// Parses a JSON string, runs a function on the root value and asserts that the function fully consumed it.
+///
+/// `assert_consumed!(f, json)` is a shorthand for [`testing::assert_consumed(json, f)`](crate::testing::assert_consumed()).
+///
+/// # Examples
+/// ```
+/// use zjson::testing::assert_consumed;
+///
+/// assert_consumed!(|value| value.finish(), r#"{"a": [1, 2]}"#);
+/// ```
+macro_rules! assert_consumed {
+    ( $f:expr, $json:expr $(,)? ) => {
+        $crate::testing::assert_consumed($json, $f)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::any::Any;
+
+    #[test]
+    fn consumed() {
+        assert_consumed!(|value: &mut Any| value.finish(), "[1, {\"a\": true}]");
+        assert_consumed!(
+            |value: &mut Any| {
+                let string = value.mut_string().expect("expected a string");
+                assert_eq!(string.get()?, "Hello, World!");
+                Ok(())
+            },
+            "\"Hello, World!\""
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value was not fully consumed")]
+    fn not_consumed() {
+        assert_consumed!(
+            |value: &mut Any| {
+                let array = value.mut_array().expect("expected an array");
+                array.next()?.expect("expected a value").finish()?;
+                Ok(())
+            },
+            "[1, 2]"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value was not fully consumed")]
+    fn scalar_not_consumed() {
+        assert_consumed!(|_: &mut Any| Ok(()), "true");
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to parse document after value")]
+    fn trailing() {
+        assert_consumed!(|value: &mut Any| value.finish(), "[] j");
+    }
+}
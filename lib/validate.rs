@@ -0,0 +1,758 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use crate::{
+    any::{ParseAnyError, ValueKind},
+    array::{self, ParseArrayError},
+    containers::{next_char, ParsePrompt, ParseStatus},
+    document::{Document, ParseAnyDocumentError, ParseDocumentError},
+    literal::ParseLiteralError,
+    multi_document::{MultiDocument, ParseAnyMultiDocumentError},
+    number::ParseNumberError,
+    object::{self, ParseObjectError},
+    string::{ParseStringError, ParsedString, String},
+    Parent, ParseOptions,
+};
+
+/// The number of levels of nesting that [`validate`] remembers the kinds of, in 64 bit words.
+const STACK_WORDS: usize = 64;
+
+/// Validation only accepts standard JSON.
+const OPTIONS: ParseOptions = ParseOptions::new();
+
+/// Check that `json` is a valid JSON document.
+///
+/// This returns the same result as [`Document::finish`], but objects and arrays are checked in a loop, rather than recursively, so deeply nested input cannot overflow the stack.
+/// No values are created for objects or arrays.
+///
+/// The kinds of the first 4096 levels of nesting are remembered on the stack.
+/// Past that, with the `alloc` feature, they are remembered in a growable buffer; otherwise, finding the enclosing object or array searches back through the input, so extremely deep input takes much longer to check.
+///
+/// # Errors
+/// If `json` is not a valid document, this will return a [`ParseAnyDocumentError`].
+///
+/// # Examples
+/// ```
+/// use zjson::validate;
+///
+/// assert!(validate(r#"{"a": [1, 2, {"b": null}]}"#).is_ok());
+/// assert!(validate("[01]").is_err());
+///
+/// let deep = "[".repeat(1_000_000) + &"]".repeat(1_000_000);
+/// assert!(validate(&deep).is_ok());
+/// ```
+pub fn validate(json: &str) -> Result<(), ParseAnyDocumentError> {
+    let mut document = Document::new(json);
+
+    if let Some(ValueKind::Object | ValueKind::Array) = document.peek()? {
+        let remaining = Scanner::run(document.remaining())?;
+        document.set_remaining(remaining);
+    }
+
+    document.finish()
+}
+
+/// Check that `json` is a valid JSON multi-document.
+///
+/// This is [`validate`] for [`MultiDocument`]s, returning the same result as [`MultiDocument::finish`].
+///
+/// # Errors
+/// If `json` is not a valid multi-document, this will return a [`ParseAnyMultiDocumentError`].
+pub fn validate_multi(json: &str) -> Result<(), ParseAnyMultiDocumentError> {
+    let mut document = MultiDocument::new(json);
+
+    loop {
+        match document.peek()? {
+            Some(ValueKind::Object | ValueKind::Array) => {
+                let remaining = Scanner::run(document.remaining())?;
+                document.set_remaining(remaining);
+            }
+
+            Some(_) => {
+                if let Some(mut value) = document.next()? {
+                    value.finish()?;
+                }
+            }
+
+            None => return Ok(()),
+        }
+    }
+}
+
+/// The state of an object or array being checked by a [`Scanner`].
+enum Level<'json> {
+    Array {
+        machine: array::Machine,
+        /// The number of values, or [`None`] if it has not been counted since returning from a nested value.
+        consumed: Option<usize>,
+    },
+    Object(object::Machine<'json>),
+}
+
+/// Checks an object or array and everything in it, without recursion.
+struct Scanner<'json> {
+    /// The JSON from the start of the outermost object or array.
+    start: &'json str,
+    remaining: &'json str,
+    /// The kinds of the enclosing objects and arrays, one bit each, set for objects.
+    stack: [u64; STACK_WORDS],
+    #[cfg(feature = "alloc")]
+    /// The kinds of the enclosing objects and arrays past those in `stack`.
+    overflow: alloc::vec::Vec<u64>,
+    depth: usize,
+}
+
+impl<'json> Parent<'json> for Scanner<'json> {
+    fn set_remaining<'a>(&'a mut self, remaining: &'json str)
+    where
+        'json: 'a,
+    {
+        self.remaining = remaining;
+    }
+
+    fn debug_parents(&self, list: &mut core::fmt::DebugList<'_, '_>) {
+        list.entry(&"Scanner");
+    }
+}
+
+impl<'json> Scanner<'json> {
+    /// Check the object or array at the start of `start`, returning the JSON after it.
+    fn run(start: &'json str) -> Result<&'json str, ParseAnyError> {
+        Self::new(start).scan()
+    }
+
+    const fn new(start: &'json str) -> Self {
+        Self {
+            start,
+            remaining: start,
+            stack: [0; STACK_WORDS],
+            #[cfg(feature = "alloc")]
+            overflow: alloc::vec::Vec::new(),
+            depth: 0,
+        }
+    }
+
+    /// Check the object or array at the start of the JSON, returning the JSON after it.
+    ///
+    /// If this fails, [`Self::depth`] is one less than the number of objects and arrays that are open.
+    fn scan(&mut self) -> Result<&'json str, ParseAnyError> {
+        let mut level = self.open();
+
+        loop {
+            let prompt = match &mut level {
+                Level::Array { machine, consumed } => self.array(machine, consumed)?,
+                Level::Object(machine) => self.object(machine)?,
+            };
+
+            match prompt {
+                Some(ParsePrompt::Object | ParsePrompt::Array) => {
+                    self.push(&level);
+                    level = self.open();
+                }
+
+                Some(prompt) => {
+                    let remaining = self.remaining;
+                    prompt.create(self, remaining, OPTIONS).finish()?;
+                }
+
+                None => match self.pop() {
+                    Some(parent) => level = parent,
+                    None => return Ok(self.remaining),
+                },
+            }
+        }
+    }
+
+    /// Start the object or array at the start of the remaining JSON.
+    fn open(&mut self) -> Level<'json> {
+        let level = if self.remaining.starts_with('{') {
+            Level::Object(object::Machine::In { postcomma: false })
+        } else {
+            Level::Array {
+                machine: array::Machine::In { postcomma: false },
+                consumed: Some(0),
+            }
+        };
+
+        // Skip the opening bracket
+        self.remaining = &self.remaining[1..];
+        level
+    }
+
+    /// Remember the kind of `level` while a nested value is checked.
+    fn push(&mut self, level: &Level) {
+        let word = self.depth / 64;
+
+        #[cfg(feature = "alloc")]
+        if word >= STACK_WORDS && self.overflow.len() <= word - STACK_WORDS {
+            self.overflow.push(0);
+        }
+
+        let bit = 1 << (self.depth % 64);
+        if let Some(word) = self.word(word) {
+            if matches!(level, Level::Object(_)) {
+                *word |= bit;
+            } else {
+                *word &= !bit;
+            }
+        }
+
+        self.depth += 1;
+    }
+
+    /// Returns the `index`th word of the remembered kinds, if it is remembered.
+    fn word(&mut self, index: usize) -> Option<&mut u64> {
+        #[cfg(feature = "alloc")]
+        if index >= STACK_WORDS {
+            return self.overflow.get_mut(index - STACK_WORDS);
+        }
+
+        self.stack.get_mut(index)
+    }
+
+    /// Return to the enclosing object or array after a nested one has ended, or [`None`] if it was the outermost.
+    fn pop(&mut self) -> Option<Level<'json>> {
+        self.depth = self.depth.checked_sub(1)?;
+
+        let bit = self.depth % 64;
+        let is_object = self
+            .word(self.depth / 64)
+            .copied()
+            .map_or_else(|| self.enclosing().0 == b'{', |word| word >> bit & 1 == 1);
+
+        // The nested value has been checked, so the enclosing one continues after it
+        Some(if is_object {
+            Level::Object(object::Machine::Element {
+                // The name is not used once the value has been parsed
+                name: ParsedString::new(""),
+                element: ParseStatus::Done,
+            })
+        } else {
+            Level::Array {
+                machine: array::Machine::Element(ParseStatus::Done),
+                consumed: None,
+            }
+        })
+    }
+
+    /// Search back through the checked JSON for the opening bracket of the current object or array.
+    ///
+    /// Returns the bracket and the number of commas directly in the object or array.
+    fn enclosing(&self) -> (u8, usize) {
+        let checked = &self.start.as_bytes()[..self.start.len() - self.remaining.len()];
+        let mut depth = 0_usize;
+        let mut commas = 0;
+
+        let mut i = checked.len();
+        while i > 0 {
+            i -= 1;
+
+            match checked[i] {
+                // Skip back to the opening quote, which is the first one that is not escaped
+                b'"' => loop {
+                    i -= 1;
+                    if checked[i] == b'"' {
+                        let backslashes = checked[..i]
+                            .iter()
+                            .rev()
+                            .take_while(|&&b| b == b'\\')
+                            .count();
+                        if backslashes % 2 == 0 {
+                            break;
+                        }
+                    }
+                },
+
+                b']' | b'}' => depth += 1,
+                b @ (b'[' | b'{') if depth == 0 => return (b, commas),
+                b'[' | b'{' => depth -= 1,
+                b',' if depth == 0 => commas += 1,
+                _ => {}
+            }
+        }
+
+        unreachable!("the outermost object or array was not opened")
+    }
+
+    /// Parse up to the start of the next value in an array, returning how it should be parsed.
+    ///
+    /// The value is counted as parsed, as it is checked before the array continues.
+    fn array(
+        &mut self,
+        machine: &mut array::Machine,
+        consumed: &mut Option<usize>,
+    ) -> Result<Option<ParsePrompt>, ParseArrayError> {
+        loop {
+            if *machine == array::Machine::End {
+                return Ok(None);
+            }
+
+            let (remaining, c) = next_char(self.remaining);
+            self.remaining = remaining;
+
+            // Values are only counted when needed for an error
+            let next = match (c, *consumed) {
+                (Some(c), Some(consumed)) => machine.apply(c, consumed, OPTIONS),
+                (Some(c), None) => machine
+                    .apply(c, 1, OPTIONS)
+                    .or_else(|_| machine.apply(c, self.count(*machine), OPTIONS)),
+                (None, consumed) => Err(ParseArrayError::UnexpectedEnd {
+                    index: machine.index(consumed.unwrap_or_else(|| self.count(*machine))),
+                }),
+            }?;
+
+            match next {
+                array::Machine::Element(ParseStatus::Prompted(prompt)) => {
+                    *machine = array::Machine::Element(ParseStatus::Done);
+                    if let Some(consumed) = consumed {
+                        *consumed += 1;
+                    }
+
+                    // Values are created from their first character, so don't remove it
+                    return Ok(Some(prompt));
+                }
+
+                next => *machine = next,
+            }
+
+            if let Some(c) = c {
+                self.remaining = &self.remaining[c.len_utf8()..];
+            }
+        }
+    }
+
+    /// Count the values in the current array, in the state `machine`.
+    fn count(&self, machine: array::Machine) -> usize {
+        let (_, commas) = self.enclosing();
+        commas + usize::from(matches!(machine, array::Machine::Element(_)))
+    }
+
+    /// Parse up to the start of the next value in an object, returning how it should be parsed.
+    fn object(
+        &mut self,
+        machine: &mut object::Machine<'json>,
+    ) -> Result<Option<ParsePrompt>, ParseObjectError> {
+        loop {
+            match *machine {
+                object::Machine::End => return Ok(None),
+
+                object::Machine::Name(None) => {
+                    let remaining = self.remaining;
+                    let name = String::new(self, remaining, OPTIONS)
+                        .get()
+                        .map_err(ParseObjectError::InvalidName)?;
+                    *machine = object::Machine::Name(Some(name));
+                }
+
+                _ => {}
+            }
+
+            let (remaining, c) = next_char(self.remaining);
+            self.remaining = remaining;
+            let c = c.ok_or(ParseObjectError::UnexpectedEnd)?;
+
+            // Names and values are created from their first character, so don't remove `c` from `self.remaining`
+            match machine.apply(c, OPTIONS)? {
+                object::Machine::Element {
+                    name,
+                    element: ParseStatus::Prompted(prompt),
+                } => {
+                    *machine = object::Machine::Element {
+                        name,
+                        element: ParseStatus::Done,
+                    };
+                    return Ok(Some(prompt));
+                }
+
+                next @ object::Machine::Name(None) => {
+                    *machine = next;
+                    continue;
+                }
+
+                next => *machine = next,
+            }
+
+            self.remaining = &self.remaining[c.len_utf8()..];
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The result of [`validate_prefix`].
+pub enum PrefixVerdict {
+    /// The prefix is a complete, valid document.
+    ///
+    /// More input could still follow, as long as it is whitespace or continues a trailing number.
+    Complete,
+    /// The prefix is valid, but more input is needed to complete the document.
+    ValidSoFar {
+        /// The number of objects and arrays that are still open.
+        open_depth: usize,
+    },
+    /// The prefix cannot be the start of a valid document.
+    Invalid {
+        /// The byte offset in the prefix of the character where the document became invalid.
+        offset: usize,
+        /// The error that made the document invalid.
+        error: ParseAnyDocumentError,
+    },
+}
+
+/// Check whether `prefix` could be the start of a valid JSON document.
+///
+/// This parses `prefix` like [`Document::finish`], but treats running out of input as valid so far, rather than as an error.
+/// Objects and arrays are checked in a loop, as in [`validate`], so deeply nested input cannot overflow the stack.
+///
+/// Finding the offset of an error parses shorter prefixes again, so invalid input takes longer to check than valid input.
+///
+/// # Examples
+/// ```
+/// use zjson::validate::{validate_prefix, PrefixVerdict};
+///
+/// assert_eq!(
+///     validate_prefix(r#"{"a": [1, "tw"#),
+///     PrefixVerdict::ValidSoFar { open_depth: 2 }
+/// );
+/// assert_eq!(validate_prefix("[1, 2] "), PrefixVerdict::Complete);
+/// assert!(matches!(
+///     validate_prefix("[1, 2 3"),
+///     PrefixVerdict::Invalid { offset: 6, .. }
+/// ));
+/// ```
+#[must_use]
+pub fn validate_prefix(prefix: &str) -> PrefixVerdict {
+    let error = match check(prefix) {
+        Ok(verdict) => return verdict,
+        Err(error) => error,
+    };
+
+    // Checking a prefix only fails at the character that makes it invalid, so search for the shortest invalid prefix
+    let (mut valid, mut invalid) = (0, prefix.len());
+    while let Some(mid) = boundary_between(prefix, valid, invalid) {
+        if check(&prefix[..mid]).is_ok() {
+            valid = mid;
+        } else {
+            invalid = mid;
+        }
+    }
+
+    let last = prefix[..invalid]
+        .chars()
+        .next_back()
+        .map_or(0, char::len_utf8);
+
+    PrefixVerdict::Invalid {
+        offset: invalid - last,
+        error,
+    }
+}
+
+/// Returns a character boundary in `s` strictly between `low` and `high`, preferring the middle.
+fn boundary_between(s: &str, low: usize, high: usize) -> Option<usize> {
+    (low + (high - low) / 2..high)
+        .chain(low + 1..high)
+        .find(|&i| i != low && s.is_char_boundary(i))
+}
+
+/// Check `prefix`, returning an error only if it is invalid.
+fn check(prefix: &str) -> Result<PrefixVerdict, ParseAnyDocumentError> {
+    let mut open_depth = 0;
+
+    match check_document(&mut Document::new(prefix), &mut open_depth) {
+        Ok(()) => Ok(PrefixVerdict::Complete),
+        Err(error) if is_end(error) => Ok(PrefixVerdict::ValidSoFar { open_depth }),
+        Err(error) => Err(error),
+    }
+}
+
+/// Check `document` as in [`validate`], setting `open_depth` to the number of objects and arrays that are open if it fails.
+fn check_document(
+    document: &mut Document,
+    open_depth: &mut usize,
+) -> Result<(), ParseAnyDocumentError> {
+    if let Some(ValueKind::Object | ValueKind::Array) = document.peek()? {
+        let mut scanner = Scanner::new(document.remaining());
+        let remaining = scanner.scan().inspect_err(|_| {
+            *open_depth = scanner.depth + 1;
+        })?;
+        document.set_remaining(remaining);
+    }
+
+    document.finish()
+}
+
+/// Returns [`true`] if `error` was caused by running out of input.
+const fn is_end(error: ParseAnyDocumentError) -> bool {
+    match error {
+        ParseAnyDocumentError::Document(error) => {
+            matches!(error, ParseDocumentError::UnexpectedEnd)
+        }
+        ParseAnyDocumentError::Any(error) => is_value_end(error),
+    }
+}
+
+/// Returns [`true`] if `error`, from a value, was caused by running out of input.
+pub(crate) const fn is_value_end(error: ParseAnyError) -> bool {
+    matches!(
+        error,
+        ParseAnyError::String(ParseStringError::UnexpectedEnd)
+            | ParseAnyError::Number(
+                ParseNumberError::UnexpectedEnd { .. }
+                    | ParseNumberError::UnexpectedEndAfterExponent { .. }
+            )
+            | ParseAnyError::Object(
+                ParseObjectError::UnexpectedEnd
+                    | ParseObjectError::InvalidName(ParseStringError::UnexpectedEnd)
+            )
+            | ParseAnyError::Array(ParseArrayError::UnexpectedEnd { .. })
+            | ParseAnyError::Literal(ParseLiteralError::UnexpectedEnd)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        any::ParseAnyError,
+        array::ParseArrayError,
+        document::{ParseAnyDocumentError, ParseDocumentError},
+        literal::ParseLiteralError,
+        number::ParseNumberError,
+        object::ParseObjectError,
+        string::ParseStringError,
+    };
+
+    use super::{validate, validate_multi, validate_prefix, PrefixVerdict};
+    use crate::{document::Document, multi_document::MultiDocument};
+
+    /// Documents to check, with their expected results.
+    const DOCUMENTS: [(&str, bool); 34] = [
+        ("1", true),
+        (" \u{feff}", false),
+        ("\u{feff}[] ", true),
+        ("", false),
+        ("-", false),
+        ("[-]", false),
+        ("01", false),
+        ("[01]", false),
+        ("[1.]", false),
+        ("1e", false),
+        ("[1e+5, -0.5E-3, 0]", true),
+        (r#""abc"#, false),
+        (r#"["abc]"#, false),
+        (r#"{"a": "b\"}"#, false),
+        (r#"["\u12g4"]"#, false),
+        (r#"["\\", "\"", "]"]"#, true),
+        ("[] x", false),
+        ("{} {}", false),
+        ("[1 2]", false),
+        ("[1,]", false),
+        ("[,1]", false),
+        (r#"{"a" 1}"#, false),
+        (r#"{"a": 1,}"#, false),
+        (r#"{"a": }"#, false),
+        ("{1: 2}", false),
+        (r#"{"a": [1, {"b": [tru]}]}"#, false),
+        (r#"{"a": [1, {"b": [true, nul]}]}"#, false),
+        (r#"{"a": [1, {"b": [true, null]}, [], {}, "c"]}"#, true),
+        ("[[1, 2], [3, 4], [5, 6 7]]", false),
+        ("[[1, 2], [3, 4], [5, 6], 7 8]", false),
+        ("[[1, 2], [3, 4], [5, 6], 7, ]", false),
+        (r#"[{"a": [1, 2]}, ["x", "y]", 3], 4, x]"#, false),
+        ("[[[]]", false),
+        ("[[]]]", false),
+    ];
+
+    #[test]
+    fn validate_documents() {
+        for (json, valid) in DOCUMENTS {
+            let result = validate(json);
+            assert_eq!(result.is_ok(), valid, "{json:?}");
+            assert_eq!(result, Document::new(json).finish(), "{json:?}");
+        }
+    }
+
+    #[test]
+    fn validate_multi_documents() {
+        for json in [
+            "",
+            "1 2",
+            "[1] {} \"a\"",
+            "[1] x",
+            "[1,] []",
+            "1 [2, {\"a\": [}]",
+        ] {
+            assert_eq!(
+                validate_multi(json),
+                MultiDocument::new(json).finish(),
+                "{json:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn validate_corpus() {
+        use crate::testing::corpus::CorpusKind;
+
+        for kind in CorpusKind::ALL {
+            let json = kind.generate(0, 1_000);
+
+            if kind == CorpusKind::Ndjson {
+                assert_eq!(validate_multi(&json), Ok(()), "{kind:?}");
+            } else {
+                assert_eq!(validate(&json), Ok(()), "{kind:?}");
+                // Cut inside the document, so at least the root is still open
+                let prefix = &json[..json.len() / 2];
+                assert!(
+                    matches!(validate_prefix(prefix), PrefixVerdict::ValidSoFar { .. }),
+                    "{kind:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validate_deep() {
+        let depth = 100_000;
+        let open = r#"{"a": ["#.repeat(depth);
+        let close = "]}".repeat(depth);
+
+        assert_eq!(validate(&format!("{open}{close}")), Ok(()));
+        assert_eq!(validate(&format!("{open}1, 2{close}")), Ok(()));
+
+        // The enclosing array of the error is past the remembered levels, so its values are counted from the input
+        assert_eq!(
+            validate(&format!("{open}[1], [\"]\"], 2 3{close}")),
+            Err(
+                ParseAnyError::Array(ParseArrayError::ExpectedCommaOrEnd { c: '3', index: 2 })
+                    .into()
+            )
+        );
+        assert_eq!(
+            validate(&format!("{open}[[], {{}}, \"\\\\\"],{}", &close[2..])),
+            Err(ParseAnyError::Array(ParseArrayError::TrailingComma { index: 1 }).into())
+        );
+        assert_eq!(
+            validate(&format!("{open}{}", &close[..close.len() - 1])),
+            Err(ParseAnyError::Object(ParseObjectError::UnexpectedEnd).into())
+        );
+    }
+
+    #[test]
+    fn valid_so_far_deep() {
+        let depth = 1_000_000;
+
+        assert_eq!(
+            validate_prefix(&"[".repeat(depth)),
+            PrefixVerdict::ValidSoFar { open_depth: depth }
+        );
+        assert_eq!(
+            validate_prefix(&(r#"{"a": ["#.repeat(depth) + "1, \"b")),
+            PrefixVerdict::ValidSoFar {
+                open_depth: depth * 2
+            }
+        );
+        assert!(matches!(
+            validate_prefix(&("[".repeat(depth) + "1 2")),
+            PrefixVerdict::Invalid { offset, .. } if offset == depth + 2
+        ));
+    }
+
+    #[test]
+    fn valid_so_far() {
+        let cases = [
+            ("", 0),
+            ("  ", 0),
+            ("[", 1),
+            (r#"{"a": [1, "#, 2),
+            (r#"{"a"#, 1),
+            (r#"{"a" "#, 1),
+            (r#"{"a":"#, 1),
+            (r#"["abc"#, 1),
+            (r#"["a\"#, 1),
+            (r#"["a\u00"#, 1),
+            (r#"["\ud83d"#, 1),
+            (r#"["\ud83d\"#, 1),
+            ("[-", 1),
+            ("[1.", 1),
+            ("[1e", 1),
+            ("[1e+", 1),
+            ("[1.5", 1),
+            ("[tr", 1),
+            ("[nul", 1),
+            ("[[[], {}", 2),
+            ("-", 0),
+            ("\"é", 0),
+        ];
+
+        for (prefix, open_depth) in cases {
+            assert_eq!(
+                validate_prefix(prefix),
+                PrefixVerdict::ValidSoFar { open_depth },
+                "{prefix:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn complete() {
+        for prefix in ["1", "1.5e3 ", "\"a\"", "true", "[]", r#" {"a": [1, {}]} "#] {
+            assert_eq!(
+                validate_prefix(prefix),
+                PrefixVerdict::Complete,
+                "{prefix:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let cases = [
+            (
+                "[1 2",
+                3,
+                ParseAnyError::Array(ParseArrayError::ExpectedCommaOrEnd { c: '2', index: 0 })
+                    .into(),
+            ),
+            (
+                r#"["a\x"#,
+                4,
+                ParseAnyError::String(ParseStringError::InvalidEscape { c: 'x', offset: 2 }).into(),
+            ),
+            (
+                r#"["é\u00g0"#,
+                8,
+                ParseAnyError::String(ParseStringError::InvalidUnicodeEscape { c: 'g', offset: 6 })
+                    .into(),
+            ),
+            (
+                "[1.x",
+                3,
+                ParseAnyError::Number(ParseNumberError::ExpectedDigit('x')).into(),
+            ),
+            (
+                "[trx",
+                3,
+                ParseAnyError::Literal(ParseLiteralError::UnexpectedCharacter('x')).into(),
+            ),
+            (
+                "[1] x",
+                4,
+                ParseAnyDocumentError::Document(ParseDocumentError::UnexpectedCharacter('x')),
+            ),
+            (
+                "é",
+                0,
+                ParseAnyDocumentError::Document(ParseDocumentError::InvalidElement('é')),
+            ),
+        ];
+
+        for (prefix, offset, error) in cases {
+            assert_eq!(
+                validate_prefix(prefix),
+                PrefixVerdict::Invalid { offset, error },
+                "{prefix:?}"
+            );
+        }
+    }
+}